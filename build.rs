@@ -29,6 +29,92 @@ fn build_hdfs3_lib() {
 
     // Comment out this, if statically linking
     println!("cargo:rustc-link-lib=dylib=hdfs3");
+    check_hdfs3_lib();
+}
+
+/// Symbols only the bundled libhdfs3 exports. A library without them is an upstream or
+/// distribution build, whose `hdfsFileInfo` is smaller and whose `errno` values differ, see
+/// "Changes to libhdfs3" in the README.
+const REQUIRED_SYMBOLS: &[&str] = &["hdfsPread", "hdfsGetSpnegoToken"];
+
+/// Fail the build if the libhdfs3 the linker will find is not built from the bundled source,
+/// rather than read its structs at the wrong offsets at runtime.
+fn check_hdfs3_lib() {
+    println!("cargo:rerun-if-env-changed=LIBRARY_PATH");
+    println!("cargo:rerun-if-env-changed=LD_LIBRARY_PATH");
+    let lib = match find_hdfs3_lib() {
+        Some(lib) => lib,
+        None => {
+            println!("cargo:warning=libhdfs3.so not found, its ABI cannot be checked");
+            return;
+        }
+    };
+    println!("cargo:rerun-if-changed={}", lib.display());
+    let contents =
+        std::fs::read(&lib).unwrap_or_else(|e| panic!("Could not read {}: {}", lib.display(), e));
+    let missing = REQUIRED_SYMBOLS
+        .iter()
+        .filter(|symbol| !has_symbol(&contents, symbol))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        panic!(
+            "{} does not export {:?}, so it is not built from the bundled libhdfs3, \
+             whose ABI and errno values differ from upstream. Build and install it from \
+             the libhdfs3 source of this crate, see \"Changes to libhdfs3\" in the README.",
+            lib.display(),
+            missing
+        );
+    }
+}
+
+/// The libhdfs3 shared library in the first of the directories the linker searches, those
+/// given with `-L` in the rustflags, then `LIBRARY_PATH` and the system ones.
+fn find_hdfs3_lib() -> Option<PathBuf> {
+    let rustflags = env::var("CARGO_ENCODED_RUSTFLAGS").unwrap_or_default();
+    let mut flags = rustflags.split('\x1f');
+    let mut dirs = Vec::new();
+    while let Some(flag) = flags.next() {
+        let dir = match flag.strip_prefix("-L") {
+            Some("") => flags.next().unwrap_or_default(),
+            Some(dir) => dir,
+            None => continue,
+        };
+        // e.g. -L native=/opt/lib
+        let dir = dir.split_once('=').map_or(dir, |(_, dir)| dir);
+        dirs.push(PathBuf::from(dir));
+    }
+    for var in ["LIBRARY_PATH", "LD_LIBRARY_PATH"] {
+        if let Some(paths) = env::var_os(var) {
+            dirs.extend(env::split_paths(&paths));
+        }
+    }
+    dirs.extend(
+        [
+            "/usr/local/lib",
+            "/usr/local/lib64",
+            "/usr/lib64",
+            "/usr/lib",
+            "/usr/lib/x86_64-linux-gnu",
+            "/usr/lib/aarch64-linux-gnu",
+        ]
+        .iter()
+        .map(PathBuf::from),
+    );
+    dirs.into_iter()
+        .map(|dir| dir.join("libhdfs3.so"))
+        .find(|lib| lib.is_file())
+}
+
+/// Whether a shared library has a symbol in its string table, which a stripped library
+/// keeps for the dynamic linker.
+fn has_symbol(contents: &[u8], symbol: &str) -> bool {
+    let mut needle = Vec::with_capacity(symbol.len() + 2);
+    needle.push(0);
+    needle.extend_from_slice(symbol.as_bytes());
+    needle.push(0);
+    contents
+        .windows(needle.len())
+        .any(|window| window == needle.as_slice())
 }
 
 fn get_hdfs3_file_path(filename: &'static str) -> String {
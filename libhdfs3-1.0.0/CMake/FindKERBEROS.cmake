@@ -15,9 +15,15 @@ FIND_PATH(KERBEROS_INCLUDE_DIRS krb5.h)
 SET(KERBEROS_NAMES krb5 k5crypto com_err)
 FIND_LIBRARY(KERBEROS_LIBRARIES NAMES ${KERBEROS_NAMES})
 
+# GSSAPI, used for SPNEGO
+FIND_LIBRARY(KERBEROS_GSSAPI_LIBRARY NAMES gssapi_krb5)
+IF (KERBEROS_GSSAPI_LIBRARY)
+  SET(KERBEROS_LIBRARIES ${KERBEROS_LIBRARIES} ${KERBEROS_GSSAPI_LIBRARY})
+ENDIF (KERBEROS_GSSAPI_LIBRARY)
+
 # handle the QUIETLY and REQUIRED arguments and set KERBEROS_FOUND to TRUE if 
 # all listed variables are TRUE
 INCLUDE(FindPackageHandleStandardArgs)
-FIND_PACKAGE_HANDLE_STANDARD_ARGS(KERBEROS DEFAULT_MSG KERBEROS_LIBRARIES KERBEROS_INCLUDE_DIRS)
+FIND_PACKAGE_HANDLE_STANDARD_ARGS(KERBEROS DEFAULT_MSG KERBEROS_LIBRARIES KERBEROS_GSSAPI_LIBRARY KERBEROS_INCLUDE_DIRS)
 
-MARK_AS_ADVANCED(KERBEROS_LIBRARIES KERBEROS_INCLUDE_DIRS)
+MARK_AS_ADVANCED(KERBEROS_LIBRARIES KERBEROS_GSSAPI_LIBRARY KERBEROS_INCLUDE_DIRS)
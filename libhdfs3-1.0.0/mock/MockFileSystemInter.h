@@ -60,10 +60,16 @@ public:
   MOCK_METHOD2(setPermission, void(const char * path, const Hdfs::Permission &));
   MOCK_METHOD2(setReplication, bool(const char * path, short replication));
   MOCK_METHOD2(rename, bool(const char * src, const char * dst));
+  MOCK_METHOD3(rename2, void(const char * src, const char * dst, bool overwrite));
+  MOCK_METHOD2(concat, void(const char * trg, const std::vector<std::string> & srcs));
+  MOCK_METHOD1(recoverLease, bool(const char * path));
+  MOCK_METHOD1(isFileClosed, bool(const char * path));
   MOCK_METHOD1(setWorkingDirectory, void(const char * path));
   MOCK_CONST_METHOD0(getWorkingDirectory, std::string());
+  MOCK_CONST_METHOD0(getActiveNamenode, std::string());
   MOCK_METHOD1(exist, bool(const char * path));
   MOCK_METHOD0(getFsStats, Hdfs::FileSystemStats());
+  MOCK_METHOD0(getServerDefaults, Hdfs::ServerDefaults());
   MOCK_METHOD2(truncate, bool(const char * src, int64_t size));
   MOCK_METHOD1(getDelegationToken, std::string(const char * renewer));
   MOCK_METHOD0(getDelegationToken, std::string());
@@ -106,6 +112,28 @@ public:
   MOCK_METHOD2(listEncryptionZones, bool(const int64_t id, std::vector<Hdfs::EncryptionZoneInfo> &));
   MOCK_METHOD0(listEncryptionZone, Hdfs::EncryptionZoneIterator());
   MOCK_METHOD0(listAllEncryptionZoneItems, std::vector<Hdfs::EncryptionZoneInfo>());
+  MOCK_METHOD2(createSnapshot, std::string(const char * path, const char * name));
+  MOCK_METHOD2(deleteSnapshot, void(const char * path, const char * name));
+  MOCK_METHOD3(renameSnapshot, void(const char * path, const char * oldName, const char * newName));
+  MOCK_METHOD1(allowSnapshot, void(const char * path));
+  MOCK_METHOD1(disallowSnapshot, void(const char * path));
+  MOCK_METHOD0(getSnapshottableDirListing, std::vector<Hdfs::SnapshottableDirectoryStatus>());
+  MOCK_METHOD3(getSnapshotDiffReport, Hdfs::SnapshotDiffReport(const char * path, const char * fromSnapshot, const char * toSnapshot));
+  MOCK_METHOD2(modifyAclEntries, void(const char * path, const std::vector<Hdfs::AclEntry> & aclSpec));
+  MOCK_METHOD2(removeAclEntries, void(const char * path, const std::vector<Hdfs::AclEntry> & aclSpec));
+  MOCK_METHOD1(removeDefaultAcl, void(const char * path));
+  MOCK_METHOD1(removeAcl, void(const char * path));
+  MOCK_METHOD2(setAcl, void(const char * path, const std::vector<Hdfs::AclEntry> & aclSpec));
+  MOCK_METHOD1(getAclStatus, Hdfs::AclStatus(const char * path));
+  MOCK_METHOD3(setXAttr, void(const char * path, const Hdfs::XAttr & xAttr, int flag));
+  MOCK_METHOD2(getXAttrs, std::vector<Hdfs::XAttr>(const char * path, const std::vector<Hdfs::XAttr> & xAttrs));
+  MOCK_METHOD1(listXAttrs, std::vector<Hdfs::XAttr>(const char * path));
+  MOCK_METHOD2(removeXAttr, void(const char * path, const Hdfs::XAttr & xAttr));
+  MOCK_METHOD1(getContentSummary, Hdfs::ContentSummary(const char * path));
+  MOCK_METHOD3(setQuota, void(const char * path, int64_t namespaceQuota, int64_t diskspaceQuota));
+  MOCK_METHOD4(createSymlink, void(const char * target, const char * link, const Hdfs::Permission & dirPerm, bool createParent));
+  MOCK_METHOD1(getLinkTarget, std::string(const char * path));
+  MOCK_METHOD1(getFileLinkInfo, Hdfs::FileStatus(const char * path));
 };
 
 #endif /* _HDFS_LIBHDFS3_MOCK_MOCKSOCKET_H_ */
@@ -76,11 +76,14 @@ public:
     MOCK_METHOD1(renewLease, void(const std::string & clientName));
     MOCK_METHOD2(recoverLease, bool(const std::string & src,
                            const std::string & clientName));
+    MOCK_METHOD1(isFileClosed, bool(const std::string & src));
     MOCK_METHOD0(getFsStats, std::vector<int64_t>());
+    MOCK_METHOD0(getServerDefaults, ServerDefaults());
     MOCK_METHOD1(metaSave, void(
           const std::string & filename));
     MOCK_METHOD2(getFileInfo, FileStatus(const std::string & src, bool *exist));
     MOCK_METHOD1(getFileLinkInfo, FileStatus(const std::string & src));
+    MOCK_METHOD1(getContentSummary, ContentSummary(const std::string & path));
     MOCK_METHOD3(setQuota, void(const std::string & path, int64_t namespaceQuota,
                         int64_t diskspaceQuota));
     MOCK_METHOD2(fsync, void(const std::string & src, const std::string & client));
@@ -99,9 +102,37 @@ public:
                            const std::string & startAfter, bool needLocation,
                            std::vector<FileStatus> & dl));
     MOCK_METHOD2(rename, bool(const std::string & src, const std::string & dst));
+    MOCK_METHOD3(rename2, void(const std::string & src, const std::string & dst, bool overwrite));
     MOCK_METHOD1(getDelegationToken, Token(const std::string & renewer) );
     MOCK_METHOD1(renewDelegationToken, int64_t(const Token & token));
     MOCK_METHOD1(cancelDelegationToken, void(const Token & token));
+    MOCK_METHOD2(createSnapshot, std::string(const std::string & snapshotRoot,
+                                             const std::string & snapshotName));
+    MOCK_METHOD2(deleteSnapshot, void(const std::string & snapshotRoot,
+                                      const std::string & snapshotName));
+    MOCK_METHOD3(renameSnapshot, void(const std::string & snapshotRoot,
+                                      const std::string & snapshotOldName,
+                                      const std::string & snapshotNewName));
+    MOCK_METHOD1(allowSnapshot, void(const std::string & snapshotRoot));
+    MOCK_METHOD1(disallowSnapshot, void(const std::string & snapshotRoot));
+    MOCK_METHOD1(getSnapshottableDirListing,
+                 void(std::vector<SnapshottableDirectoryStatus> & dirs));
+    MOCK_METHOD3(getSnapshotDiffReport, SnapshotDiffReport(const std::string & snapshotRoot,
+                 const std::string & fromSnapshot, const std::string & toSnapshot));
+    MOCK_METHOD2(modifyAclEntries, void(const std::string & src,
+                                        const std::vector<AclEntry> & aclSpec));
+    MOCK_METHOD2(removeAclEntries, void(const std::string & src,
+                                        const std::vector<AclEntry> & aclSpec));
+    MOCK_METHOD1(removeDefaultAcl, void(const std::string & src));
+    MOCK_METHOD1(removeAcl, void(const std::string & src));
+    MOCK_METHOD2(setAcl, void(const std::string & src,
+                              const std::vector<AclEntry> & aclSpec));
+    MOCK_METHOD1(getAclStatus, AclStatus(const std::string & src));
+    MOCK_METHOD3(setXAttr, void(const std::string & src, const XAttr & xAttr, int flag));
+    MOCK_METHOD2(getXAttrs, std::vector<XAttr>(const std::string & src,
+                                               const std::vector<XAttr> & xAttrs));
+    MOCK_METHOD1(listXAttrs, std::vector<XAttr>(const std::string & src));
+    MOCK_METHOD2(removeXAttr, void(const std::string & src, const XAttr & xAttr));
 };
 
 }
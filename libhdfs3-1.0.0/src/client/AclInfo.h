@@ -0,0 +1,176 @@
+/**
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+#ifndef _HDFS_LIBHDFS3_CLIENT_ACLINFO_H_
+#define _HDFS_LIBHDFS3_CLIENT_ACLINFO_H_
+
+#include <string>
+#include <vector>
+
+namespace Hdfs {
+
+/**
+ * An entry of the ACL of a file or directory.
+ */
+class AclEntry {
+public:
+    /**
+     * Whether the entry applies to the file itself, or is inherited
+     * by the children created in a directory.
+     */
+    enum Scope {
+        ACCESS = 0,
+        DEFAULT = 1
+    };
+
+    /**
+     * The class of users the entry applies to.
+     */
+    enum Type {
+        USER = 0,
+        GROUP = 1,
+        MASK = 2,
+        OTHER = 3
+    };
+
+    AclEntry() :
+        scope(ACCESS), type(USER), permissions(0) {
+    }
+
+    Scope getScope() const {
+        return scope;
+    }
+
+    void setScope(Scope scope) {
+        this->scope = scope;
+    }
+
+    Type getType() const {
+        return type;
+    }
+
+    void setType(Type type) {
+        this->type = type;
+    }
+
+    /**
+     * @return the name of the user or group, empty for the owner,
+     *  the group of the file, the mask and others.
+     */
+    const char * getName() const {
+        return name.c_str();
+    }
+
+    void setName(const char * name) {
+        this->name = name;
+    }
+
+    /**
+     * @return the permitted actions as the bits rwx, e.g. 5 for r-x.
+     */
+    int getPermissions() const {
+        return permissions;
+    }
+
+    void setPermissions(int permissions) {
+        this->permissions = permissions;
+    }
+
+private:
+    Scope scope;
+    Type type;
+    int permissions;
+    std::string name;
+};
+
+/**
+ * The ACL of a file or directory, with its owner, group and permission.
+ */
+class AclStatus {
+public:
+    AclStatus() :
+        sticky(false), permission(0), permissionSet(false) {
+    }
+
+    const char * getOwner() const {
+        return owner.c_str();
+    }
+
+    void setOwner(const char * owner) {
+        this->owner = owner;
+    }
+
+    const char * getGroup() const {
+        return group.c_str();
+    }
+
+    void setGroup(const char * group) {
+        this->group = group;
+    }
+
+    bool isSticky() const {
+        return sticky;
+    }
+
+    void setSticky(bool sticky) {
+        this->sticky = sticky;
+    }
+
+    /**
+     * @return the permission of the file, whose group bits are the mask
+     *  if it has an ACL.
+     */
+    short getPermission() const {
+        return permission;
+    }
+
+    void setPermission(short permission) {
+        this->permission = permission;
+        permissionSet = true;
+    }
+
+    /**
+     * @return false if the namenode, older than Hadoop 2.7, did not
+     *  return the permission.
+     */
+    bool hasPermission() const {
+        return permissionSet;
+    }
+
+    /**
+     * @return the entries besides the owner, group and other entries
+     *  of the permission.
+     */
+    const std::vector<AclEntry> & getEntries() const {
+        return entries;
+    }
+
+    std::vector<AclEntry> & getEntries() {
+        return entries;
+    }
+
+private:
+    std::string owner;
+    std::string group;
+    bool sticky;
+    short permission;
+    bool permissionSet;
+    std::vector<AclEntry> entries;
+};
+
+}
+#endif /* _HDFS_LIBHDFS3_CLIENT_ACLINFO_H_ */
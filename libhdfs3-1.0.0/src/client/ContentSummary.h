@@ -0,0 +1,116 @@
+/********************************************************************
+ * 2014 -
+ * open source under Apache License Version 2.0
+ ********************************************************************/
+/**
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+#ifndef _HDFS_LIBHDFS3_CLIENT_CONTENTSUMMARY_H_
+#define _HDFS_LIBHDFS3_CLIENT_CONTENTSUMMARY_H_
+
+#include <stdint.h>
+
+namespace Hdfs {
+
+/**
+ * The disk usage, file and directory counts and quotas of a directory tree.
+ */
+class ContentSummary {
+public:
+    ContentSummary() :
+        length(0), fileCount(0), directoryCount(0), quota(-1), spaceConsumed(0),
+        spaceQuota(-1) {
+    }
+
+    /**
+     * @return the total length in bytes of the files in the tree.
+     */
+    int64_t getLength() const {
+        return length;
+    }
+
+    void setLength(int64_t length) {
+        this->length = length;
+    }
+
+    /**
+     * @return the number of files in the tree.
+     */
+    int64_t getFileCount() const {
+        return fileCount;
+    }
+
+    void setFileCount(int64_t fileCount) {
+        this->fileCount = fileCount;
+    }
+
+    /**
+     * @return the number of directories in the tree, including its root.
+     */
+    int64_t getDirectoryCount() const {
+        return directoryCount;
+    }
+
+    void setDirectoryCount(int64_t directoryCount) {
+        this->directoryCount = directoryCount;
+    }
+
+    /**
+     * @return the namespace quota of the directory, -1 if it has none.
+     */
+    int64_t getQuota() const {
+        return quota;
+    }
+
+    void setQuota(int64_t quota) {
+        this->quota = quota;
+    }
+
+    /**
+     * @return the disk space in bytes consumed by the tree, including replicas.
+     */
+    int64_t getSpaceConsumed() const {
+        return spaceConsumed;
+    }
+
+    void setSpaceConsumed(int64_t spaceConsumed) {
+        this->spaceConsumed = spaceConsumed;
+    }
+
+    /**
+     * @return the disk space quota in bytes of the directory, -1 if it has none.
+     */
+    int64_t getSpaceQuota() const {
+        return spaceQuota;
+    }
+
+    void setSpaceQuota(int64_t spaceQuota) {
+        this->spaceQuota = spaceQuota;
+    }
+
+private:
+    int64_t length;
+    int64_t fileCount;
+    int64_t directoryCount;
+    int64_t quota;
+    int64_t spaceConsumed;
+    int64_t spaceQuota;
+};
+
+}
+
+#endif /* _HDFS_LIBHDFS3_CLIENT_CONTENTSUMMARY_H_ */
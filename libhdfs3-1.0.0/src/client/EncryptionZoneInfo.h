@@ -44,11 +44,11 @@ public:
         this->cryptoProtocolVersion = cryptoProtocolVersion;
     }
 
-    int getId() const {
+    int64_t getId() const {
         return id;
     }
 
-    void setId(int id) {
+    void setId(int64_t id) {
         this->id = id;
     }
 
@@ -189,11 +189,25 @@ void FileSystem::connect(const char * uri) {
 }
 
 static FileSystemWrapper * ConnectInternal(const char * uri,
-        const std::string & principal, const Token * token, Config & conf) {
+        const std::string & principal, const Token * token, Config & conf,
+        const char * proxyUser = NULL) {
     if (NULL == uri || 0 == strlen(uri)) {
         THROW(InvalidParameter, "Invalid HDFS uri.");
     }
 
+    if (proxyUser && strlen(proxyUser) > 0) {
+        /*
+         * The proxy user is the effective user, so that connections are cached
+         * per impersonated user, and the authenticated user is the real user.
+         */
+        FileSystemKey key(uri, proxyUser);
+        UserInfo user = key.getUser();
+        user.setRealUser(principal.empty() ?
+                         UserInfo::LocalUser().getEffectiveUser() : principal);
+        key.setUser(user);
+        return new FileSystemWrapper(shared_ptr<FileSystemInter>(new FileSystemImpl(key, conf)));
+    }
+
     FileSystemKey key(uri, principal.c_str());
 
     if (token) {
@@ -211,6 +225,19 @@ static FileSystemWrapper * ConnectInternal(const char * uri,
  * @param token token used to connect to hdfs
  */
 void FileSystem::connect(const char * uri, const char * username, const char * token) {
+    connect(uri, username, token, NULL);
+}
+
+/**
+ * Connect to hdfs with user or token, impersonating a proxy user
+ * 	username and token cannot be set at the same time
+ * @param uri connection uri.
+ * @param username user used to connect to hdfs
+ * @param token token used to connect to hdfs
+ * @param proxyUser user to impersonate, or NULL
+ */
+void FileSystem::connect(const char * uri, const char * username, const char * token,
+                         const char * proxyUser) {
     AuthMethod auth;
     std::string principal;
 
@@ -237,7 +264,7 @@ void FileSystem::connect(const char * uri, const char * username, const char * t
             principal = ExtractPrincipalFromTicketCache(sconf.getKerberosCachePath());
         }
 
-        impl = ConnectInternal(uri, principal, NULL, conf);
+        impl = ConnectInternal(uri, principal, NULL, conf, proxyUser);
         impl->filesystem->connect();
     } catch (...) {
         delete impl;
@@ -467,6 +494,59 @@ bool FileSystem::rename(const char * src, const char * dst) {
     return impl->filesystem->rename(src, dst);
 }
 
+/**
+ * To rename a path, atomically replacing the destination if overwrite is set.
+ * @param src old path.
+ * @param dst new path.
+ * @param overwrite overwrite dst if it is a file or an empty directory.
+ */
+void FileSystem::rename2(const char * src, const char * dst, bool overwrite) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->rename2(src, dst, overwrite);
+}
+
+/**
+ * To move the blocks of srcs to the end of trg and delete srcs.
+ * @param trg existing file.
+ * @param srcs existing files with the same block size and replication as trg.
+ */
+void FileSystem::concat(const char * trg, const std::vector<std::string> & srcs) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->concat(trg, srcs);
+}
+
+/**
+ * To start recovery of the lease on a file left open by a writer.
+ * @param path the file.
+ * @return true if the file is already closed.
+ */
+bool FileSystem::recoverLease(const char * path) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->recoverLease(path);
+}
+
+/**
+ * To get whether a file is closed.
+ * @param path the file.
+ * @return true if the file is closed.
+ */
+bool FileSystem::isFileClosed(const char * path) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->isFileClosed(path);
+}
+
 /**
  * To set working directory.
  * @param path new working directory.
@@ -491,6 +571,18 @@ std::string FileSystem::getWorkingDirectory() const {
     return impl->filesystem->getWorkingDirectory();
 }
 
+/**
+ * To get the RPC address of the namenode which serves the requests.
+ * @return the namenode address in the form of "host:port".
+ */
+std::string FileSystem::getActiveNamenode() const {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->getActiveNamenode();
+}
+
 /**
  * To test if the path exist.
  * @param path the path which is to be tested.
@@ -516,6 +608,18 @@ FileSystemStats FileSystem::getStats() const {
     return impl->filesystem->getFsStats();
 }
 
+/**
+ * To get the defaults the namenode applies to new files.
+ * @return the server defaults.
+ */
+ServerDefaults FileSystem::getServerDefaults() const {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->getServerDefaults();
+}
+
 /**
  * Truncate the file in the indicated path to the indicated size.
  * @param src The path to the file to be truncated
@@ -636,4 +740,299 @@ std::vector<EncryptionZoneInfo> FileSystem::listAllEncryptionZoneItems() {
     return impl->filesystem->listAllEncryptionZoneItems();
 }
 
+/**
+ * Create a snapshot of a snapshottable directory.
+ * @param path the path of the directory.
+ * @param name the name of the snapshot, NULL to let the namenode generate one.
+ * @return the path of the snapshot.
+ */
+std::string FileSystem::createSnapshot(const char * path, const char * name) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->createSnapshot(path, name);
+}
+
+/**
+ * Delete a snapshot of a snapshottable directory.
+ * @param path the path of the directory.
+ * @param name the name of the snapshot.
+ */
+void FileSystem::deleteSnapshot(const char * path, const char * name) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->deleteSnapshot(path, name);
+}
+
+/**
+ * Rename a snapshot of a snapshottable directory.
+ * @param path the path of the directory.
+ * @param oldName the old name of the snapshot.
+ * @param newName the new name of the snapshot.
+ */
+void FileSystem::renameSnapshot(const char * path,
+                                const char * oldName,
+                                const char * newName) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->renameSnapshot(path, oldName, newName);
+}
+
+/**
+ * Allow snapshots of a directory.
+ * @param path the path of the directory.
+ */
+void FileSystem::allowSnapshot(const char * path) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->allowSnapshot(path);
+}
+
+/**
+ * Disallow snapshots of a directory.
+ * @param path the path of the directory.
+ */
+void FileSystem::disallowSnapshot(const char * path) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->disallowSnapshot(path);
+}
+
+/**
+ * Get the snapshottable directories of the user.
+ * @return the snapshottable directories.
+ */
+std::vector<SnapshottableDirectoryStatus> FileSystem::getSnapshottableDirListing() {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->getSnapshottableDirListing();
+}
+
+/**
+ * Get the differences between two snapshots of a directory.
+ * @param path the path of the directory.
+ * @param fromSnapshot the name of the earlier snapshot, NULL for the current state.
+ * @param toSnapshot the name of the later snapshot, NULL for the current state.
+ * @return the difference report.
+ */
+SnapshotDiffReport FileSystem::getSnapshotDiffReport(const char * path,
+                                                     const char * fromSnapshot,
+                                                     const char * toSnapshot) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->getSnapshotDiffReport(path, fromSnapshot, toSnapshot);
+}
+
+/**
+ * Get the disk usage, file and directory counts and quotas of a directory tree.
+ * @param path the root of the tree.
+ * @return the content summary.
+ */
+ContentSummary FileSystem::getContentSummary(const char * path) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->getContentSummary(path);
+}
+
+/**
+ * Set the namespace and disk space quotas of a directory.
+ * @param path the path of the directory.
+ * @param namespaceQuota the maximum number of files and directories in the tree.
+ * @param diskspaceQuota the maximum disk space in bytes consumed by the tree.
+ */
+void FileSystem::setQuota(const char * path, int64_t namespaceQuota, int64_t diskspaceQuota) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->setQuota(path, namespaceQuota, diskspaceQuota);
+}
+
+/**
+ * Create a symlink to a file or directory.
+ * @param target the path the link points to, which is not resolved or checked.
+ * @param link the path of the link.
+ * @param dirPerm the permission of the missing parent directories which are created.
+ * @param createParent whether to create missing parent directories of the link.
+ */
+void FileSystem::createSymlink(const char * target, const char * link,
+                               const Permission & dirPerm, bool createParent) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->createSymlink(target, link, dirPerm, createParent);
+}
+
+/**
+ * Get the target of a symlink.
+ * @param path the path of the symlink.
+ * @return the target the symlink points to.
+ */
+std::string FileSystem::getLinkTarget(const char * path) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->getLinkTarget(path);
+}
+
+/**
+ * Get the information of a path without resolving it if it is a symlink.
+ * @param path the path which information is to be returned.
+ * @return the path information, of the symlink itself if it is one.
+ */
+FileStatus FileSystem::getFileLinkInfo(const char * path) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->getFileLinkInfo(path);
+}
+
+/**
+ * Add or update entries of the ACL of a file or directory.
+ * @param path the path of the file or directory.
+ * @param aclSpec the entries to add or update.
+ */
+void FileSystem::modifyAclEntries(const char * path, const std::vector<AclEntry> & aclSpec) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->modifyAclEntries(path, aclSpec);
+}
+
+/**
+ * Remove entries of the ACL of a file or directory.
+ * @param path the path of the file or directory.
+ * @param aclSpec the entries to remove, whose permissions are ignored.
+ */
+void FileSystem::removeAclEntries(const char * path, const std::vector<AclEntry> & aclSpec) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->removeAclEntries(path, aclSpec);
+}
+
+/**
+ * Remove the default entries of the ACL of a directory.
+ * @param path the path of the directory.
+ */
+void FileSystem::removeDefaultAcl(const char * path) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->removeDefaultAcl(path);
+}
+
+/**
+ * Remove all entries of the ACL of a file or directory.
+ * @param path the path of the file or directory.
+ */
+void FileSystem::removeAcl(const char * path) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->removeAcl(path);
+}
+
+/**
+ * Replace the ACL of a file or directory.
+ * @param path the path of the file or directory.
+ * @param aclSpec the entries of the new ACL.
+ */
+void FileSystem::setAcl(const char * path, const std::vector<AclEntry> & aclSpec) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->setAcl(path, aclSpec);
+}
+
+/**
+ * Get the ACL of a file or directory.
+ * @param path the path of the file or directory.
+ * @return the ACL, with the owner, group and permission of the file.
+ */
+AclStatus FileSystem::getAclStatus(const char * path) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->getAclStatus(path);
+}
+
+/**
+ * Set an extended attribute of a file or directory.
+ * @param path the path of the file or directory.
+ * @param xAttr the attribute with its value.
+ * @param flag the bits of XAttr::SetFlag.
+ */
+void FileSystem::setXAttr(const char * path, const XAttr & xAttr, int flag) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->setXAttr(path, xAttr, flag);
+}
+
+/**
+ * Get extended attributes of a file or directory with their values.
+ * @param path the path of the file or directory.
+ * @param xAttrs the attributes to get, all of them if it is empty.
+ * @return the attributes.
+ */
+std::vector<XAttr> FileSystem::getXAttrs(const char * path, const std::vector<XAttr> & xAttrs) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->getXAttrs(path, xAttrs);
+}
+
+/**
+ * List the extended attributes of a file or directory, without their values.
+ * @param path the path of the file or directory.
+ * @return the attributes.
+ */
+std::vector<XAttr> FileSystem::listXAttrs(const char * path) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    return impl->filesystem->listXAttrs(path);
+}
+
+/**
+ * Remove an extended attribute of a file or directory.
+ * @param path the path of the file or directory.
+ * @param xAttr the attribute, whose value is ignored.
+ */
+void FileSystem::removeXAttr(const char * path, const XAttr & xAttr) {
+    if (!impl) {
+        THROW(HdfsIOException, "FileSystem: not connected.");
+    }
+
+    impl->filesystem->removeXAttr(path, xAttr);
+}
+
 }
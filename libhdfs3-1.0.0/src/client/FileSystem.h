@@ -23,11 +23,16 @@
 #define _HDFS_LIBHDFS3_CLIENT_FILESYSTEM_H_
 
 #include "BlockLocation.h"
+#include "ContentSummary.h"
 #include "DirectoryIterator.h"
 #include "EncryptionZoneIterator.h"
 #include "FileStatus.h"
 #include "FileSystemStats.h"
+#include "ServerDefaults.h"
 #include "EncryptionZoneInfo.h"
+#include "SnapshotInfo.h"
+#include "AclInfo.h"
+#include "XAttrInfo.h"
 #include "Permission.h"
 #include "XmlConfig.h"
 
@@ -82,6 +87,17 @@ public:
      */
     void connect(const char * uri, const char * username, const char * token);
 
+    /**
+     * Connect to hdfs with user or token, impersonating a proxy user
+     * 	username and token cannot be set at the same time
+     * @param uri connection uri.
+     * @param username user used to connect to hdfs
+     * @param token token used to connect to hdfs
+     * @param proxyUser user to impersonate, or NULL
+     */
+    void connect(const char * uri, const char * username, const char * token,
+                 const char * proxyUser);
+
     /**
      * disconnect from hdfs
      */
@@ -209,6 +225,35 @@ public:
      */
     bool rename(const char * src, const char * dst);
 
+    /**
+     * To move the blocks of srcs to the end of trg and delete srcs.
+     * @param trg existing file.
+     * @param srcs existing files with the same block size and replication as trg.
+     */
+    void concat(const char * trg, const std::vector<std::string> & srcs);
+
+    /**
+     * To start recovery of the lease on a file left open by a writer.
+     * @param path the file.
+     * @return true if the file is already closed.
+     */
+    bool recoverLease(const char * path);
+
+    /**
+     * To get whether a file is closed.
+     * @param path the file.
+     * @return true if the file is closed.
+     */
+    bool isFileClosed(const char * path);
+
+    /**
+     * To rename a path, atomically replacing the destination if overwrite is set.
+     * @param src old path.
+     * @param dst new path.
+     * @param overwrite overwrite dst if it is a file or an empty directory.
+     */
+    void rename2(const char * src, const char * dst, bool overwrite);
+
     /**
      * To set working directory.
      * @param path new working directory.
@@ -221,6 +266,12 @@ public:
      */
     std::string getWorkingDirectory() const;
 
+    /**
+     * To get the RPC address of the namenode which serves the requests.
+     * @return the namenode address in the form of "host:port".
+     */
+    std::string getActiveNamenode() const;
+
     /**
      * To test if the path exist.
      * @param path the path which is to be tested.
@@ -234,6 +285,12 @@ public:
      */
     FileSystemStats getStats() const;
 
+    /**
+     * To get the defaults the namenode applies to new files.
+     * @return the server defaults.
+     */
+    ServerDefaults getServerDefaults() const;
+
     /**
      * Truncate the file in the indicated path to the indicated size.
      * @param src The path to the file to be truncated
@@ -307,6 +364,169 @@ public:
     */
     std::vector<EncryptionZoneInfo> listAllEncryptionZoneItems();
 
+    /**
+     * Create a snapshot of a snapshottable directory.
+     * @param path the path of the directory.
+     * @param name the name of the snapshot, NULL to let the namenode generate one.
+     * @return the path of the snapshot.
+     */
+    std::string createSnapshot(const char * path, const char * name);
+
+    /**
+     * Delete a snapshot of a snapshottable directory.
+     * @param path the path of the directory.
+     * @param name the name of the snapshot.
+     */
+    void deleteSnapshot(const char * path, const char * name);
+
+    /**
+     * Rename a snapshot of a snapshottable directory.
+     * @param path the path of the directory.
+     * @param oldName the old name of the snapshot.
+     * @param newName the new name of the snapshot.
+     */
+    void renameSnapshot(const char * path, const char * oldName, const char * newName);
+
+    /**
+     * Allow snapshots of a directory.
+     * @param path the path of the directory.
+     */
+    void allowSnapshot(const char * path);
+
+    /**
+     * Disallow snapshots of a directory.
+     * @param path the path of the directory.
+     */
+    void disallowSnapshot(const char * path);
+
+    /**
+     * Get the snapshottable directories of the user.
+     * @return the snapshottable directories.
+     */
+    std::vector<SnapshottableDirectoryStatus> getSnapshottableDirListing();
+
+    /**
+     * Get the differences between two snapshots of a directory.
+     * @param path the path of the directory.
+     * @param fromSnapshot the name of the earlier snapshot, NULL for the current state.
+     * @param toSnapshot the name of the later snapshot, NULL for the current state.
+     * @return the difference report.
+     */
+    SnapshotDiffReport getSnapshotDiffReport(const char * path,
+                                             const char * fromSnapshot,
+                                             const char * toSnapshot);
+
+    /**
+     * Add or update entries of the ACL of a file or directory.
+     * @param path the path of the file or directory.
+     * @param aclSpec the entries to add or update.
+     */
+    void modifyAclEntries(const char * path, const std::vector<AclEntry> & aclSpec);
+
+    /**
+     * Remove entries of the ACL of a file or directory.
+     * @param path the path of the file or directory.
+     * @param aclSpec the entries to remove, whose permissions are ignored.
+     */
+    void removeAclEntries(const char * path, const std::vector<AclEntry> & aclSpec);
+
+    /**
+     * Remove the default entries of the ACL of a directory.
+     * @param path the path of the directory.
+     */
+    void removeDefaultAcl(const char * path);
+
+    /**
+     * Remove all entries of the ACL of a file or directory.
+     * @param path the path of the file or directory.
+     */
+    void removeAcl(const char * path);
+
+    /**
+     * Replace the ACL of a file or directory.
+     * @param path the path of the file or directory.
+     * @param aclSpec the entries of the new ACL.
+     */
+    void setAcl(const char * path, const std::vector<AclEntry> & aclSpec);
+
+    /**
+     * Get the ACL of a file or directory.
+     * @param path the path of the file or directory.
+     * @return the ACL, with the owner, group and permission of the file.
+     */
+    AclStatus getAclStatus(const char * path);
+
+    /**
+     * Set an extended attribute of a file or directory.
+     * @param path the path of the file or directory.
+     * @param xAttr the attribute with its value.
+     * @param flag the bits of XAttr::SetFlag.
+     */
+    void setXAttr(const char * path, const XAttr & xAttr, int flag);
+
+    /**
+     * Get extended attributes of a file or directory with their values.
+     * @param path the path of the file or directory.
+     * @param xAttrs the attributes to get, all of them if it is empty.
+     * @return the attributes.
+     */
+    std::vector<XAttr> getXAttrs(const char * path, const std::vector<XAttr> & xAttrs);
+
+    /**
+     * List the extended attributes of a file or directory, without their values.
+     * @param path the path of the file or directory.
+     * @return the attributes.
+     */
+    std::vector<XAttr> listXAttrs(const char * path);
+
+    /**
+     * Remove an extended attribute of a file or directory.
+     * @param path the path of the file or directory.
+     * @param xAttr the attribute, whose value is ignored.
+     */
+    void removeXAttr(const char * path, const XAttr & xAttr);
+
+    /**
+     * Get the disk usage, file and directory counts and quotas of a directory tree.
+     * @param path the root of the tree.
+     * @return the content summary.
+     */
+    ContentSummary getContentSummary(const char * path);
+
+    /**
+     * Set the namespace and disk space quotas of a directory.
+     * @param path the path of the directory.
+     * @param namespaceQuota the maximum number of files and directories in the tree,
+     *        INT64_MAX to leave it unchanged or -1 to clear it.
+     * @param diskspaceQuota the maximum disk space in bytes consumed by the tree,
+     *        INT64_MAX to leave it unchanged or -1 to clear it.
+     */
+    void setQuota(const char * path, int64_t namespaceQuota, int64_t diskspaceQuota);
+
+    /**
+     * Create a symlink to a file or directory.
+     * @param target the path the link points to, which is not resolved or checked.
+     * @param link the path of the link.
+     * @param dirPerm the permission of the missing parent directories which are created.
+     * @param createParent whether to create missing parent directories of the link.
+     */
+    void createSymlink(const char * target, const char * link,
+                       const Permission & dirPerm, bool createParent);
+
+    /**
+     * Get the target of a symlink.
+     * @param path the path of the symlink.
+     * @return the target the symlink points to.
+     */
+    std::string getLinkTarget(const char * path);
+
+    /**
+     * Get the information of a path without resolving it if it is a symlink.
+     * @param path the path which information is to be returned.
+     * @return the path information, of the symlink itself if it is one.
+     */
+    FileStatus getFileLinkInfo(const char * path);
+
 
 private:
     Config conf;
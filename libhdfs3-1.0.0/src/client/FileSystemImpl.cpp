@@ -493,6 +493,93 @@ bool FileSystemImpl::rename(const char * src, const char * dst) {
     return nn->rename(getStandardPath(src), getStandardPath(dst));
 }
 
+/**
+ * To rename a path, atomically replacing the destination if overwrite is set.
+ * @param src old path.
+ * @param dst new path.
+ * @param overwrite overwrite dst if it is a file or an empty directory.
+ */
+void FileSystemImpl::rename2(const char * src, const char * dst, bool overwrite) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == src || !strlen(src)) {
+        THROW(InvalidParameter, "Invalid input: src should not be empty");
+    }
+
+    if (NULL == dst || !strlen(dst)) {
+        THROW(InvalidParameter, "Invalid input: dst should not be empty");
+    }
+
+    nn->rename2(getStandardPath(src), getStandardPath(dst), overwrite);
+}
+
+/**
+ * To move the blocks of srcs to the end of trg and delete srcs.
+ * @param trg existing file.
+ * @param srcs existing files with the same block size and replication as trg.
+ */
+void FileSystemImpl::concat(const char * trg, const std::vector<std::string> & srcs) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == trg || !strlen(trg)) {
+        THROW(InvalidParameter, "Invalid input: trg should not be empty");
+    }
+
+    if (srcs.empty()) {
+        THROW(InvalidParameter, "Invalid input: srcs should not be empty");
+    }
+
+    std::vector<std::string> paths;
+
+    for (size_t i = 0; i < srcs.size(); ++i) {
+        if (srcs[i].empty()) {
+            THROW(InvalidParameter, "Invalid input: src should not be empty");
+        }
+
+        paths.push_back(getStandardPath(srcs[i].c_str()));
+    }
+
+    nn->concat(getStandardPath(trg), paths);
+}
+
+/**
+ * To start recovery of the lease on a file left open by a writer.
+ * @param path the file.
+ * @return true if the file is already closed.
+ */
+bool FileSystemImpl::recoverLease(const char * path) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    return nn->recoverLease(getStandardPath(path), clientName);
+}
+
+/**
+ * To get whether a file is closed.
+ * @param path the file.
+ * @return true if the file is closed.
+ */
+bool FileSystemImpl::isFileClosed(const char * path) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    return nn->isFileClosed(getStandardPath(path));
+}
+
 /**
  * To set working directory.
  * @param path new working directory.
@@ -519,6 +606,18 @@ std::string FileSystemImpl::getWorkingDirectory() const {
     return workingDir;
 }
 
+/**
+ * To get the RPC address of the namenode which serves the requests.
+ * @return the namenode address in the form of "host:port".
+ */
+std::string FileSystemImpl::getActiveNamenode() const {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    return nn->getRpcAddress();
+}
+
 /**
  * To test if the path exist.
  * @param path the path which is to be tested.
@@ -555,8 +654,21 @@ FileSystemStats FileSystemImpl::getFsStats() {
     }
 
     std::vector<int64_t> retval = nn->getFsStats();
-    assert(retval.size() >= 3);
-    return FileSystemStats(retval[0], retval[1], retval[2]);
+    assert(retval.size() >= 6);
+    return FileSystemStats(retval[0], retval[1], retval[2], retval[3],
+                           retval[4], retval[5]);
+}
+
+/**
+ * To get the defaults the namenode applies to new files.
+ * @return the server defaults.
+ */
+ServerDefaults FileSystemImpl::getServerDefaults() {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    return nn->getServerDefaults();
 }
 
 /**
@@ -863,5 +975,412 @@ std::vector<EncryptionZoneInfo> FileSystemImpl::listAllEncryptionZoneItems() {
     return retval;
 }
 
+/**
+ * Create a snapshot of a snapshottable directory.
+ * @param path the path of the directory.
+ * @param name the name of the snapshot, NULL to let the namenode generate one.
+ * @return the path of the snapshot.
+ */
+std::string FileSystemImpl::createSnapshot(const char * path, const char * name) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    return nn->createSnapshot(getStandardPath(path), name ? name : "");
+}
+
+/**
+ * Delete a snapshot of a snapshottable directory.
+ * @param path the path of the directory.
+ * @param name the name of the snapshot.
+ */
+void FileSystemImpl::deleteSnapshot(const char * path, const char * name) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    if (NULL == name || !strlen(name)) {
+        THROW(InvalidParameter, "Invalid input: snapshot name should not be empty");
+    }
+
+    nn->deleteSnapshot(getStandardPath(path), name);
+}
+
+/**
+ * Rename a snapshot of a snapshottable directory.
+ * @param path the path of the directory.
+ * @param oldName the old name of the snapshot.
+ * @param newName the new name of the snapshot.
+ */
+void FileSystemImpl::renameSnapshot(const char * path,
+                                    const char * oldName,
+                                    const char * newName) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    if (NULL == oldName || !strlen(oldName)) {
+        THROW(InvalidParameter, "Invalid input: old snapshot name should not be empty");
+    }
+
+    if (NULL == newName || !strlen(newName)) {
+        THROW(InvalidParameter, "Invalid input: new snapshot name should not be empty");
+    }
+
+    nn->renameSnapshot(getStandardPath(path), oldName, newName);
+}
+
+/**
+ * Allow snapshots of a directory.
+ * @param path the path of the directory.
+ */
+void FileSystemImpl::allowSnapshot(const char * path) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    nn->allowSnapshot(getStandardPath(path));
+}
+
+/**
+ * Disallow snapshots of a directory.
+ * @param path the path of the directory.
+ */
+void FileSystemImpl::disallowSnapshot(const char * path) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    nn->disallowSnapshot(getStandardPath(path));
+}
+
+/**
+ * Get the snapshottable directories of the user.
+ * @return the snapshottable directories.
+ */
+std::vector<SnapshottableDirectoryStatus> FileSystemImpl::getSnapshottableDirListing() {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    std::vector<SnapshottableDirectoryStatus> retval;
+    nn->getSnapshottableDirListing(retval);
+    return retval;
+}
+
+/**
+ * Get the differences between two snapshots of a directory.
+ * @param path the path of the directory.
+ * @param fromSnapshot the name of the earlier snapshot, NULL for the current state.
+ * @param toSnapshot the name of the later snapshot, NULL for the current state.
+ * @return the difference report.
+ */
+SnapshotDiffReport FileSystemImpl::getSnapshotDiffReport(const char * path,
+                                                         const char * fromSnapshot,
+                                                         const char * toSnapshot) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    return nn->getSnapshotDiffReport(getStandardPath(path), fromSnapshot ? fromSnapshot : "",
+                                     toSnapshot ? toSnapshot : "");
+}
+
+/**
+ * Get the disk usage, file and directory counts and quotas of a directory tree.
+ * @param path the root of the tree.
+ * @return the content summary.
+ */
+ContentSummary FileSystemImpl::getContentSummary(const char * path) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    return nn->getContentSummary(getStandardPath(path));
+}
+
+/**
+ * Set the namespace and disk space quotas of a directory.
+ * @param path the path of the directory.
+ * @param namespaceQuota the maximum number of files and directories in the tree.
+ * @param diskspaceQuota the maximum disk space in bytes consumed by the tree.
+ */
+void FileSystemImpl::setQuota(const char * path, int64_t namespaceQuota,
+                              int64_t diskspaceQuota) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    nn->setQuota(getStandardPath(path), namespaceQuota, diskspaceQuota);
+}
+
+/**
+ * Create a symlink to a file or directory.
+ * @param target the path the link points to, which is not resolved or checked.
+ * @param link the path of the link.
+ * @param dirPerm the permission of the missing parent directories which are created.
+ * @param createParent whether to create missing parent directories of the link.
+ */
+void FileSystemImpl::createSymlink(const char * target, const char * link,
+                                   const Permission & dirPerm, bool createParent) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == target || !strlen(target)) {
+        THROW(InvalidParameter, "Invalid input: target should not be empty");
+    }
+
+    if (NULL == link || !strlen(link)) {
+        THROW(InvalidParameter, "Invalid input: link should not be empty");
+    }
+
+    nn->createSymlink(target, getStandardPath(link), dirPerm, createParent);
+}
+
+/**
+ * Get the target of a symlink.
+ * @param path the path of the symlink.
+ * @return the target the symlink points to.
+ */
+std::string FileSystemImpl::getLinkTarget(const char * path) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    return nn->getLinkTarget(getStandardPath(path));
+}
+
+/**
+ * Get the information of a path without resolving it if it is a symlink.
+ * @param path the path which information is to be returned.
+ * @return the path information, of the symlink itself if it is one.
+ */
+FileStatus FileSystemImpl::getFileLinkInfo(const char * path) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    return nn->getFileLinkInfo(getStandardPath(path));
+}
+
+/**
+ * Add or update entries of the ACL of a file or directory.
+ * @param path the path of the file or directory.
+ * @param aclSpec the entries to add or update.
+ */
+void FileSystemImpl::modifyAclEntries(const char * path, const std::vector<AclEntry> & aclSpec) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    nn->modifyAclEntries(getStandardPath(path), aclSpec);
+}
+
+/**
+ * Remove entries of the ACL of a file or directory.
+ * @param path the path of the file or directory.
+ * @param aclSpec the entries to remove, whose permissions are ignored.
+ */
+void FileSystemImpl::removeAclEntries(const char * path, const std::vector<AclEntry> & aclSpec) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    nn->removeAclEntries(getStandardPath(path), aclSpec);
+}
+
+/**
+ * Remove the default entries of the ACL of a directory.
+ * @param path the path of the directory.
+ */
+void FileSystemImpl::removeDefaultAcl(const char * path) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    nn->removeDefaultAcl(getStandardPath(path));
+}
+
+/**
+ * Remove all entries of the ACL of a file or directory.
+ * @param path the path of the file or directory.
+ */
+void FileSystemImpl::removeAcl(const char * path) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    nn->removeAcl(getStandardPath(path));
+}
+
+/**
+ * Replace the ACL of a file or directory.
+ * @param path the path of the file or directory.
+ * @param aclSpec the entries of the new ACL.
+ */
+void FileSystemImpl::setAcl(const char * path, const std::vector<AclEntry> & aclSpec) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    nn->setAcl(getStandardPath(path), aclSpec);
+}
+
+/**
+ * Get the ACL of a file or directory.
+ * @param path the path of the file or directory.
+ * @return the ACL, with the owner, group and permission of the file.
+ */
+AclStatus FileSystemImpl::getAclStatus(const char * path) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    std::string src = getStandardPath(path);
+    AclStatus retval = nn->getAclStatus(src);
+
+    if (!retval.hasPermission()) {
+        FileStatus status = nn->getFileInfo(src, NULL);
+        retval.setPermission(status.getPermission().toShort());
+    }
+
+    return retval;
+}
+
+/**
+ * Set an extended attribute of a file or directory.
+ * @param path the path of the file or directory.
+ * @param xAttr the attribute with its value.
+ * @param flag the bits of XAttr::SetFlag.
+ */
+void FileSystemImpl::setXAttr(const char * path, const XAttr & xAttr, int flag) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    nn->setXAttr(getStandardPath(path), xAttr, flag);
+}
+
+/**
+ * Get extended attributes of a file or directory with their values.
+ * @param path the path of the file or directory.
+ * @param xAttrs the attributes to get, all of them if it is empty.
+ * @return the attributes.
+ */
+std::vector<XAttr> FileSystemImpl::getXAttrs(const char * path, const std::vector<XAttr> & xAttrs) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    return nn->getXAttrs(getStandardPath(path), xAttrs);
+}
+
+/**
+ * List the extended attributes of a file or directory, without their values.
+ * @param path the path of the file or directory.
+ * @return the attributes.
+ */
+std::vector<XAttr> FileSystemImpl::listXAttrs(const char * path) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    return nn->listXAttrs(getStandardPath(path));
+}
+
+/**
+ * Remove an extended attribute of a file or directory.
+ * @param path the path of the file or directory.
+ * @param xAttr the attribute, whose value is ignored.
+ */
+void FileSystemImpl::removeXAttr(const char * path, const XAttr & xAttr) {
+    if (!nn) {
+        THROW(HdfsIOException, "FileSystemImpl: not connected.");
+    }
+
+    if (NULL == path || !strlen(path)) {
+        THROW(InvalidParameter, "Invalid input: path should not be empty");
+    }
+
+    nn->removeXAttr(getStandardPath(path), xAttr);
+}
+
 }
 }
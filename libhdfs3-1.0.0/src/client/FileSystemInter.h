@@ -26,12 +26,17 @@
 #include <vector>
 
 #include "BlockLocation.h"
+#include "ContentSummary.h"
 #include "DirectoryIterator.h"
 #include "EncryptionZoneIterator.h"
 #include "FileStatus.h"
 #include "FileSystemKey.h"
 #include "FileSystemStats.h"
+#include "ServerDefaults.h"
 #include "EncryptionZoneInfo.h"
+#include "SnapshotInfo.h"
+#include "AclInfo.h"
+#include "XAttrInfo.h"
 #include "PeerCache.h"
 #include "Permission.h"
 #include "server/LocatedBlocks.h"
@@ -215,6 +220,35 @@ public:
      */
     virtual bool rename(const char * src, const char * dst) = 0;
 
+    /**
+     * To move the blocks of srcs to the end of trg and delete srcs.
+     * @param trg existing file.
+     * @param srcs existing files with the same block size and replication as trg.
+     */
+    virtual void concat(const char * trg, const std::vector<std::string> & srcs) = 0;
+
+    /**
+     * To start recovery of the lease on a file left open by a writer.
+     * @param path the file.
+     * @return true if the file is already closed.
+     */
+    virtual bool recoverLease(const char * path) = 0;
+
+    /**
+     * To get whether a file is closed.
+     * @param path the file.
+     * @return true if the file is closed.
+     */
+    virtual bool isFileClosed(const char * path) = 0;
+
+    /**
+     * To rename a path, atomically replacing the destination if overwrite is set.
+     * @param src old path.
+     * @param dst new path.
+     * @param overwrite overwrite dst if it is a file or an empty directory.
+     */
+    virtual void rename2(const char * src, const char * dst, bool overwrite) = 0;
+
     /**
      * To set working directory.
      * @param path new working directory.
@@ -227,6 +261,12 @@ public:
      */
     virtual std::string getWorkingDirectory() const = 0;
 
+    /**
+     * To get the RPC address of the namenode which serves the requests.
+     * @return the namenode address in the form of "host:port".
+     */
+    virtual std::string getActiveNamenode() const = 0;
+
     /**
      * To test if the path exist.
      * @param path the path which is to be tested.
@@ -240,6 +280,12 @@ public:
      */
     virtual FileSystemStats getFsStats() = 0;
 
+    /**
+     * To get the defaults the namenode applies to new files.
+     * @return the server defaults.
+     */
+    virtual ServerDefaults getServerDefaults() = 0;
+
     /**
      * Truncate the file in the indicated path to the indicated size.
      * @param src The path we will find the file to be truncated.
@@ -521,6 +567,172 @@ public:
      * @return Return a vector of encryption zones information..
      */
     virtual std::vector<EncryptionZoneInfo> listAllEncryptionZoneItems() = 0; 
+
+    /**
+     * Create a snapshot of a snapshottable directory.
+     * @param path the path of the directory.
+     * @param name the name of the snapshot, NULL to let the namenode generate one.
+     * @return the path of the snapshot.
+     */
+    virtual std::string createSnapshot(const char * path, const char * name) = 0;
+
+    /**
+     * Delete a snapshot of a snapshottable directory.
+     * @param path the path of the directory.
+     * @param name the name of the snapshot.
+     */
+    virtual void deleteSnapshot(const char * path, const char * name) = 0;
+
+    /**
+     * Rename a snapshot of a snapshottable directory.
+     * @param path the path of the directory.
+     * @param oldName the old name of the snapshot.
+     * @param newName the new name of the snapshot.
+     */
+    virtual void renameSnapshot(const char * path,
+                                const char * oldName,
+                                const char * newName) = 0;
+
+    /**
+     * Allow snapshots of a directory.
+     * @param path the path of the directory.
+     */
+    virtual void allowSnapshot(const char * path) = 0;
+
+    /**
+     * Disallow snapshots of a directory.
+     * @param path the path of the directory.
+     */
+    virtual void disallowSnapshot(const char * path) = 0;
+
+    /**
+     * Get the snapshottable directories of the user.
+     * @return the snapshottable directories.
+     */
+    virtual std::vector<SnapshottableDirectoryStatus> getSnapshottableDirListing() = 0;
+
+    /**
+     * Get the differences between two snapshots of a directory.
+     * @param path the path of the directory.
+     * @param fromSnapshot the name of the earlier snapshot, NULL for the current state.
+     * @param toSnapshot the name of the later snapshot, NULL for the current state.
+     * @return the difference report.
+     */
+    virtual SnapshotDiffReport getSnapshotDiffReport(const char * path,
+                                                     const char * fromSnapshot,
+                                                     const char * toSnapshot) = 0;
+
+    /**
+     * Add or update entries of the ACL of a file or directory.
+     * @param path the path of the file or directory.
+     * @param aclSpec the entries to add or update.
+     */
+    virtual void modifyAclEntries(const char * path, const std::vector<AclEntry> & aclSpec) = 0;
+
+    /**
+     * Remove entries of the ACL of a file or directory.
+     * @param path the path of the file or directory.
+     * @param aclSpec the entries to remove, whose permissions are ignored.
+     */
+    virtual void removeAclEntries(const char * path, const std::vector<AclEntry> & aclSpec) = 0;
+
+    /**
+     * Remove the default entries of the ACL of a directory.
+     * @param path the path of the directory.
+     */
+    virtual void removeDefaultAcl(const char * path) = 0;
+
+    /**
+     * Remove all entries of the ACL of a file or directory.
+     * @param path the path of the file or directory.
+     */
+    virtual void removeAcl(const char * path) = 0;
+
+    /**
+     * Replace the ACL of a file or directory.
+     * @param path the path of the file or directory.
+     * @param aclSpec the entries of the new ACL.
+     */
+    virtual void setAcl(const char * path, const std::vector<AclEntry> & aclSpec) = 0;
+
+    /**
+     * Get the ACL of a file or directory.
+     * @param path the path of the file or directory.
+     * @return the ACL, with the owner, group and permission of the file.
+     */
+    virtual AclStatus getAclStatus(const char * path) = 0;
+
+    /**
+     * Set an extended attribute of a file or directory.
+     * @param path the path of the file or directory.
+     * @param xAttr the attribute with its value.
+     * @param flag the bits of XAttr::SetFlag.
+     */
+    virtual void setXAttr(const char * path, const XAttr & xAttr, int flag) = 0;
+
+    /**
+     * Get extended attributes of a file or directory with their values.
+     * @param path the path of the file or directory.
+     * @param xAttrs the attributes to get, all of them if it is empty.
+     * @return the attributes.
+     */
+    virtual std::vector<XAttr> getXAttrs(const char * path, const std::vector<XAttr> & xAttrs) = 0;
+
+    /**
+     * List the extended attributes of a file or directory, without their values.
+     * @param path the path of the file or directory.
+     * @return the attributes.
+     */
+    virtual std::vector<XAttr> listXAttrs(const char * path) = 0;
+
+    /**
+     * Remove an extended attribute of a file or directory.
+     * @param path the path of the file or directory.
+     * @param xAttr the attribute, whose value is ignored.
+     */
+    virtual void removeXAttr(const char * path, const XAttr & xAttr) = 0;
+
+    /**
+     * Get the disk usage, file and directory counts and quotas of a directory tree.
+     * @param path the root of the tree.
+     * @return the content summary.
+     */
+    virtual ContentSummary getContentSummary(const char * path) = 0;
+
+    /**
+     * Set the namespace and disk space quotas of a directory.
+     * @param path the path of the directory.
+     * @param namespaceQuota the maximum number of files and directories in the tree,
+     *        INT64_MAX to leave it unchanged or -1 to clear it.
+     * @param diskspaceQuota the maximum disk space in bytes consumed by the tree,
+     *        INT64_MAX to leave it unchanged or -1 to clear it.
+     */
+    virtual void setQuota(const char * path, int64_t namespaceQuota,
+                          int64_t diskspaceQuota) = 0;
+
+    /**
+     * Create a symlink to a file or directory.
+     * @param target the path the link points to, which is not resolved or checked.
+     * @param link the path of the link.
+     * @param dirPerm the permission of the missing parent directories which are created.
+     * @param createParent whether to create missing parent directories of the link.
+     */
+    virtual void createSymlink(const char * target, const char * link,
+                               const Permission & dirPerm, bool createParent) = 0;
+
+    /**
+     * Get the target of a symlink.
+     * @param path the path of the symlink.
+     * @return the target the symlink points to.
+     */
+    virtual std::string getLinkTarget(const char * path) = 0;
+
+    /**
+     * Get the information of a path without resolving it if it is a symlink.
+     * @param path the path which information is to be returned.
+     * @return the path information, of the symlink itself if it is one.
+     */
+    virtual FileStatus getFileLinkInfo(const char * path) = 0;
 };
 
 }
@@ -35,7 +35,8 @@ public:
      * To construct a FileSystemStats.
      */
     FileSystemStats() :
-        capacity(-1), used(-1), remaining(-1) {
+        capacity(-1), used(-1), remaining(-1), underReplicated(-1),
+        corruptBlocks(-1), missingBlocks(-1) {
     }
 
     /**
@@ -45,7 +46,25 @@ public:
      * @param remaining available space on file system.
      */
     FileSystemStats(int64_t capacity, int64_t used, int64_t remaining) :
-        capacity(capacity), used(used), remaining(remaining) {
+        capacity(capacity), used(used), remaining(remaining), underReplicated(-1),
+        corruptBlocks(-1), missingBlocks(-1) {
+    }
+
+    /**
+     * To construct a FileSystemStats with given values and block counts.
+     * @param capacity the capacity of file system.
+     * @param used the space which has been used.
+     * @param remaining available space on file system.
+     * @param underReplicated the number of under replicated blocks.
+     * @param corruptBlocks the number of blocks with a corrupt replica.
+     * @param missingBlocks the number of blocks without any good replica.
+     */
+    FileSystemStats(int64_t capacity, int64_t used, int64_t remaining,
+                    int64_t underReplicated, int64_t corruptBlocks,
+                    int64_t missingBlocks) :
+        capacity(capacity), used(used), remaining(remaining),
+        underReplicated(underReplicated), corruptBlocks(corruptBlocks),
+        missingBlocks(missingBlocks) {
     }
 
     /**
@@ -72,10 +91,37 @@ public:
         return remaining;
     }
 
+    /**
+     * Return the number of under replicated blocks
+     * @return return under replicated blocks, -1 if unknown.
+     */
+    int64_t getUnderReplicated() {
+        return underReplicated;
+    }
+
+    /**
+     * Return the number of blocks with a corrupt replica
+     * @return return corrupt blocks, -1 if unknown.
+     */
+    int64_t getCorruptBlocks() {
+        return corruptBlocks;
+    }
+
+    /**
+     * Return the number of blocks without any good replica
+     * @return return missing blocks, -1 if unknown.
+     */
+    int64_t getMissingBlocks() {
+        return missingBlocks;
+    }
+
 private:
     int64_t capacity;
     int64_t used;
     int64_t remaining;
+    int64_t underReplicated;
+    int64_t corruptBlocks;
+    int64_t missingBlocks;
 
 };
 
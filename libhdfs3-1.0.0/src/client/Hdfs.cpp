@@ -38,6 +38,8 @@
 #include <vector>
 #include <string>
 #include <libxml/uri.h>
+#include <gssapi/gssapi.h>
+#include <gssapi/gssapi_krb5.h>
 
 #ifdef __cplusplus
 extern "C" {
@@ -83,6 +85,46 @@ static inline char * Strdup(const char * str) {
     return retval;
 }
 
+/* Copy binary data, which may contain NUL bytes, followed by a NUL. */
+static inline char * Bytesdup(const std::string & str) {
+    char * retval = new char[str.size() + 1];
+    memcpy(retval, str.data(), str.size());
+    retval[str.size()] = 0;
+    return retval;
+}
+
+/* The messages of a GSSAPI major and minor status. */
+static std::string GssErrorMessage(OM_uint32 major, OM_uint32 minor) {
+    const OM_uint32 codes[] = {major, minor};
+    const int types[] = {GSS_C_GSS_CODE, GSS_C_MECH_CODE};
+    std::string message;
+
+    for (int i = 0; i < 2; ++i) {
+        OM_uint32 status, context = 0;
+
+        do {
+            gss_buffer_desc buffer = GSS_C_EMPTY_BUFFER;
+
+            if (GSS_ERROR(gss_display_status(&status, codes[i], types[i], GSS_C_NO_OID,
+                                             &context, &buffer))) {
+                break;
+            }
+
+            if (buffer.length > 0) {
+                if (!message.empty()) {
+                    message += ": ";
+                }
+
+                message.append(static_cast<const char *>(buffer.value), buffer.length);
+            }
+
+            gss_release_buffer(&status, &buffer);
+        } while (context != 0);
+    }
+
+    return message;
+}
+
 using Hdfs::InputStream;
 using Hdfs::OutputStream;
 using Hdfs::FileSystem;
@@ -229,9 +271,17 @@ public:
     shared_ptr<Config> conf;
     std::string nn;
     std::string userName;
+    std::string proxyUser;
     tPort port;
 };
 
+/*
+ * Map an exception to errno. This differs from upstream libhdfs3 for C callers: an
+ * UnresolvedLinkException sets ENOLINK instead of EACCES, an NSQuotaExceededException
+ * EDQUOT instead of EINVAL and a ParentNotDirectoryException ENOTDIR instead of EACCES.
+ * Together with the mSymlink member of hdfsFileInfo, this is why the Rust binding checks
+ * that it links against this build, see "Changes to libhdfs3" in its README.
+ */
 static void handleException(Hdfs::exception_ptr error) {
     try {
         Hdfs::rethrow_exception(error);
@@ -280,15 +330,15 @@ static void handleException(Hdfs::exception_ptr error) {
     } catch (Hdfs::NotReplicatedYetException &) {
         errno = EINVAL;
     } catch (Hdfs::NSQuotaExceededException &) {
-        errno = EINVAL;
+        errno = EDQUOT;
     } catch (Hdfs::ParentNotDirectoryException &) {
-        errno = EACCES;
+        errno = ENOTDIR;
     } catch (Hdfs::ReplicaNotFoundException &) {
         errno = EACCES;
     } catch (Hdfs::SafeModeException &) {
         errno = EIO;
     } catch (Hdfs::UnresolvedLinkException &) {
-        errno = EACCES;
+        errno = ENOLINK;
     } catch (Hdfs::HdfsRpcException &) {
         errno = EIO;
     } catch (Hdfs::HdfsNetworkException &) {
@@ -479,6 +529,10 @@ hdfsFS hdfsBuilderConnect(struct hdfsBuilder * bld) {
 
         if (!bld->token.empty()) {
             fs->connect(uri.c_str(), NULL, bld->token.c_str());
+        } else if (!bld->proxyUser.empty()) {
+            fs->connect(uri.c_str(),
+                        bld->userName.empty() ? NULL : bld->userName.c_str(),
+                        NULL, bld->proxyUser.c_str());
         } else {
             fs->connect(uri.c_str());
         }
@@ -534,6 +588,11 @@ void hdfsBuilderSetUserName(struct hdfsBuilder * bld, const char * userName) {
     bld->userName = userName;
 }
 
+void hdfsBuilderSetProxyUser(struct hdfsBuilder * bld, const char * proxyUser) {
+    assert(bld && proxyUser && strlen(proxyUser) > 0 && bld->token.empty());
+    bld->proxyUser = proxyUser;
+}
+
 void hdfsBuilderSetKerbTicketCachePath(struct hdfsBuilder * bld,
                                        const char * kerbTicketCachePath) {
     assert(bld && kerbTicketCachePath && strlen(kerbTicketCachePath) > 0);
@@ -541,7 +600,8 @@ void hdfsBuilderSetKerbTicketCachePath(struct hdfsBuilder * bld,
 }
 
 void hdfsBuilderSetToken(struct hdfsBuilder * bld, const char * token) {
-    assert(bld && token && strlen(token) > 0 && bld->userName.empty());
+    assert(bld && token && strlen(token) > 0 && bld->userName.empty()
+           && bld->proxyUser.empty());
 
     try {
         bld->token = token;
@@ -791,6 +851,27 @@ tSize hdfsRead(hdfsFS fs, hdfsFile file, void * buffer, tSize length) {
     return -1;
 }
 
+tSize hdfsPread(hdfsFS fs, hdfsFile file, tOffset position, void * buffer,
+                tSize length) {
+    PARAMETER_ASSERT(fs && file && buffer && length > 0 && position >= 0, -1, EINVAL);
+    PARAMETER_ASSERT(file->isInput(), -1, EINVAL);
+
+    try {
+        return file->getInputStream().pread(static_cast<char *>(buffer), length,
+                                            position);
+    } catch (const Hdfs::HdfsEndOfStream & e) {
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
 tSize hdfsWrite(hdfsFS fs, hdfsFile file, const void * buffer, tSize length) {
     PARAMETER_ASSERT(fs && file && buffer && length > 0, -1, EINVAL);
     PARAMETER_ASSERT(!file->isInput(), -1, EINVAL);
@@ -921,6 +1002,81 @@ int hdfsRename(hdfsFS fs, const char * oldPath, const char * newPath) {
     return -1;
 }
 
+int hdfsRename2(hdfsFS fs, const char * oldPath, const char * newPath,
+                int overwrite) {
+    PARAMETER_ASSERT(fs && oldPath && strlen(oldPath) > 0, -1, EINVAL);
+    PARAMETER_ASSERT(newPath && strlen(newPath) > 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().rename2(oldPath, newPath, overwrite != 0);
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+int hdfsConcat(hdfsFS fs, const char * trg, const char ** srcs) {
+    PARAMETER_ASSERT(fs && trg && strlen(trg) > 0, -1, EINVAL);
+    PARAMETER_ASSERT(srcs && srcs[0], -1, EINVAL);
+
+    try {
+        std::vector<std::string> paths;
+
+        for (int i = 0; srcs[i]; ++i) {
+            paths.push_back(srcs[i]);
+        }
+
+        fs->getFilesystem().concat(trg, paths);
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+int hdfsRecoverLease(hdfsFS fs, const char * path) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0, -1, EINVAL);
+
+    try {
+        return fs->getFilesystem().recoverLease(path) ? 1 : 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+int hdfsIsFileClosed(hdfsFS fs, const char * path) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0, -1, EINVAL);
+
+    try {
+        return fs->getFilesystem().isFileClosed(path) ? 1 : 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
 char * hdfsGetWorkingDirectory(hdfsFS fs, char * buffer, size_t bufferSize) {
     PARAMETER_ASSERT(fs && buffer && bufferSize > 0, NULL, EINVAL);
 
@@ -940,6 +1096,25 @@ char * hdfsGetWorkingDirectory(hdfsFS fs, char * buffer, size_t bufferSize) {
     return NULL;
 }
 
+char * hdfsGetHomeDirectory(hdfsFS fs, char * buffer, size_t bufferSize) {
+    PARAMETER_ASSERT(fs && buffer && bufferSize > 0, NULL, EINVAL);
+
+    try {
+        std::string retval = fs->getFilesystem().getHomeDirectory();
+        PARAMETER_ASSERT(retval.length() + 1 <= bufferSize, NULL, ENOMEM);
+        strncpy(buffer, retval.c_str(), bufferSize);
+        return buffer;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
 int hdfsSetWorkingDirectory(hdfsFS fs, const char * path) {
     PARAMETER_ASSERT(fs && path && strlen(path) > 0, -1, EINVAL);
 
@@ -1006,10 +1181,12 @@ static void ConstructHdfsEncryptionFileInfo(hdfsEncryptionFileInfo * infoEn,
                                   Hdfs::FileEncryptionInfo* enStatus) {
     infoEn->mSuite = enStatus->getSuite();
     infoEn->mCryptoProtocolVersion = enStatus->getCryptoProtocolVersion();
-    infoEn->mKey = const_cast<char*>(enStatus->getKey().c_str());
-    infoEn->mKeyName = const_cast<char*>(enStatus->getKeyName().c_str());
-    infoEn->mIv = const_cast<char*>(enStatus->getIv().c_str());
-    infoEn->mEzKeyVersionName = const_cast<char*>(enStatus->getEzKeyVersionName().c_str());
+    infoEn->mKey = Bytesdup(enStatus->getKey());
+    infoEn->mKeyLength = enStatus->getKey().size();
+    infoEn->mKeyName = Strdup(enStatus->getKeyName().c_str());
+    infoEn->mIv = Bytesdup(enStatus->getIv());
+    infoEn->mIvLength = enStatus->getIv().size();
+    infoEn->mEzKeyVersionName = Strdup(enStatus->getEzKeyVersionName().c_str());
 }
 
 static void ConstructHdfsFileInfo(hdfsFileInfo * infos,
@@ -1021,7 +1198,8 @@ static void ConstructHdfsFileInfo(hdfsFileInfo * infos,
         infos[i].mGroup = Strdup(status[i].getGroup());
         infos[i].mKind =
             status[i].isDirectory() ?
-            kObjectKindDirectory : kObjectKindFile;
+            kObjectKindDirectory : (status[i].isSymlink() ? kObjectKindSymlink : kObjectKindFile);
+        infos[i].mSymlink = status[i].isSymlink() ? Strdup(status[i].getSymlink()) : NULL;
         infos[i].mLastAccess = status[i].getAccessTime() / 1000;
         infos[i].mLastMod = status[i].getModificationTime() / 1000;
         infos[i].mName = Strdup(status[i].getPath());
@@ -1091,6 +1269,69 @@ hdfsFileInfo * hdfsGetPathInfo(hdfsFS fs, const char * path) {
     return NULL;
 }
 
+hdfsFileInfo * hdfsGetPathLinkInfo(hdfsFS fs, const char * path) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0, NULL, EINVAL);
+    hdfsFileInfo * retval = NULL;
+
+    try {
+        retval = new hdfsFileInfo[1];
+        memset(retval, 0, sizeof(hdfsFileInfo));
+        std::vector<Hdfs::FileStatus> status(1);
+        status[0] = fs->getFilesystem().getFileLinkInfo(path);
+        ConstructHdfsFileInfo(retval, status);
+        return retval;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        hdfsFreeFileInfo(retval, 1);
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        hdfsFreeFileInfo(retval, 1);
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
+int hdfsCreateSymlink(hdfsFS fs, const char * target, const char * link,
+                      int createParent) {
+    PARAMETER_ASSERT(fs && target && strlen(target) > 0 && link && strlen(link) > 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().createSymlink(target, link, 0755, createParent != 0);
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+char * hdfsGetLinkTarget(hdfsFS fs, const char * path) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0, NULL, EINVAL);
+
+    try {
+        std::string target = fs->getFilesystem().getLinkTarget(path);
+        return Strdup(target.c_str());
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
+void hdfsFreeLinkTarget(char * target) {
+    delete [] target;
+}
+
 void hdfsFreeEncryptionZoneInfo(hdfsEncryptionZoneInfo * infos, int numEntries) {
     for (int i = 0; infos != NULL && i < numEntries; ++i) {
         delete [] infos[i].mPath;
@@ -1104,7 +1345,12 @@ void hdfsFreeFileInfo(hdfsFileInfo * infos, int numEntries) {
         delete [] infos[i].mGroup;
         delete [] infos[i].mName;
         delete [] infos[i].mOwner;
+        delete [] infos[i].mSymlink;
         if (infos[i].mHdfsEncryptionFileInfo != NULL) {
+            delete [] infos[i].mHdfsEncryptionFileInfo->mKey;
+            delete [] infos[i].mHdfsEncryptionFileInfo->mKeyName;
+            delete [] infos[i].mHdfsEncryptionFileInfo->mIv;
+            delete [] infos[i].mHdfsEncryptionFileInfo->mEzKeyVersionName;
             delete [] infos[i].mHdfsEncryptionFileInfo;
         }
     }
@@ -1214,6 +1460,110 @@ tOffset hdfsGetUsed(hdfsFS fs) {
     return -1;
 }
 
+hdfsFsStats * hdfsGetFsStats(hdfsFS fs) {
+    PARAMETER_ASSERT(fs != NULL, NULL, EINVAL);
+
+    try {
+        Hdfs::FileSystemStats stat = fs->getFilesystem().getStats();
+        hdfsFsStats * retval = new hdfsFsStats[1];
+        retval->mCapacity = stat.getCapacity();
+        retval->mUsed = stat.getUsed();
+        retval->mRemaining = stat.getRemaining();
+        retval->mUnderReplicated = stat.getUnderReplicated();
+        retval->mCorruptBlocks = stat.getCorruptBlocks();
+        retval->mMissingBlocks = stat.getMissingBlocks();
+        return retval;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
+void hdfsFreeFsStats(hdfsFsStats * stats) {
+    delete [] stats;
+}
+
+hdfsServerDefaults * hdfsGetServerDefaults(hdfsFS fs) {
+    PARAMETER_ASSERT(fs != NULL, NULL, EINVAL);
+
+    try {
+        Hdfs::ServerDefaults defaults = fs->getFilesystem().getServerDefaults();
+        hdfsServerDefaults * retval = new hdfsServerDefaults[1];
+        retval->mBlockSize = defaults.getBlockSize();
+        retval->mTrashInterval = defaults.getTrashInterval();
+        retval->mBytesPerChecksum = defaults.getBytesPerChecksum();
+        retval->mWritePacketSize = defaults.getWritePacketSize();
+        retval->mFileBufferSize = defaults.getFileBufferSize();
+        retval->mEncryptDataTransfer = defaults.isEncryptDataTransfer() ? 1 : 0;
+        retval->mChecksumType = defaults.getChecksumType();
+        retval->mReplication = defaults.getReplication();
+        return retval;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
+void hdfsFreeServerDefaults(hdfsServerDefaults * defaults) {
+    delete [] defaults;
+}
+
+hdfsContentSummary * hdfsGetContentSummary(hdfsFS fs, const char * path) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0, NULL, EINVAL);
+
+    try {
+        Hdfs::ContentSummary summary = fs->getFilesystem().getContentSummary(path);
+        hdfsContentSummary * retval = new hdfsContentSummary[1];
+        retval->mLength = summary.getLength();
+        retval->mFileCount = summary.getFileCount();
+        retval->mDirectoryCount = summary.getDirectoryCount();
+        retval->mQuota = summary.getQuota();
+        retval->mSpaceConsumed = summary.getSpaceConsumed();
+        retval->mSpaceQuota = summary.getSpaceQuota();
+        return retval;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
+void hdfsFreeContentSummary(hdfsContentSummary * summary) {
+    delete [] summary;
+}
+
+int hdfsSetQuota(hdfsFS fs, const char * path, int64_t namespaceQuota,
+                 int64_t diskspaceQuota) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().setQuota(path, namespaceQuota, diskspaceQuota);
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
 int hdfsChown(hdfsFS fs, const char * path, const char * owner,
               const char * group) {
     PARAMETER_ASSERT(fs && path && strlen(path) > 0, -1, EINVAL);
@@ -1306,7 +1656,7 @@ void hdfsFreeDelegationToken(char * token) {
         return;
     }
 
-    delete token;
+    delete[] token;
 }
 
 int64_t hdfsRenewDelegationToken(hdfsFS fs, const char * token) {
@@ -1417,6 +1767,25 @@ void hdfsFreeNamenodeInformation(Namenode * namenodes, int size) {
     delete[] namenodes;
 }
 
+char * hdfsGetActiveNamenode(hdfsFS fs, char * buffer, size_t bufferSize) {
+    PARAMETER_ASSERT(fs && buffer && bufferSize > 0, NULL, EINVAL);
+
+    try {
+        std::string retval = fs->getFilesystem().getActiveNamenode();
+        PARAMETER_ASSERT(retval.length() + 1 <= bufferSize, NULL, ENOMEM);
+        strncpy(buffer, retval.c_str(), bufferSize);
+        return buffer;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
 static void ConstructFileBlockLocation(Hdfs::BlockLocation & bl, BlockLocation * target) {
     memset(target, 0, sizeof(BlockLocation));
     target->corrupt = bl.isCorrupt();
@@ -1560,6 +1929,582 @@ hdfsEncryptionZoneInfo * hdfsListEncryptionZones(hdfsFS fs, int * numEntries) {
     }
     return NULL;
 }
+
+char * hdfsCreateSnapshot(hdfsFS fs, const char * path, const char * name,
+                          char * buffer, size_t bufferSize) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0 && buffer && bufferSize > 0, NULL, EINVAL);
+
+    try {
+        std::string retval = fs->getFilesystem().createSnapshot(path, name);
+        PARAMETER_ASSERT(retval.length() + 1 <= bufferSize, NULL, ENOMEM);
+        strncpy(buffer, retval.c_str(), bufferSize);
+        return buffer;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
+int hdfsDeleteSnapshot(hdfsFS fs, const char * path, const char * name) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0 && name && strlen(name) > 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().deleteSnapshot(path, name);
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+int hdfsRenameSnapshot(hdfsFS fs, const char * path, const char * oldName,
+                       const char * newName) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0 && oldName && strlen(oldName) > 0
+                     && newName && strlen(newName) > 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().renameSnapshot(path, oldName, newName);
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+int hdfsAllowSnapshot(hdfsFS fs, const char * path) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().allowSnapshot(path);
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+int hdfsDisallowSnapshot(hdfsFS fs, const char * path) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().disallowSnapshot(path);
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+hdfsSnapshottableDirInfo * hdfsGetSnapshottableDirListing(hdfsFS fs, int * numEntries) {
+    PARAMETER_ASSERT(fs && numEntries, NULL, EINVAL);
+    hdfsSnapshottableDirInfo * retval = NULL;
+    int size = 0;
+
+    try {
+        std::vector<Hdfs::SnapshottableDirectoryStatus> dirs =
+            fs->getFilesystem().getSnapshottableDirListing();
+        size = dirs.size();
+        *numEntries = size;
+
+        if (size == 0) {
+            return NULL;
+        }
+
+        retval = new hdfsSnapshottableDirInfo[size];
+        memset(retval, 0, sizeof(hdfsSnapshottableDirInfo) * size);
+
+        for (int i = 0; i < size; ++i) {
+            const Hdfs::FileStatus & status = dirs[i].getDirStatus();
+            retval[i].mPath = Strdup(status.getPath());
+            retval[i].mOwner = Strdup(status.getOwner());
+            retval[i].mGroup = Strdup(status.getGroup());
+            retval[i].mPermissions = status.getPermission().toShort();
+            retval[i].mLastMod = status.getModificationTime() / 1000;
+            retval[i].mSnapshotNumber = dirs[i].getSnapshotNumber();
+            retval[i].mSnapshotQuota = dirs[i].getSnapshotQuota();
+        }
+
+        return retval;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        hdfsFreeSnapshottableDirInfo(retval, size);
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        hdfsFreeSnapshottableDirInfo(retval, size);
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
+void hdfsFreeSnapshottableDirInfo(hdfsSnapshottableDirInfo * infos, int numEntries) {
+    for (int i = 0; infos != NULL && i < numEntries; ++i) {
+        delete [] infos[i].mPath;
+        delete [] infos[i].mOwner;
+        delete [] infos[i].mGroup;
+    }
+
+    delete[] infos;
+}
+
+hdfsSnapshotDiffReport * hdfsGetSnapshotDiffReport(hdfsFS fs, const char * path,
+        const char * fromSnapshot, const char * toSnapshot) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0, NULL, EINVAL);
+    hdfsSnapshotDiffReport * retval = NULL;
+
+    try {
+        Hdfs::SnapshotDiffReport report =
+            fs->getFilesystem().getSnapshotDiffReport(path, fromSnapshot, toSnapshot);
+        const std::vector<Hdfs::SnapshotDiffEntry> & entries = report.getEntries();
+        retval = new hdfsSnapshotDiffReport[1];
+        memset(retval, 0, sizeof(hdfsSnapshotDiffReport));
+        retval->mSnapshotRoot = Strdup(report.getSnapshotRoot());
+        retval->mFromSnapshot = Strdup(report.getFromSnapshot());
+        retval->mToSnapshot = Strdup(report.getToSnapshot());
+
+        if (!entries.empty()) {
+            retval->mEntries = new hdfsSnapshotDiffEntry[entries.size()];
+            memset(retval->mEntries, 0, sizeof(hdfsSnapshotDiffEntry) * entries.size());
+        }
+
+        for (size_t i = 0; i < entries.size(); ++i) {
+            hdfsSnapshotDiffEntry & entry = retval->mEntries[i];
+            entry.mType = static_cast<char>(entries[i].getType());
+            entry.mPath = Strdup(entries[i].getPath());
+
+            if (entries[i].getType() == Hdfs::SnapshotDiffEntry::RENAME) {
+                entry.mTargetPath = Strdup(entries[i].getTargetPath());
+            }
+
+            retval->mNumEntries = i + 1;
+        }
+
+        return retval;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        hdfsFreeSnapshotDiffReport(retval);
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        hdfsFreeSnapshotDiffReport(retval);
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
+void hdfsFreeSnapshotDiffReport(hdfsSnapshotDiffReport * report) {
+    if (!report) {
+        return;
+    }
+
+    for (int i = 0; i < report->mNumEntries; ++i) {
+        delete [] report->mEntries[i].mPath;
+        delete [] report->mEntries[i].mTargetPath;
+    }
+
+    delete [] report->mEntries;
+    delete [] report->mSnapshotRoot;
+    delete [] report->mFromSnapshot;
+    delete [] report->mToSnapshot;
+    delete [] report;
+}
+
+static std::vector<Hdfs::AclEntry> BuildAclSpec(const hdfsAclEntry * entries,
+        int numEntries) {
+    std::vector<Hdfs::AclEntry> retval(numEntries);
+
+    for (int i = 0; i < numEntries; ++i) {
+        retval[i].setScope(static_cast<Hdfs::AclEntry::Scope>(entries[i].mScope));
+        retval[i].setType(static_cast<Hdfs::AclEntry::Type>(entries[i].mType));
+        retval[i].setPermissions(entries[i].mPermissions);
+
+        if (entries[i].mName) {
+            retval[i].setName(entries[i].mName);
+        }
+    }
+
+    return retval;
+}
+
+hdfsAclStatus * hdfsGetAclStatus(hdfsFS fs, const char * path) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0, NULL, EINVAL);
+    hdfsAclStatus * retval = NULL;
+
+    try {
+        Hdfs::AclStatus status = fs->getFilesystem().getAclStatus(path);
+        const std::vector<Hdfs::AclEntry> & entries = status.getEntries();
+        retval = new hdfsAclStatus[1];
+        memset(retval, 0, sizeof(hdfsAclStatus));
+        retval->mOwner = Strdup(status.getOwner());
+        retval->mGroup = Strdup(status.getGroup());
+        retval->mPermissions = status.getPermission();
+        retval->mSticky = status.isSticky() ? 1 : 0;
+
+        if (!entries.empty()) {
+            retval->mEntries = new hdfsAclEntry[entries.size()];
+            memset(retval->mEntries, 0, sizeof(hdfsAclEntry) * entries.size());
+        }
+
+        for (size_t i = 0; i < entries.size(); ++i) {
+            hdfsAclEntry & entry = retval->mEntries[i];
+            entry.mScope = entries[i].getScope();
+            entry.mType = entries[i].getType();
+            entry.mPermissions = entries[i].getPermissions();
+
+            if (strlen(entries[i].getName()) > 0) {
+                entry.mName = Strdup(entries[i].getName());
+            }
+
+            retval->mNumEntries = i + 1;
+        }
+
+        return retval;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        hdfsFreeAclStatus(retval);
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        hdfsFreeAclStatus(retval);
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
+void hdfsFreeAclStatus(hdfsAclStatus * status) {
+    if (!status) {
+        return;
+    }
+
+    for (int i = 0; i < status->mNumEntries; ++i) {
+        delete [] status->mEntries[i].mName;
+    }
+
+    delete [] status->mEntries;
+    delete [] status->mOwner;
+    delete [] status->mGroup;
+    delete [] status;
+}
+
+int hdfsSetAcl(hdfsFS fs, const char * path, const hdfsAclEntry * entries,
+               int numEntries) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0
+                     && (entries || numEntries == 0) && numEntries >= 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().setAcl(path, BuildAclSpec(entries, numEntries));
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+int hdfsModifyAclEntries(hdfsFS fs, const char * path, const hdfsAclEntry * entries,
+                         int numEntries) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0
+                     && (entries || numEntries == 0) && numEntries >= 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().modifyAclEntries(path, BuildAclSpec(entries, numEntries));
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+int hdfsRemoveAclEntries(hdfsFS fs, const char * path, const hdfsAclEntry * entries,
+                         int numEntries) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0
+                     && (entries || numEntries == 0) && numEntries >= 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().removeAclEntries(path, BuildAclSpec(entries, numEntries));
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+int hdfsRemoveDefaultAcl(hdfsFS fs, const char * path) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().removeDefaultAcl(path);
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+int hdfsRemoveAcl(hdfsFS fs, const char * path) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().removeAcl(path);
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+static Hdfs::XAttr BuildXAttr(const hdfsXAttr & xattr) {
+    Hdfs::XAttr retval;
+    retval.setNamespace(static_cast<Hdfs::XAttr::Namespace>(xattr.mNamespace));
+    retval.setName(xattr.mName);
+
+    if (xattr.mValue) {
+        retval.setValue(std::string(xattr.mValue, xattr.mValueLength));
+    }
+
+    return retval;
+}
+
+static hdfsXAttr * ConstructHdfsXAttrs(const std::vector<Hdfs::XAttr> & xattrs,
+                                       int * numEntries) {
+    int size = xattrs.size();
+    hdfsXAttr * retval = NULL;
+
+    if (size == 0) {
+        *numEntries = 0;
+        return NULL;
+    }
+
+    try {
+        retval = new hdfsXAttr[size];
+        memset(retval, 0, sizeof(hdfsXAttr) * size);
+
+        for (int i = 0; i < size; ++i) {
+            retval[i].mNamespace = xattrs[i].getNamespace();
+            retval[i].mName = Strdup(xattrs[i].getName());
+
+            if (xattrs[i].hasValue()) {
+                retval[i].mValue = Bytesdup(xattrs[i].getValue());
+                retval[i].mValueLength = xattrs[i].getValue().size();
+            }
+        }
+    } catch (...) {
+        hdfsFreeXAttrs(retval, size);
+        throw;
+    }
+
+    *numEntries = size;
+    return retval;
+}
+
+hdfsXAttr * hdfsGetXAttrs(hdfsFS fs, const char * path, const hdfsXAttr * names,
+                          int numNames, int * numEntries) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0 && numEntries
+                     && (names || numNames == 0) && numNames >= 0, NULL, EINVAL);
+
+    try {
+        std::vector<Hdfs::XAttr> xattrs;
+
+        for (int i = 0; i < numNames; ++i) {
+            PARAMETER_ASSERT(names[i].mName && strlen(names[i].mName) > 0, NULL, EINVAL);
+            Hdfs::XAttr xattr = BuildXAttr(names[i]);
+            xattrs.push_back(xattr);
+        }
+
+        return ConstructHdfsXAttrs(fs->getFilesystem().getXAttrs(path, xattrs), numEntries);
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
+hdfsXAttr * hdfsListXAttrs(hdfsFS fs, const char * path, int * numEntries) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0 && numEntries, NULL, EINVAL);
+
+    try {
+        return ConstructHdfsXAttrs(fs->getFilesystem().listXAttrs(path), numEntries);
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return NULL;
+}
+
+void hdfsFreeXAttrs(hdfsXAttr * xattrs, int numEntries) {
+    for (int i = 0; xattrs != NULL && i < numEntries; ++i) {
+        delete [] xattrs[i].mName;
+        delete [] xattrs[i].mValue;
+    }
+
+    delete [] xattrs;
+}
+
+int hdfsSetXAttr(hdfsFS fs, const char * path, const hdfsXAttr * xattr, int flags) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0 && xattr && xattr->mName
+                     && strlen(xattr->mName) > 0 && xattr->mValueLength >= 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().setXAttr(path, BuildXAttr(*xattr), flags);
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+int hdfsRemoveXAttr(hdfsFS fs, const char * path, const hdfsXAttr * xattr) {
+    PARAMETER_ASSERT(fs && path && strlen(path) > 0 && xattr && xattr->mName
+                     && strlen(xattr->mName) > 0, -1, EINVAL);
+
+    try {
+        fs->getFilesystem().removeXAttr(path, BuildXAttr(*xattr));
+        return 0;
+    } catch (const std::bad_alloc & e) {
+        SetErrorMessage("Out of memory");
+        errno = ENOMEM;
+    } catch (...) {
+        SetLastException(Hdfs::current_exception());
+        handleException(Hdfs::current_exception());
+    }
+
+    return -1;
+}
+
+int hdfsGetSpnegoToken(const char * host, const char * ticketCachePath,
+                       char ** token, int * length) {
+    PARAMETER_ASSERT(host && strlen(host) > 0 && token && length, -1, EINVAL);
+    /* 1.3.6.1.5.5.2 */
+    static gss_OID_desc spnego = {6, const_cast<char *>("\x2b\x06\x01\x05\x05\x02")};
+    OM_uint32 major, minor, ignored;
+    const char * previous = NULL;
+    std::string previousCache;
+    gss_name_t service = GSS_C_NO_NAME;
+    gss_ctx_id_t context = GSS_C_NO_CONTEXT;
+    gss_buffer_desc output = GSS_C_EMPTY_BUFFER;
+    std::string name = std::string("HTTP@") + host;
+    gss_buffer_desc input;
+    int retval = -1;
+
+    input.length = name.size();
+    input.value = const_cast<char *>(name.c_str());
+
+    if (ticketCachePath) {
+        major = gss_krb5_ccache_name(&minor, ticketCachePath, &previous);
+
+        if (previous) {
+            previousCache = previous;
+        }
+    } else {
+        major = GSS_S_COMPLETE;
+    }
+
+    if (!GSS_ERROR(major)) {
+        major = gss_import_name(&minor, &input, GSS_C_NT_HOSTBASED_SERVICE, &service);
+    }
+
+    if (!GSS_ERROR(major)) {
+        major = gss_init_sec_context(&minor, GSS_C_NO_CREDENTIAL, &context, service,
+                                     &spnego, GSS_C_MUTUAL_FLAG, GSS_C_INDEFINITE,
+                                     GSS_C_NO_CHANNEL_BINDINGS, GSS_C_NO_BUFFER, NULL,
+                                     &output, NULL, NULL);
+    }
+
+    if (GSS_ERROR(major)) {
+        std::string message = std::string("Could not get a SPNEGO token for HTTP/") + host
+                              + ": " + GssErrorMessage(major, minor);
+        SetErrorMessage(message.c_str());
+        errno = EACCES;
+    } else {
+        *token = Bytesdup(std::string(static_cast<const char *>(output.value), output.length));
+        *length = output.length;
+        retval = 0;
+    }
+
+    gss_release_buffer(&ignored, &output);
+    gss_delete_sec_context(&ignored, &context, GSS_C_NO_BUFFER);
+    gss_release_name(&ignored, &service);
+
+    if (ticketCachePath) {
+        gss_krb5_ccache_name(&ignored, previous ? previousCache.c_str() : NULL, NULL);
+    }
+
+    return retval;
+}
+
+void hdfsFreeSpnegoToken(char * token) {
+    if (!token) {
+        return;
+    }
+
+    delete[] token;
+}
+
 #ifdef __cplusplus
 }
 #endif
@@ -459,6 +459,56 @@ int32_t InputStreamImpl::read(char * buf, int32_t size) {
     }
 }
 
+/**
+ * To read data from hdfs at the given position, without moving the file point.
+ * @param buf the buffer used to filled.
+ * @param size buffer size.
+ * @param position the position in the file to read from.
+ * @return return the number of bytes filled in the buffer, it may less than size.
+ */
+int32_t InputStreamImpl::pread(char * buf, int32_t size, int64_t position) {
+    checkStatus();
+    /*
+     * Read through a block reader of its own, then put back the state of the
+     * sequential read so that its position and block reader are left as they were.
+     */
+    int64_t oldCursor = cursor;
+    int64_t oldEndOfCurBlock = endOfCurBlock;
+    bool oldReadFromUnderConstructedBlock = readFromUnderConstructedBlock;
+    DatanodeInfo oldCurNode = curNode;
+    shared_ptr<BlockReader> oldBlockReader = blockReader;
+    shared_ptr<LocatedBlock> oldCurBlock = curBlock;
+    std::vector<DatanodeInfo> oldFailedNodes = failedNodes;
+    int32_t done = 0;
+
+    try {
+        blockReader.reset();
+        endOfCurBlock = 0;
+        seekInternal(position);
+        done = readInternal(buf, size);
+        LOG(DEBUG3, "%p pread file %s size is %d, offset %" PRId64 " done %d", this, path.c_str(), size,
+            position, done);
+    } catch (...) {
+        cursor = oldCursor;
+        endOfCurBlock = oldEndOfCurBlock;
+        readFromUnderConstructedBlock = oldReadFromUnderConstructedBlock;
+        curNode = oldCurNode;
+        blockReader = oldBlockReader;
+        curBlock = oldCurBlock;
+        failedNodes = oldFailedNodes;
+        throw;
+    }
+
+    cursor = oldCursor;
+    endOfCurBlock = oldEndOfCurBlock;
+    readFromUnderConstructedBlock = oldReadFromUnderConstructedBlock;
+    curNode = oldCurNode;
+    blockReader = oldBlockReader;
+    curBlock = oldCurBlock;
+    failedNodes = oldFailedNodes;
+    return done;
+}
+
 int32_t InputStreamImpl::readOneBlock(char * buf, int32_t size, bool shouldUpdateMetadataOnFailure) {
     bool temporaryDisableLocalRead = false;
     std::string buffer;
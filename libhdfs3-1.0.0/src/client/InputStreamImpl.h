@@ -76,6 +76,15 @@ public:
      */
     void readFully(char * buf, int64_t size);
 
+    /**
+     * To read data from hdfs at the given position, without moving the file point.
+     * @param buf the buffer used to filled.
+     * @param size buffer size.
+     * @param position the position in the file to read from.
+     * @return return the number of bytes filled in the buffer, it may less than size.
+     */
+    int32_t pread(char * buf, int32_t size, int64_t position);
+
     int64_t available();
 
     /**
@@ -0,0 +1,142 @@
+/********************************************************************
+ * 2014 -
+ * open source under Apache License Version 2.0
+ ********************************************************************/
+/**
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+#ifndef _HDFS_LIBHDFS3_CLIENT_SERVERDEFAULTS_H_
+#define _HDFS_LIBHDFS3_CLIENT_SERVERDEFAULTS_H_
+
+#include <stdint.h>
+
+namespace Hdfs {
+
+/**
+ * The defaults the namenode applies to files created by clients which do not
+ * configure their own.
+ */
+class ServerDefaults {
+public:
+    ServerDefaults() :
+        blockSize(0), bytesPerChecksum(0), writePacketSize(0), replication(0),
+        fileBufferSize(0), encryptDataTransfer(false), trashInterval(0),
+        checksumType(1) {
+    }
+
+    /**
+     * @return the block size of new files in bytes.
+     */
+    int64_t getBlockSize() const {
+        return blockSize;
+    }
+
+    void setBlockSize(int64_t blockSize) {
+        this->blockSize = blockSize;
+    }
+
+    /**
+     * @return the number of data bytes covered by each checksum.
+     */
+    int32_t getBytesPerChecksum() const {
+        return bytesPerChecksum;
+    }
+
+    void setBytesPerChecksum(int32_t bytesPerChecksum) {
+        this->bytesPerChecksum = bytesPerChecksum;
+    }
+
+    /**
+     * @return the size in bytes of the packets written to datanodes.
+     */
+    int32_t getWritePacketSize() const {
+        return writePacketSize;
+    }
+
+    void setWritePacketSize(int32_t writePacketSize) {
+        this->writePacketSize = writePacketSize;
+    }
+
+    /**
+     * @return the replication of new files.
+     */
+    int16_t getReplication() const {
+        return replication;
+    }
+
+    void setReplication(int16_t replication) {
+        this->replication = replication;
+    }
+
+    /**
+     * @return the size in bytes of the buffer used to read and write files.
+     */
+    int32_t getFileBufferSize() const {
+        return fileBufferSize;
+    }
+
+    void setFileBufferSize(int32_t fileBufferSize) {
+        this->fileBufferSize = fileBufferSize;
+    }
+
+    /**
+     * @return true if data transfer to datanodes must be encrypted.
+     */
+    bool isEncryptDataTransfer() const {
+        return encryptDataTransfer;
+    }
+
+    void setEncryptDataTransfer(bool encryptDataTransfer) {
+        this->encryptDataTransfer = encryptDataTransfer;
+    }
+
+    /**
+     * @return the interval in minutes between trash checkpoints, 0 if trash is disabled.
+     */
+    int64_t getTrashInterval() const {
+        return trashInterval;
+    }
+
+    void setTrashInterval(int64_t trashInterval) {
+        this->trashInterval = trashInterval;
+    }
+
+    /**
+     * @return the checksum type, 0 for none, 1 for CRC32 and 2 for CRC32C.
+     */
+    int32_t getChecksumType() const {
+        return checksumType;
+    }
+
+    void setChecksumType(int32_t checksumType) {
+        this->checksumType = checksumType;
+    }
+
+private:
+    int64_t blockSize;
+    int32_t bytesPerChecksum;
+    int32_t writePacketSize;
+    int16_t replication;
+    int32_t fileBufferSize;
+    bool encryptDataTransfer;
+    int64_t trashInterval;
+    int32_t checksumType;
+};
+
+}
+
+#endif /* _HDFS_LIBHDFS3_CLIENT_SERVERDEFAULTS_H_ */
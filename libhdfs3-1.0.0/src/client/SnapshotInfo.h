@@ -0,0 +1,180 @@
+/**
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+#ifndef _HDFS_LIBHDFS3_CLIENT_SNAPSHOTINFO_H_
+#define _HDFS_LIBHDFS3_CLIENT_SNAPSHOTINFO_H_
+
+#include "FileStatus.h"
+
+#include <string>
+#include <vector>
+
+namespace Hdfs {
+
+/**
+ * Status of a snapshottable directory.
+ */
+class SnapshottableDirectoryStatus {
+public:
+    SnapshottableDirectoryStatus() :
+        snapshotQuota(0), snapshotNumber(0) {
+    }
+
+    /**
+     * @return the status of the directory, with its full path.
+     */
+    const FileStatus & getDirStatus() const {
+        return dirStatus;
+    }
+
+    void setDirStatus(const FileStatus & dirStatus) {
+        this->dirStatus = dirStatus;
+    }
+
+    int getSnapshotQuota() const {
+        return snapshotQuota;
+    }
+
+    void setSnapshotQuota(int snapshotQuota) {
+        this->snapshotQuota = snapshotQuota;
+    }
+
+    int getSnapshotNumber() const {
+        return snapshotNumber;
+    }
+
+    void setSnapshotNumber(int snapshotNumber) {
+        this->snapshotNumber = snapshotNumber;
+    }
+
+    const char * getParentFullPath() const {
+        return parentFullPath.c_str();
+    }
+
+    void setParentFullPath(const char * parentFullPath) {
+        this->parentFullPath = parentFullPath;
+    }
+
+private:
+    FileStatus dirStatus;
+    int snapshotQuota;
+    int snapshotNumber;
+    std::string parentFullPath;
+};
+
+/**
+ * An entry of a snapshot diff report.
+ */
+class SnapshotDiffEntry {
+public:
+    /**
+     * The type of the difference, as labelled by the namenode.
+     */
+    enum DiffType {
+        CREATE = '+',
+        MODIFY = 'M',
+        DELETE = '-',
+        RENAME = 'R'
+    };
+
+    SnapshotDiffEntry() :
+        type(MODIFY) {
+    }
+
+    DiffType getType() const {
+        return type;
+    }
+
+    void setType(DiffType type) {
+        this->type = type;
+    }
+
+    /**
+     * @return the path relative to the snapshot root.
+     */
+    const char * getPath() const {
+        return path.c_str();
+    }
+
+    void setPath(const char * path) {
+        this->path = path;
+    }
+
+    /**
+     * @return the new path relative to the snapshot root of a renamed entry.
+     */
+    const char * getTargetPath() const {
+        return targetPath.c_str();
+    }
+
+    void setTargetPath(const char * targetPath) {
+        this->targetPath = targetPath;
+    }
+
+private:
+    DiffType type;
+    std::string path;
+    std::string targetPath;
+};
+
+/**
+ * The differences between two snapshots of a directory, or a snapshot
+ * and the current state of the directory.
+ */
+class SnapshotDiffReport {
+public:
+    const char * getSnapshotRoot() const {
+        return snapshotRoot.c_str();
+    }
+
+    void setSnapshotRoot(const char * snapshotRoot) {
+        this->snapshotRoot = snapshotRoot;
+    }
+
+    const char * getFromSnapshot() const {
+        return fromSnapshot.c_str();
+    }
+
+    void setFromSnapshot(const char * fromSnapshot) {
+        this->fromSnapshot = fromSnapshot;
+    }
+
+    const char * getToSnapshot() const {
+        return toSnapshot.c_str();
+    }
+
+    void setToSnapshot(const char * toSnapshot) {
+        this->toSnapshot = toSnapshot;
+    }
+
+    const std::vector<SnapshotDiffEntry> & getEntries() const {
+        return entries;
+    }
+
+    std::vector<SnapshotDiffEntry> & getEntries() {
+        return entries;
+    }
+
+private:
+    std::string snapshotRoot;
+    std::string fromSnapshot;
+    std::string toSnapshot;
+    std::vector<SnapshotDiffEntry> entries;
+};
+
+}
+#endif /* _HDFS_LIBHDFS3_CLIENT_SNAPSHOTINFO_H_ */
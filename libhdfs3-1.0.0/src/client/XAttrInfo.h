@@ -0,0 +1,99 @@
+/**
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+#ifndef _HDFS_LIBHDFS3_CLIENT_XATTRINFO_H_
+#define _HDFS_LIBHDFS3_CLIENT_XATTRINFO_H_
+
+#include <string>
+
+namespace Hdfs {
+
+/**
+ * An extended attribute of a file or directory.
+ */
+class XAttr {
+public:
+    /**
+     * The namespace of the attribute, which decides who may access it.
+     */
+    enum Namespace {
+        USER = 0,
+        TRUSTED = 1,
+        SECURITY = 2,
+        SYSTEM = 3,
+        RAW = 4
+    };
+
+    /**
+     * The flags of setting an attribute, which may be combined.
+     */
+    enum SetFlag {
+        CREATE = 1,
+        REPLACE = 2
+    };
+
+    XAttr() :
+        ns(USER), valueSet(false) {
+    }
+
+    Namespace getNamespace() const {
+        return ns;
+    }
+
+    void setNamespace(Namespace ns) {
+        this->ns = ns;
+    }
+
+    /**
+     * @return the name of the attribute without its namespace.
+     */
+    const char * getName() const {
+        return name.c_str();
+    }
+
+    void setName(const char * name) {
+        this->name = name;
+    }
+
+    /**
+     * @return the value of the attribute, which may contain NUL bytes.
+     */
+    const std::string & getValue() const {
+        return value;
+    }
+
+    void setValue(const std::string & value) {
+        this->value = value;
+        valueSet = true;
+    }
+
+    /**
+     * @return false if the attribute was listed without its value.
+     */
+    bool hasValue() const {
+        return valueSet;
+    }
+
+private:
+    Namespace ns;
+    std::string name;
+    std::string value;
+    bool valueSet;
+};
+
+}
+#endif /* _HDFS_LIBHDFS3_CLIENT_XATTRINFO_H_ */
@@ -39,7 +39,20 @@
 #define EINTERNAL 255
 #endif
 
-/** All APIs set errno to meaningful values */
+/** Quota value which leaves the quota unchanged, see hdfsSetQuota */
+#define HDFS_QUOTA_DONT_SET INT64_MAX
+
+/** Quota value which clears the quota, see hdfsSetQuota */
+#define HDFS_QUOTA_RESET -1
+
+/**
+ * All APIs set errno to meaningful values.
+ *
+ * Unlike upstream libhdfs3, a path containing a symlink, which the client does not
+ * follow, fails with ENOLINK rather than EACCES. An exceeded namespace quota fails with
+ * EDQUOT rather than EINVAL, and a parent path which is a file with ENOTDIR rather than
+ * EACCES, for every call.
+ */
 
 #ifdef __cplusplus
 extern "C" {
@@ -53,7 +66,7 @@ typedef int64_t tOffset; /// offset within the file
 typedef uint16_t tPort; /// port
 
 typedef enum tObjectKind {
-    kObjectKindFile = 'F', kObjectKindDirectory = 'D',
+    kObjectKindFile = 'F', kObjectKindDirectory = 'D', kObjectKindSymlink = 'L',
 } tObjectKind;
 
 struct HdfsFileSystemInternalWrapper;
@@ -196,6 +209,19 @@ void hdfsBuilderSetUserName(struct hdfsBuilder * bld, const char * userName);
 void hdfsBuilderSetKerbTicketCachePath(struct hdfsBuilder * bld,
                                        const char * kerbTicketCachePath);
 
+/**
+ * Set the user to impersonate, like Hadoop's UserGroupInformation.createProxyUser.
+ * The connection authenticates as the user name or Kerberos principal of the
+ * builder, and requests are executed as the proxy user. With Kerberos, the
+ * authenticated user must be allowed to impersonate by the hadoop.proxyuser
+ * settings of the namenode. With simple authentication no real user is sent,
+ * so the connection just acts as the proxy user and nothing is checked.
+ *
+ * @param bld The HDFS builder
+ * @param proxyUser The user to impersonate.  The string will be shallow-copied.
+ */
+void hdfsBuilderSetProxyUser(struct hdfsBuilder * bld, const char * proxyUser);
+
 /**
  * Set the token used to authenticate
  *
@@ -341,6 +367,22 @@ tOffset hdfsTell(hdfsFS fs, hdfsFile file);
  */
 tSize hdfsRead(hdfsFS fs, hdfsFile file, void * buffer, tSize length);
 
+/**
+ * hdfsPread - Positional read of data from an open file.
+ * The current offset in the file is left unchanged.
+ * @param fs The configured filesystem handle.
+ * @param file The file handle.
+ * @param position Position from which to read
+ * @param buffer The buffer to copy read bytes into.
+ * @param length The length of the buffer.
+ * @return      On success, a positive number indicating how many bytes
+ *              were read.
+ *              On end-of-file, 0.
+ *              On error, -1.  Errno will be set to the error code.
+ */
+tSize hdfsPread(hdfsFS fs, hdfsFile file, tOffset position, void * buffer,
+                tSize length);
+
 /**
  * hdfsWrite - Write data into an open file.
  * @param fs The configured filesystem handle.
@@ -426,6 +468,50 @@ int hdfsDelete(hdfsFS fs, const char * path, int recursive);
  */
 int hdfsRename(hdfsFS fs, const char * oldPath, const char * newPath);
 
+/**
+ * hdfsRename2 - Rename file, atomically replacing the destination.
+ * Fails if the parent of newPath does not exist.
+ * @param fs The configured filesystem handle.
+ * @param oldPath The path of the source file.
+ * @param newPath The path of the destination file.
+ * @param overwrite Replace newPath if it is a file or an empty directory;
+ * otherwise the rename fails with EEXIST if it exists.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsRename2(hdfsFS fs, const char * oldPath, const char * newPath,
+                int overwrite);
+
+/**
+ * hdfsConcat - Move the blocks of the source files to the end of the
+ * target file and delete the source files, without copying any data.
+ * The source files must have the same block size and replication as
+ * the target file.
+ * @param fs The configured filesystem handle.
+ * @param trg The path of the target file.
+ * @param srcs A NULL terminated array of the paths of the source files.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsConcat(hdfsFS fs, const char * trg, const char ** srcs);
+
+/**
+ * hdfsRecoverLease - Start recovery of the lease on a file left open by
+ * a writer which has died, so that the file can be closed.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the file.
+ * @return Returns 1 if the file is already closed, 0 if lease recovery
+ * has started, -1 on error.
+ */
+int hdfsRecoverLease(hdfsFS fs, const char * path);
+
+/**
+ * hdfsIsFileClosed - Check whether a file is closed.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the file.
+ * @return Returns 1 if the file is closed, 0 if it is open for writing,
+ * -1 on error.
+ */
+int hdfsIsFileClosed(hdfsFS fs, const char * path);
+
 /**
  * hdfsGetWorkingDirectory - Get the current working directory for
  * the given filesystem.
@@ -436,6 +522,16 @@ int hdfsRename(hdfsFS fs, const char * oldPath, const char * newPath);
  */
 char * hdfsGetWorkingDirectory(hdfsFS fs, char * buffer, size_t bufferSize);
 
+/**
+ * hdfsGetHomeDirectory - Get the home directory of the effective user,
+ * which is the working directory of a new filesystem handle.
+ * @param fs The configured filesystem handle.
+ * @param buffer The user-buffer to copy path of the home directory into.
+ * @param bufferSize The length of user-buffer.
+ * @return Returns buffer, NULL on error.
+ */
+char * hdfsGetHomeDirectory(hdfsFS fs, char * buffer, size_t bufferSize);
+
 /**
  * hdfsSetWorkingDirectory - Set the working directory. All relative
  * paths will be resolved relative to it.
@@ -481,10 +577,12 @@ typedef struct {
 typedef struct {
     int mSuite; /* the suite of encryption file/directory */
     int mCryptoProtocolVersion; /* the version of crypto protocol */
-    char * mKey; /* the key of encryption file/directory */
+    char * mKey; /* the encrypted data encryption key of the file, mKeyLength bytes */
     char * mKeyName; /* the key name of encryption file/directory */
-    char * mIv; /* the iv of encryption file/directory */
+    char * mIv; /* the iv of encryption file/directory, mIvLength bytes */
     char * mEzKeyVersionName; /* the version encryption file/directory */
+    int mKeyLength; /* the length of mKey, which may contain NUL bytes */
+    int mIvLength; /* the length of mIv, which may contain NUL bytes */
 } hdfsEncryptionFileInfo;
 
 /**
@@ -502,6 +600,11 @@ typedef struct {
     short mPermissions; /* the permissions associated with the file */
     tTime mLastAccess; /* the last access time for the file in seconds */
     hdfsEncryptionFileInfo * mHdfsEncryptionFileInfo; /* the encryption info of the file/directory */
+    /*
+     * the target of a symlink, NULL otherwise. Not in upstream libhdfs3, whose
+     * hdfsFileInfo is smaller, so callers must be built against this header.
+     */
+    char * mSymlink;
 } hdfsFileInfo;
 
 /**
@@ -526,6 +629,43 @@ hdfsFileInfo * hdfsListDirectory(hdfsFS fs, const char * path, int * numEntries)
  */
 hdfsFileInfo * hdfsGetPathInfo(hdfsFS fs, const char * path);
 
+/**
+ * hdfsGetPathLinkInfo - Get information about a path like hdfsGetPathInfo,
+ * but of the symlink itself if the path is one. hdfsFreeFileInfo should be
+ * called when the pointer is no longer needed.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the file.
+ * @return Returns a dynamically-allocated hdfsFileInfo object;
+ * NULL on error.
+ */
+hdfsFileInfo * hdfsGetPathLinkInfo(hdfsFS fs, const char * path);
+
+/**
+ * hdfsCreateSymlink - Create a symlink to a file or directory.
+ * @param fs The configured filesystem handle.
+ * @param target The path the link points to, which is not resolved or checked.
+ * @param link The path of the link.
+ * @param createParent Whether to create missing parent directories of the link.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsCreateSymlink(hdfsFS fs, const char * target, const char * link,
+                      int createParent);
+
+/**
+ * hdfsGetLinkTarget - Get the target of a symlink. hdfsFreeLinkTarget
+ * should be called when the pointer is no longer needed.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the symlink.
+ * @return Returns a dynamically-allocated target path; NULL on error.
+ */
+char * hdfsGetLinkTarget(hdfsFS fs, const char * path);
+
+/**
+ * hdfsFreeLinkTarget - Free up the target path returned by hdfsGetLinkTarget
+ * @param target The target path to be freed.
+ */
+void hdfsFreeLinkTarget(char * target);
+
 /**
  * hdfsFreeFileInfo - Free up the hdfsFileInfo array (including fields)
  * @param infos The array of dynamically-allocated hdfsFileInfo
@@ -590,6 +730,106 @@ tOffset hdfsGetCapacity(hdfsFS fs);
  */
 tOffset hdfsGetUsed(hdfsFS fs);
 
+/**
+ * hdfsFsStats - Space usage and block health of the filesystem.
+ */
+typedef struct {
+    tOffset mCapacity; /* the raw capacity of the filesystem in bytes */
+    tOffset mUsed; /* the raw space used by files in bytes */
+    tOffset mRemaining; /* the raw space available for files in bytes */
+    int64_t mUnderReplicated; /* the number of under replicated blocks */
+    int64_t mCorruptBlocks; /* the number of blocks with a corrupt replica */
+    int64_t mMissingBlocks; /* the number of blocks without any good replica */
+} hdfsFsStats;
+
+/**
+ * hdfsGetFsStats - Get the space usage and block health of the filesystem.
+ * hdfsFreeFsStats should be called when the pointer is no longer needed.
+ * @param fs The configured filesystem handle.
+ * @return Returns a dynamically-allocated hdfsFsStats object; NULL on error.
+ */
+hdfsFsStats * hdfsGetFsStats(hdfsFS fs);
+
+/**
+ * hdfsFreeFsStats - Free up the hdfsFsStats
+ * @param stats The dynamically-allocated hdfsFsStats object.
+ */
+void hdfsFreeFsStats(hdfsFsStats * stats);
+
+/**
+ * hdfsServerDefaults - The defaults the namenode applies to new files.
+ */
+typedef struct {
+    tOffset mBlockSize; /* the block size in bytes */
+    int64_t mTrashInterval; /* minutes between trash checkpoints, 0 if trash is disabled */
+    int mBytesPerChecksum; /* the number of data bytes covered by each checksum */
+    int mWritePacketSize; /* the size in bytes of packets written to datanodes */
+    int mFileBufferSize; /* the size in bytes of the buffer used to read and write files */
+    int mEncryptDataTransfer; /* 1 if data transfer must be encrypted, 0 otherwise */
+    int mChecksumType; /* 0 for no checksum, 1 for CRC32 and 2 for CRC32C */
+    short mReplication; /* the replication */
+} hdfsServerDefaults;
+
+/**
+ * hdfsGetServerDefaults - Get the defaults the namenode applies to new files.
+ * hdfsFreeServerDefaults should be called when the pointer is no longer needed.
+ * @param fs The configured filesystem handle.
+ * @return Returns a dynamically-allocated hdfsServerDefaults object;
+ * NULL on error.
+ */
+hdfsServerDefaults * hdfsGetServerDefaults(hdfsFS fs);
+
+/**
+ * hdfsFreeServerDefaults - Free up the hdfsServerDefaults
+ * @param defaults The dynamically-allocated hdfsServerDefaults object.
+ */
+void hdfsFreeServerDefaults(hdfsServerDefaults * defaults);
+
+/**
+ * hdfsContentSummary - The disk usage, file and directory counts and
+ * quotas of a directory tree.
+ */
+typedef struct {
+    tOffset mLength; /* the total length in bytes of the files in the tree */
+    int64_t mFileCount; /* the number of files in the tree */
+    int64_t mDirectoryCount; /* the number of directories in the tree, including its root */
+    int64_t mQuota; /* the namespace quota of the directory, -1 if it has none */
+    tOffset mSpaceConsumed; /* the disk space consumed by the tree, including replicas */
+    tOffset mSpaceQuota; /* the disk space quota of the directory, -1 if it has none */
+} hdfsContentSummary;
+
+/**
+ * hdfsGetContentSummary - Get the disk usage, file and directory counts
+ * and quotas of a directory tree. hdfsFreeContentSummary should be called
+ * when the pointer is no longer needed.
+ * @param fs The configured filesystem handle.
+ * @param path The root of the tree.
+ * @return Returns a dynamically-allocated hdfsContentSummary object;
+ * NULL on error. errno is set to ENOTSUP if the namenode does not
+ * support the call.
+ */
+hdfsContentSummary * hdfsGetContentSummary(hdfsFS fs, const char * path);
+
+/**
+ * hdfsFreeContentSummary - Free up the hdfsContentSummary
+ * @param summary The dynamically-allocated hdfsContentSummary object.
+ */
+void hdfsFreeContentSummary(hdfsContentSummary * summary);
+
+/**
+ * hdfsSetQuota - Set the namespace and disk space quotas of a directory.
+ * Requires superuser privilege.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the directory.
+ * @param namespaceQuota The maximum number of files and directories in the
+ * tree, HDFS_QUOTA_DONT_SET to leave it unchanged or HDFS_QUOTA_RESET to clear it.
+ * @param diskspaceQuota The maximum disk space in bytes consumed by the
+ * tree, HDFS_QUOTA_DONT_SET to leave it unchanged or HDFS_QUOTA_RESET to clear it.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsSetQuota(hdfsFS fs, const char * path, int64_t namespaceQuota,
+                 int64_t diskspaceQuota);
+
 /**
  * Change the user and/or group of a file or directory.
  *
@@ -626,8 +866,9 @@ int hdfsUtime(hdfsFS fs, const char * path, tTime mtime, tTime atime);
  * @param fs The configured filesystem handle.
  * @param path the path to the file.
  * @param pos the position the file will be truncated to.
- * @param shouldWait output value, true if and client does not need to wait for block recovery,
- * false if client needs to wait for block recovery.
+ * @param shouldWait output value, 1 if the last block is being recovered and the
+ * client must wait for the file to be closed before appending to it, 0 otherwise.
+ * @return Returns 0 on success, -1 on error.
  */
 int hdfsTruncate(hdfsFS fs, const char * path, tOffset pos, int * shouldWait);
 
@@ -707,6 +948,17 @@ Namenode * hdfsGetHANamenodesWithConfig(const char * conf, const char * nameserv
  */
 void hdfsFreeNamenodeInformation(Namenode * namenodes, int size);
 
+/**
+ * hdfsGetActiveNamenode - Get the RPC address of the namenode which
+ * currently serves the requests of the given filesystem.
+ * If namenode HA is enabled, the returned address changes after failover.
+ * @param fs The configured filesystem handle.
+ * @param buffer The user-buffer to copy the "host:port" address into.
+ * @param bufferSize The length of user-buffer.
+ * @return Returns buffer, NULL on error.
+ */
+char * hdfsGetActiveNamenode(hdfsFS fs, char * buffer, size_t bufferSize);
+
 typedef struct BlockLocation {
     uint32_t rangeId;
     uint32_t replicaGroupId;
@@ -770,6 +1022,300 @@ hdfsEncryptionZoneInfo * hdfsGetEZForPath(hdfsFS fs, const char * path);
  */
 hdfsEncryptionZoneInfo * hdfsListEncryptionZones(hdfsFS fs, int * numEntries);
 
+/**
+ * hdfsCreateSnapshot - Create a snapshot of a snapshottable directory.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the directory.
+ * @param name The name of the snapshot, NULL to let the namenode generate one.
+ * @param buffer The user-buffer to copy the path of the snapshot into.
+ * @param bufferSize The length of user-buffer.
+ * @return Returns buffer, NULL on error.
+ */
+char * hdfsCreateSnapshot(hdfsFS fs, const char * path, const char * name,
+                          char * buffer, size_t bufferSize);
+
+/**
+ * hdfsDeleteSnapshot - Delete a snapshot of a snapshottable directory.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the directory.
+ * @param name The name of the snapshot.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsDeleteSnapshot(hdfsFS fs, const char * path, const char * name);
+
+/**
+ * hdfsRenameSnapshot - Rename a snapshot of a snapshottable directory.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the directory.
+ * @param oldName The current name of the snapshot.
+ * @param newName The new name of the snapshot.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsRenameSnapshot(hdfsFS fs, const char * path, const char * oldName,
+                       const char * newName);
+
+/**
+ * hdfsAllowSnapshot - Allow snapshots to be taken of a directory.
+ * Requires superuser privilege.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the directory.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsAllowSnapshot(hdfsFS fs, const char * path);
+
+/**
+ * hdfsDisallowSnapshot - Disallow snapshots of a directory. All of its
+ * snapshots must have been deleted. Requires superuser privilege.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the directory.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsDisallowSnapshot(hdfsFS fs, const char * path);
+
+/**
+ * hdfsSnapshottableDirInfo - Information about a snapshottable directory.
+ */
+typedef struct {
+    char * mPath; /* the full path of the directory */
+    char * mOwner; /* the owner of the directory */
+    char * mGroup; /* the group associated with the directory */
+    short mPermissions; /* the permissions associated with the directory */
+    tTime mLastMod; /* the last modification time for the directory in seconds */
+    int mSnapshotNumber; /* the number of snapshots of the directory */
+    int mSnapshotQuota; /* the maximum number of snapshots of the directory */
+} hdfsSnapshottableDirInfo;
+
+/**
+ * hdfsGetSnapshottableDirListing - Get list of the snapshottable directories
+ * which the current user owns, or all of them for the superuser.
+ * hdfsFreeSnapshottableDirInfo should be called to deallocate memory.
+ * @param fs The configured filesystem handle.
+ * @param numEntries Set to the number of directories.
+ * @return Returns a dynamically-allocated array of hdfsSnapshottableDirInfo
+ * objects; NULL on error or if there is none.
+ */
+hdfsSnapshottableDirInfo * hdfsGetSnapshottableDirListing(hdfsFS fs, int * numEntries);
+
+/**
+ * hdfsFreeSnapshottableDirInfo - Free up the hdfsSnapshottableDirInfo array (including fields)
+ * @param infos The array of dynamically-allocated hdfsSnapshottableDirInfo
+ * objects.
+ * @param numEntries The size of the array.
+ */
+void hdfsFreeSnapshottableDirInfo(hdfsSnapshottableDirInfo * infos, int numEntries);
+
+/**
+ * hdfsSnapshotDiffEntry - A difference between two snapshots.
+ */
+typedef struct {
+    char mType; /* '+' created, '-' deleted, 'M' modified or 'R' renamed */
+    char * mPath; /* the path relative to the snapshot root */
+    char * mTargetPath; /* the new relative path of a renamed entry, NULL otherwise */
+} hdfsSnapshotDiffEntry;
+
+/**
+ * hdfsSnapshotDiffReport - The differences between two snapshots of a directory.
+ */
+typedef struct {
+    char * mSnapshotRoot; /* the path of the snapshottable directory */
+    char * mFromSnapshot; /* the earlier snapshot, empty for the current state */
+    char * mToSnapshot; /* the later snapshot, empty for the current state */
+    int mNumEntries; /* the number of entries */
+    hdfsSnapshotDiffEntry * mEntries; /* the entries */
+} hdfsSnapshotDiffReport;
+
+/**
+ * hdfsGetSnapshotDiffReport - Get the differences between two snapshots
+ * of a directory. hdfsFreeSnapshotDiffReport should be called to deallocate memory.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the snapshottable directory.
+ * @param fromSnapshot The earlier snapshot, NULL or empty for the current state.
+ * @param toSnapshot The later snapshot, NULL or empty for the current state.
+ * @return Returns a dynamically-allocated hdfsSnapshotDiffReport object;
+ * NULL on error.
+ */
+hdfsSnapshotDiffReport * hdfsGetSnapshotDiffReport(hdfsFS fs, const char * path,
+        const char * fromSnapshot, const char * toSnapshot);
+
+/**
+ * hdfsFreeSnapshotDiffReport - Free up the hdfsSnapshotDiffReport (including fields)
+ * @param report The dynamically-allocated hdfsSnapshotDiffReport object.
+ */
+void hdfsFreeSnapshotDiffReport(hdfsSnapshotDiffReport * report);
+
+/**
+ * hdfsAclEntry - An entry of the ACL of a file or directory.
+ */
+typedef struct {
+    int mScope; /* 0 for an access entry, 1 for a default entry of a directory */
+    int mType; /* 0 user, 1 group, 2 mask or 3 other */
+    int mPermissions; /* the permitted actions as the bits rwx, e.g. 5 for r-x */
+    char * mName; /* the name of the user or group, NULL or empty if unnamed */
+} hdfsAclEntry;
+
+/**
+ * hdfsAclStatus - The ACL of a file or directory.
+ */
+typedef struct {
+    char * mOwner; /* the owner of the file */
+    char * mGroup; /* the group associated with the file */
+    short mPermissions; /* the permission of the file, whose group bits are the mask if it has an ACL */
+    int mSticky; /* 1 if the sticky bit is set, 0 otherwise */
+    int mNumEntries; /* the number of entries */
+    hdfsAclEntry * mEntries; /* the entries besides those of the owner, group and others */
+} hdfsAclStatus;
+
+/**
+ * hdfsGetAclStatus - Get the ACL of a file or directory.
+ * hdfsFreeAclStatus should be called to deallocate memory.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the file or directory.
+ * @return Returns a dynamically-allocated hdfsAclStatus object;
+ * NULL on error.
+ */
+hdfsAclStatus * hdfsGetAclStatus(hdfsFS fs, const char * path);
+
+/**
+ * hdfsFreeAclStatus - Free up the hdfsAclStatus (including fields)
+ * @param status The dynamically-allocated hdfsAclStatus object.
+ */
+void hdfsFreeAclStatus(hdfsAclStatus * status);
+
+/**
+ * hdfsSetAcl - Replace the ACL of a file or directory. The entries must
+ * include those of the owner, group and others.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the file or directory.
+ * @param entries The entries of the new ACL.
+ * @param numEntries The number of entries.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsSetAcl(hdfsFS fs, const char * path, const hdfsAclEntry * entries,
+               int numEntries);
+
+/**
+ * hdfsModifyAclEntries - Add or update entries of the ACL of a file or
+ * directory, keeping the other entries.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the file or directory.
+ * @param entries The entries to add or update.
+ * @param numEntries The number of entries.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsModifyAclEntries(hdfsFS fs, const char * path, const hdfsAclEntry * entries,
+                         int numEntries);
+
+/**
+ * hdfsRemoveAclEntries - Remove entries of the ACL of a file or directory.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the file or directory.
+ * @param entries The entries to remove, whose permissions are ignored.
+ * @param numEntries The number of entries.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsRemoveAclEntries(hdfsFS fs, const char * path, const hdfsAclEntry * entries,
+                         int numEntries);
+
+/**
+ * hdfsRemoveDefaultAcl - Remove the default entries of the ACL of a directory.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the directory.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsRemoveDefaultAcl(hdfsFS fs, const char * path);
+
+/**
+ * hdfsRemoveAcl - Remove all entries of the ACL of a file or directory,
+ * except those of its permission.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the file or directory.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsRemoveAcl(hdfsFS fs, const char * path);
+
+/**
+ * hdfsXAttr - An extended attribute of a file or directory.
+ */
+typedef struct {
+    int mNamespace; /* 0 user, 1 trusted, 2 security, 3 system or 4 raw */
+    char * mName; /* the name without its namespace */
+    char * mValue; /* the value, which may contain NUL bytes; NULL if it has none */
+    int mValueLength; /* the length of the value */
+} hdfsXAttr;
+
+/**
+ * hdfsGetXAttrs - Get extended attributes of a file or directory with
+ * their values. hdfsFreeXAttrs should be called to deallocate memory.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the file or directory.
+ * @param names The attributes to get, whose values are ignored; NULL for all of them.
+ * @param numNames The number of attributes to get.
+ * @param numEntries Set to the number of attributes returned.
+ * @return Returns a dynamically-allocated array of hdfsXAttr objects;
+ * NULL on error or if there is none.
+ */
+hdfsXAttr * hdfsGetXAttrs(hdfsFS fs, const char * path, const hdfsXAttr * names,
+                          int numNames, int * numEntries);
+
+/**
+ * hdfsListXAttrs - List the extended attributes of a file or directory
+ * which the user may access, without their values.
+ * hdfsFreeXAttrs should be called to deallocate memory.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the file or directory.
+ * @param numEntries Set to the number of attributes.
+ * @return Returns a dynamically-allocated array of hdfsXAttr objects;
+ * NULL on error or if there is none.
+ */
+hdfsXAttr * hdfsListXAttrs(hdfsFS fs, const char * path, int * numEntries);
+
+/**
+ * hdfsFreeXAttrs - Free up the hdfsXAttr array (including fields)
+ * @param xattrs The array of dynamically-allocated hdfsXAttr objects.
+ * @param numEntries The size of the array.
+ */
+void hdfsFreeXAttrs(hdfsXAttr * xattrs, int numEntries);
+
+/**
+ * hdfsSetXAttr - Set an extended attribute of a file or directory.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the file or directory.
+ * @param xattr The attribute with its value.
+ * @param flags 1 to create the attribute, 2 to replace it, or 3 for either.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsSetXAttr(hdfsFS fs, const char * path, const hdfsXAttr * xattr, int flags);
+
+/**
+ * hdfsRemoveXAttr - Remove an extended attribute of a file or directory.
+ * @param fs The configured filesystem handle.
+ * @param path The path of the file or directory.
+ * @param xattr The attribute, whose value is ignored.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsRemoveXAttr(hdfsFS fs, const char * path, const hdfsXAttr * xattr);
+
+/**
+ * hdfsGetSpnegoToken - Get a SPNEGO token to authenticate to an HTTP service,
+ * e.g. the KMS, with the Kerberos credentials of a ticket cache.
+ * The token should be freed using hdfsFreeSpnegoToken.
+ * @param host The host name of the service, whose principal is `HTTP/<host>`.
+ * @param ticketCachePath The Kerberos ticket cache, NULL for the default one.
+ * @param token Output value, the token to send base64 encoded in a
+ * "Negotiate" Authorization header.
+ * @param length Output value, the length of the token.
+ * @return Returns 0 on success, -1 on error.
+ */
+int hdfsGetSpnegoToken(const char * host, const char * ticketCachePath,
+                       char ** token, int * length);
+
+/**
+ * Free a SPNEGO token.
+ *
+ * @param token The token to be freed.
+ */
+void hdfsFreeSpnegoToken(char * token);
+
 #ifdef __cplusplus
 }
 #endif
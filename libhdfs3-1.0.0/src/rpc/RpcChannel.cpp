@@ -141,8 +141,13 @@ const RpcSaslProto_SaslAuth * RpcChannelImpl::createSaslClient(
         THROW(AccessControlException, "%s", ss.str().c_str());
     }
 
+    /*
+     * A proxy user connection authenticates as the real user.
+     */
+    const UserInfo & user = key.getAuth().getUser();
     saslClient = shared_ptr<SaslClient>(
-                     new SaslClient(*auth, token, key.getAuth().getUser().getPrincipal()));
+                     new SaslClient(*auth, token,
+                                    user.getRealUser().empty() ? user.getPrincipal() : user.getRealUser()));
     return auth;
 }
 
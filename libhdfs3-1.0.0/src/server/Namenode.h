@@ -23,7 +23,12 @@
 #define _HDFS_LIBHDFS3_SERVER_NAMENODE_H_
 
 #include "client/FileStatus.h"
+#include "client/ContentSummary.h"
+#include "client/ServerDefaults.h"
 #include "client/EncryptionZoneInfo.h"
+#include "client/SnapshotInfo.h"
+#include "client/AclInfo.h"
+#include "client/XAttrInfo.h"
 #include "client/Permission.h"
 #include "DatanodeInfo.h"
 #include "Exception.h"
@@ -364,9 +369,9 @@ public:
      * @throw UnresolvedLinkException if <code>trg</code> or <code>srcs</code>
      *           contains a symlink
      */
-    /*    virtual void concat(const std::string & trg,
-                            const std::vector<std::string> & srcs)  throw (HdfsIOException,
-                 UnresolvedLinkException)  = 0;*/
+    virtual void concat(const std::string & trg,
+                        const std::vector<std::string> & srcs)
+    /* throw (HdfsIOException, UnresolvedLinkException) */ = 0;
 
     /**
      * Truncate a file to the indicated length
@@ -428,7 +433,7 @@ public:
      * <p>
      * @param src existing file or directory name.
      * @param dst new name.
-     * @param options Rename options
+     * @param overwrite overwrite dst if it is a file or an empty directory
      *
      * @throw AccessControlException If access is denied
      * @throw DSQuotaExceededException If rename violates disk space
@@ -446,11 +451,12 @@ public:
      *           <code>dst</code> contains a symlink
      * @throw HdfsIOException If an I/O error occurred
      */
-    /*virtual void rename2(const std::string & src, const std::string & dst)
-     throw (AccessControlException, DSQuotaExceededException,
+    virtual void rename2(const std::string & src, const std::string & dst,
+                         bool overwrite)
+    /* throw (AccessControlException, DSQuotaExceededException,
      FileAlreadyExistsException, FileNotFoundException,
      NSQuotaExceededException, ParentNotDirectoryException,
-     SafeModeException, UnresolvedLinkException, HdfsIOException) = 0;*/
+     SafeModeException, UnresolvedLinkException, HdfsIOException) */ = 0;
 
     /**
      * Delete the given file or directory from the file system.
@@ -559,10 +565,24 @@ public:
      * @throw HdfsIOException
      */
     //Idempotent
-    /*
-        virtual bool recoverLease(const std::string & src,
-                                  const std::string & clientName)  throw (HdfsIOException)  = 0;
-    */
+    virtual bool recoverLease(const std::string & src,
+                              const std::string & clientName)
+    /* throw (HdfsIOException) */ = 0;
+
+    /**
+     * Get the close status of a file.
+     *
+     * @param src path of the file
+     * @return true if the file is closed
+     * @throw AccessControlException permission denied
+     * @throw FileNotFoundException file <code>src</code> is not found
+     * @throw UnresolvedLinkException if the path contains a symlink
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    //Idempotent
+    virtual bool isFileClosed(const std::string & src)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */ = 0;
 
     /**
      * Get a set of statistics about the filesystem.
@@ -581,6 +601,15 @@ public:
     //Idempotent
     virtual std::vector<int64_t> getFsStats() /* throw (HdfsIOException) */ = 0;
 
+    /**
+     * Get the defaults the namenode applies to new files, such as the block
+     * size, replication and checksum type.
+     * @return the server defaults.
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    //Idempotent
+    virtual ServerDefaults getServerDefaults() /* throw (HdfsIOException) */ = 0;
+
     /**
      * Dumps namenode data structures into specified file. If the file
      * already exists, then append.
@@ -620,9 +649,9 @@ public:
      * @throw HdfsIOException If an I/O error occurred
      */
     //Idempotent
-    /*    virtual FileStatus getFileLinkInfo(const std::string & src)
-         throw (AccessControlException, UnresolvedLinkException,
-         HdfsIOException)  = 0;*/
+    virtual FileStatus getFileLinkInfo(const std::string & src)
+    /* throw (AccessControlException, UnresolvedLinkException,
+     HdfsIOException) */ = 0;
 
     /**
      * Get {//link ContentSummary} rooted at the specified directory.
@@ -634,9 +663,9 @@ public:
      * @throw HdfsIOException If an I/O error occurred
      */
     //Idempotent
-    /*    virtual ContentSummary getContentSummary(const std::string & path)
-         throw (AccessControlException, FileNotFoundException,
-         UnresolvedLinkException, HdfsIOException)  = 0;*/
+    virtual ContentSummary getContentSummary(const std::string & path)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */ = 0;
 
     /**
      * Set the quota for a directory.
@@ -660,12 +689,10 @@ public:
      * @throw HdfsIOException If an I/O error occurred
      */
     //Idempotent
-    /*
-        virtual void setQuota(const std::string & path, int64_t namespaceQuota,
-                              int64_t diskspaceQuota)  throw (AccessControlException,
-                 FileNotFoundException, UnresolvedLinkException,
-                 HdfsIOException)  = 0;
-    */
+    virtual void setQuota(const std::string & path, int64_t namespaceQuota,
+                          int64_t diskspaceQuota) /* throw (AccessControlException,
+             FileNotFoundException, UnresolvedLinkException,
+             HdfsIOException) */ = 0;
 
     /**
      * Write all metadata for this file into persistent storage.
@@ -721,12 +748,12 @@ public:
      * @throw UnresolvedLinkException if <code>link</target> contains a symlink.
      * @throw HdfsIOException If an I/O error occurred
      */
-    /*    virtual void createSymlink(const std::string & target,
-                                   const std::string & link, const Permission & dirPerm,
-                                   bool createParent)  throw (AccessControlException,
-                 FileAlreadyExistsException, FileNotFoundException,
-                 ParentNotDirectoryException, SafeModeException,
-                 UnresolvedLinkException, HdfsIOException)  = 0;*/
+    virtual void createSymlink(const std::string & target,
+                               const std::string & link, const Permission & dirPerm,
+                               bool createParent) /* throw (AccessControlException,
+             FileAlreadyExistsException, FileNotFoundException,
+             ParentNotDirectoryException, SafeModeException,
+             UnresolvedLinkException, HdfsIOException) */ = 0;
 
     /**
      * Return the target of the given symlink. If there is an intermediate
@@ -741,9 +768,9 @@ public:
      *           or an I/O error occurred
      */
     //Idempotent
-    /*    virtual std::string getLinkTarget(const std::string & path)
-         throw (AccessControlException, FileNotFoundException,
-         HdfsIOException)  = 0;*/
+    virtual std::string getLinkTarget(const std::string & path)
+    /* throw (AccessControlException, FileNotFoundException,
+     HdfsIOException) */ = 0;
 
     /**
      * Get a new generation stamp together with an access token for
@@ -811,6 +838,14 @@ public:
      */
     virtual void close() {};
 
+    /**
+     * Get the RPC address of the namenode which serves the requests.
+     * @return the address in the form of "host:port".
+     */
+    virtual std::string getRpcAddress() {
+        return "";
+    }
+
     /**
      * Create encryption zone for the directory with specific key name
      * @param path the directory path which is to be created.
@@ -843,6 +878,234 @@ public:
     virtual bool listEncryptionZones(const int64_t id, std::vector<EncryptionZoneInfo> & ezl) 
               /* throw (AccessControlException, UnresolvedLinkException, HdfsIOException) */ = 0;
 
+    /**
+     * Create a snapshot of a snapshottable directory.
+     * @param snapshotRoot the path of the directory.
+     * @param snapshotName the name of the snapshot, the namenode generates
+     *  one if it is empty.
+     * @return the path of the snapshot.
+     * @throw AccessControlException permission denied
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    virtual std::string createSnapshot(const std::string & snapshotRoot,
+                                       const std::string & snapshotName)
+    /* throw (AccessControlException, HdfsIOException) */ = 0;
+
+    /**
+     * Delete a snapshot of a snapshottable directory.
+     * @param snapshotRoot the path of the directory.
+     * @param snapshotName the name of the snapshot.
+     * @throw AccessControlException permission denied
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    virtual void deleteSnapshot(const std::string & snapshotRoot,
+                                const std::string & snapshotName)
+    /* throw (AccessControlException, HdfsIOException) */ = 0;
+
+    /**
+     * Rename a snapshot of a snapshottable directory.
+     * @param snapshotRoot the path of the directory.
+     * @param snapshotOldName the old name of the snapshot.
+     * @param snapshotNewName the new name of the snapshot.
+     * @throw AccessControlException permission denied
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    virtual void renameSnapshot(const std::string & snapshotRoot,
+                                const std::string & snapshotOldName,
+                                const std::string & snapshotNewName)
+    /* throw (AccessControlException, HdfsIOException) */ = 0;
+
+    /**
+     * Allow snapshots of a directory. Only the superuser may do this.
+     * @param snapshotRoot the path of the directory.
+     * @throw AccessControlException permission denied
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    virtual void allowSnapshot(const std::string & snapshotRoot)
+    /* throw (AccessControlException, HdfsIOException) */ = 0;
+
+    /**
+     * Disallow snapshots of a directory which has no snapshots.
+     * Only the superuser may do this.
+     * @param snapshotRoot the path of the directory.
+     * @throw AccessControlException permission denied
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    virtual void disallowSnapshot(const std::string & snapshotRoot)
+    /* throw (AccessControlException, HdfsIOException) */ = 0;
+
+    /**
+     * Get the snapshottable directories the user owns, or all of them for
+     * the superuser.
+     * @param dirs append the returned directories.
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    virtual void getSnapshottableDirListing(
+        std::vector<SnapshottableDirectoryStatus> & dirs)
+    /* throw (HdfsIOException) */ = 0;
+
+    /**
+     * Get the differences between two snapshots of a directory, or a
+     * snapshot and the current state of the directory.
+     * @param snapshotRoot the path of the directory.
+     * @param fromSnapshot the name of the earlier snapshot, empty for the
+     *  current state.
+     * @param toSnapshot the name of the later snapshot, empty for the
+     *  current state.
+     * @return the difference report.
+     * @throw AccessControlException permission denied
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    virtual SnapshotDiffReport getSnapshotDiffReport(const std::string & snapshotRoot,
+            const std::string & fromSnapshot, const std::string & toSnapshot)
+    /* throw (AccessControlException, HdfsIOException) */ = 0;
+
+    /**
+     * Add or update entries of the ACL of a file or directory, keeping
+     * the other entries.
+     * @param src the path of the file or directory.
+     * @param aclSpec the entries to add or update.
+     * @throw AccessControlException If access is denied
+     * @throw FileNotFoundException If file <code>src</code> is not found
+     * @throw UnresolvedLinkException If <code>src</code> contains a symlink
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    virtual void modifyAclEntries(const std::string & src,
+                                  const std::vector<AclEntry> & aclSpec)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */ = 0;
+
+    /**
+     * Remove entries of the ACL of a file or directory. The permissions
+     * of the entries are ignored.
+     * @param src the path of the file or directory.
+     * @param aclSpec the entries to remove.
+     * @throw AccessControlException If access is denied
+     * @throw FileNotFoundException If file <code>src</code> is not found
+     * @throw UnresolvedLinkException If <code>src</code> contains a symlink
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    virtual void removeAclEntries(const std::string & src,
+                                  const std::vector<AclEntry> & aclSpec)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */ = 0;
+
+    /**
+     * Remove the default entries of the ACL of a directory.
+     * @param src the path of the directory.
+     * @throw AccessControlException If access is denied
+     * @throw FileNotFoundException If file <code>src</code> is not found
+     * @throw UnresolvedLinkException If <code>src</code> contains a symlink
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    virtual void removeDefaultAcl(const std::string & src)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */ = 0;
+
+    /**
+     * Remove all entries of the ACL of a file or directory, except those
+     * of its permission.
+     * @param src the path of the file or directory.
+     * @throw AccessControlException If access is denied
+     * @throw FileNotFoundException If file <code>src</code> is not found
+     * @throw UnresolvedLinkException If <code>src</code> contains a symlink
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    virtual void removeAcl(const std::string & src)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */ = 0;
+
+    /**
+     * Replace the ACL of a file or directory. The entries must include
+     * those of the owner, group and others.
+     * @param src the path of the file or directory.
+     * @param aclSpec the entries of the new ACL.
+     * @throw AccessControlException If access is denied
+     * @throw FileNotFoundException If file <code>src</code> is not found
+     * @throw UnresolvedLinkException If <code>src</code> contains a symlink
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    virtual void setAcl(const std::string & src,
+                        const std::vector<AclEntry> & aclSpec)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */ = 0;
+
+    /**
+     * Get the ACL of a file or directory.
+     * @param src the path of the file or directory.
+     * @return the ACL.
+     * @throw AccessControlException If access is denied
+     * @throw FileNotFoundException If file <code>src</code> is not found
+     * @throw UnresolvedLinkException If <code>src</code> contains a symlink
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    //Idempotent
+    virtual AclStatus getAclStatus(const std::string & src)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */ = 0;
+
+    /**
+     * Set an extended attribute of a file or directory.
+     * @param src the path of the file or directory.
+     * @param xAttr the attribute with its value.
+     * @param flag the bits of XAttr::SetFlag, CREATE to create a new
+     *  attribute and REPLACE to replace an existing one.
+     * @throw AccessControlException If access is denied
+     * @throw FileNotFoundException If file <code>src</code> is not found
+     * @throw UnresolvedLinkException If <code>src</code> contains a symlink
+     * @throw HdfsIOException If an I/O error occurred, e.g. the attribute
+     *  exists without the REPLACE flag
+     */
+    virtual void setXAttr(const std::string & src, const XAttr & xAttr, int flag)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */ = 0;
+
+    /**
+     * Get extended attributes of a file or directory with their values.
+     * @param src the path of the file or directory.
+     * @param xAttrs the attributes to get, all of them if it is empty.
+     * @return the attributes.
+     * @throw AccessControlException If access is denied
+     * @throw FileNotFoundException If file <code>src</code> is not found
+     * @throw UnresolvedLinkException If <code>src</code> contains a symlink
+     * @throw HdfsIOException If an I/O error occurred, e.g. one of the
+     *  attributes does not exist
+     */
+    //Idempotent
+    virtual std::vector<XAttr> getXAttrs(const std::string & src,
+                                         const std::vector<XAttr> & xAttrs)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */ = 0;
+
+    /**
+     * List the extended attributes of a file or directory which the user
+     * may access, without their values.
+     * @param src the path of the file or directory.
+     * @return the attributes.
+     * @throw AccessControlException If access is denied
+     * @throw FileNotFoundException If file <code>src</code> is not found
+     * @throw UnresolvedLinkException If <code>src</code> contains a symlink
+     * @throw HdfsIOException If an I/O error occurred
+     */
+    //Idempotent
+    virtual std::vector<XAttr> listXAttrs(const std::string & src)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */ = 0;
+
+    /**
+     * Remove an extended attribute of a file or directory.
+     * @param src the path of the file or directory.
+     * @param xAttr the attribute, whose value is ignored.
+     * @throw AccessControlException If access is denied
+     * @throw FileNotFoundException If file <code>src</code> is not found
+     * @throw UnresolvedLinkException If <code>src</code> contains a symlink
+     * @throw HdfsIOException If an I/O error occurred, e.g. the attribute
+     *  does not exist
+     */
+    virtual void removeXAttr(const std::string & src, const XAttr & xAttr)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */ = 0;
+
 };
 }
 }
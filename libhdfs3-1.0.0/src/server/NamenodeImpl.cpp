@@ -337,20 +337,47 @@ bool NamenodeImpl::rename(const std::string & src, const std::string & dst)
     }
 }
 
-/*void NamenodeImpl::concat(const std::string & trg,
-                          const std::vector<std::string> & srcs)  throw (UnresolvedLinkException,
-         HdfsIOException) {
+void NamenodeImpl::rename2(const std::string & src, const std::string & dst,
+                           bool overwrite)
+/* throw (AccessControlException, DSQuotaExceededException,
+ FileAlreadyExistsException, FileNotFoundException,
+ NSQuotaExceededException, ParentNotDirectoryException,
+ SafeModeException, UnresolvedLinkException, HdfsIOException) */{
+    try {
+        Rename2RequestProto request;
+        Rename2ResponseProto response;
+        request.set_src(src);
+        request.set_dst(dst);
+        request.set_overwritedest(overwrite);
+        invoke(RpcCall(false, "rename2", &request, &response));
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<FileAlreadyExistsException, FileNotFoundException,
+                  NSQuotaExceededException, DSQuotaExceededException,
+                  ParentNotDirectoryException, SafeModeException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+void NamenodeImpl::concat(const std::string & trg,
+                          const std::vector<std::string> & srcs)
+/* throw (UnresolvedLinkException, HdfsIOException) */{
     try {
         ConcatRequestProto request;
         ConcatResponseProto response;
         request.set_trg(trg);
-        Build(srcs, request.mutable_srcs());
+
+        for (size_t i = 0; i < srcs.size(); ++i) {
+            request.add_srcs(srcs[i]);
+        }
+
         invoke(RpcCall(false, "concat", &request, &response));
     } catch (const HdfsRpcServerException & e) {
-        UnWrapper<UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        UnWrapper<FileNotFoundException, HadoopIllegalArgumentException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
         unwrapper.unwrap(__FILE__, __LINE__);
     }
-}*/
+}
 
 bool NamenodeImpl::truncate(const std::string & src, int64_t size,
                             const std::string & clientName)
@@ -492,8 +519,9 @@ void NamenodeImpl::renewLease(const std::string & clientName)
 }
 
 //Idempotent
-/*bool NamenodeImpl::recoverLease(const std::string & src,
-                                const std::string & clientName) {   throw (HdfsIOException)
+bool NamenodeImpl::recoverLease(const std::string & src,
+                                const std::string & clientName)
+/* throw (HdfsIOException) */{
     try {
         RecoverLeaseRequestProto request;
         RecoverLeaseResponseProto response;
@@ -502,12 +530,32 @@ void NamenodeImpl::renewLease(const std::string & clientName)
         invoke(RpcCall(true, "recoverLease", &request, &response));
         return response.result();
     } catch (const HdfsRpcServerException & e) {
-        UnWrapper<HdfsIOException> unwrapper(e);
+        UnWrapper<FileNotFoundException, AccessControlException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
         unwrapper.unwrap(__FILE__, __LINE__);
     }
 
     return false;
-}*/
+}
+
+//Idempotent
+bool NamenodeImpl::isFileClosed(const std::string & src)
+/* throw (AccessControlException, FileNotFoundException,
+ UnresolvedLinkException, HdfsIOException) */{
+    try {
+        IsFileClosedRequestProto request;
+        IsFileClosedResponseProto response;
+        request.set_src(src);
+        invoke(RpcCall(true, "isFileClosed", &request, &response));
+        return response.result();
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<FileNotFoundException, AccessControlException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+
+    return false;
+}
 
 //Idempotent
 std::vector<int64_t> NamenodeImpl::getFsStats() { /* throw (HdfsIOException) */
@@ -533,6 +581,23 @@ std::vector<int64_t> NamenodeImpl::getFsStats() { /* throw (HdfsIOException) */
     return std::vector<int64_t>();
 }
 
+//Idempotent
+ServerDefaults NamenodeImpl::getServerDefaults() { /* throw (HdfsIOException) */
+    ServerDefaults defaults;
+
+    try {
+        GetServerDefaultsRequestProto request;
+        GetServerDefaultsResponseProto response;
+        invoke(RpcCall(true, "getServerDefaults", &request, &response));
+        Convert(defaults, response.serverdefaults());
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+
+    return defaults;
+}
+
 /*void NamenodeImpl::metaSave(const std::string & filename)
  throw (HdfsIOException) {
     try {
@@ -585,8 +650,8 @@ FileStatus NamenodeImpl::getFileInfo(const std::string & src, bool *exist)
 }
 
 //Idempotent
-/*FileStatus NamenodeImpl::getFileLinkInfo(const std::string & src)
- throw (UnresolvedLinkException, HdfsIOException) {
+FileStatus NamenodeImpl::getFileLinkInfo(const std::string & src)
+/* throw (UnresolvedLinkException, HdfsIOException) */{
     FileStatus fileStatus;
 
     try {
@@ -596,22 +661,25 @@ FileStatus NamenodeImpl::getFileInfo(const std::string & src, bool *exist)
         invoke(RpcCall(true, "getFileLinkInfo", &request, &response));
 
         if (response.has_fs()) {
-            Convert(fileStatus, response.fs());
+            Convert(src, fileStatus, response.fs());
+            fileStatus.setPath(src.c_str());
             return fileStatus;
         }
 
         THROW(FileNotFoundException, "Path %s does not exist.", src.c_str());
     } catch (const HdfsRpcServerException & e) {
-        UnWrapper < UnresolvedLinkException,
+        UnWrapper < FileNotFoundException, UnresolvedLinkException,
                   HdfsIOException > unwrapper(e);
         unwrapper.unwrap(__FILE__, __LINE__);
     }
-}*/
+
+    return fileStatus;
+}
 
 //Idempotent
-/*ContentSummary NamenodeImpl::getContentSummary(const std::string & path)
- throw (FileNotFoundException,
- UnresolvedLinkException, HdfsIOException) {
+ContentSummary NamenodeImpl::getContentSummary(const std::string & path)
+/* throw (FileNotFoundException,
+ UnresolvedLinkException, HdfsIOException) */{
     ContentSummary contentSummary;
 
     try {
@@ -631,24 +699,28 @@ FileStatus NamenodeImpl::getFileInfo(const std::string & src, bool *exist)
                   UnresolvedLinkException, HdfsIOException > unwrapper(e);
         unwrapper.unwrap(__FILE__, __LINE__);
     }
-}*/
+
+    return contentSummary;
+}
 
 //Idempotent
-/*void NamenodeImpl::setQuota(const std::string & path, int64_t namespaceQuota,
-                            int64_t diskspaceQuota)  throw (AccessControlException,
-         FileNotFoundException, UnresolvedLinkException, HdfsIOException) {
+void NamenodeImpl::setQuota(const std::string & path, int64_t namespaceQuota,
+                            int64_t diskspaceQuota) /* throw (AccessControlException,
+         FileNotFoundException, UnresolvedLinkException, HdfsIOException) */{
     try {
         SetQuotaRequestProto request;
         SetQuotaResponseProto response;
         request.set_path(path);
         request.set_namespacequota(namespaceQuota);
         request.set_diskspacequota(diskspaceQuota);
-        invoke(RpcCall(true, "diskspaceQuota", &request, &response));
+        invoke(RpcCall(true, "setQuota", &request, &response));
     } catch (const HdfsRpcServerException & e) {
-        UnWrapper<HdfsIOException> unwrapper(e);
+        UnWrapper < FileNotFoundException, NSQuotaExceededException,
+                  DSQuotaExceededException, UnresolvedLinkException,
+                  HdfsIOException > unwrapper(e);
         unwrapper.unwrap(__FILE__, __LINE__);
     }
-}*/
+}
 
 //Idempotent
 void NamenodeImpl::fsync(const std::string & src, const std::string & client)
@@ -685,11 +757,11 @@ void NamenodeImpl::setTimes(const std::string & src, int64_t mtime,
     }
 }
 
-/*void NamenodeImpl::createSymlink(const std::string & target,
-                                 const std::string & link, const Permission & dirPerm, bool createParent)  throw (AccessControlException,
+void NamenodeImpl::createSymlink(const std::string & target,
+                                 const std::string & link, const Permission & dirPerm, bool createParent) /* throw (AccessControlException,
          FileAlreadyExistsException, FileNotFoundException,
          ParentNotDirectoryException,
-         UnresolvedLinkException, HdfsIOException) {
+         UnresolvedLinkException, HdfsIOException) */{
     try {
         CreateSymlinkRequestProto request;
         CreateSymlinkResponseProto response;
@@ -697,16 +769,18 @@ void NamenodeImpl::setTimes(const std::string & src, int64_t mtime,
         request.set_link(link);
         request.set_createparent(createParent);
         Build(dirPerm, request.mutable_dirperm());
-        invoke(RpcCall(true, "createSymlink", &request, &response));
+        invoke(RpcCall(false, "createSymlink", &request, &response));
     } catch (const HdfsRpcServerException & e) {
-        UnWrapper<FileNotFoundException, HdfsIOException> unwrapper(e);
+        UnWrapper < FileAlreadyExistsException, FileNotFoundException,
+                  ParentNotDirectoryException, SafeModeException,
+                  UnresolvedLinkException, HdfsIOException > unwrapper(e);
         unwrapper.unwrap(__FILE__, __LINE__);
     }
-}*/
+}
 
 //Idempotent
-/*std::string NamenodeImpl::getLinkTarget(const std::string & path)
- throw (FileNotFoundException, HdfsIOException) {
+std::string NamenodeImpl::getLinkTarget(const std::string & path)
+/* throw (FileNotFoundException, HdfsIOException) */{
     try {
         GetLinkTargetRequestProto request;
         GetLinkTargetResponseProto response;
@@ -718,7 +792,9 @@ void NamenodeImpl::setTimes(const std::string & src, int64_t mtime,
             e);
         unwrapper.unwrap(__FILE__, __LINE__);
     }
-}*/
+
+    return "";
+}
 
 //Idempotent
 shared_ptr<LocatedBlock> NamenodeImpl::updateBlockForPipeline(const ExtendedBlock & block,
@@ -821,7 +897,6 @@ EncryptionZoneInfo NamenodeImpl::getEncryptionZoneInfo(const std::string & src,
 
         if (response.has_zone()) {
             Convert(retval, response.zone());
-            retval.setPath(src.c_str());
 
             if (exist) {
                 *exist = true;
@@ -865,5 +940,310 @@ bool NamenodeImpl::listEncryptionZones(const int64_t id, std::vector<EncryptionZ
     }
 }
 
+std::string NamenodeImpl::getRpcAddress() {
+    return server.getHost() + ":" + server.getPort();
+}
+
+std::string NamenodeImpl::createSnapshot(const std::string & snapshotRoot,
+        const std::string & snapshotName)
+/* throw (AccessControlException, HdfsIOException) */{
+    try {
+        CreateSnapshotRequestProto request;
+        CreateSnapshotResponseProto response;
+        request.set_snapshotroot(snapshotRoot);
+
+        if (!snapshotName.empty()) {
+            request.set_snapshotname(snapshotName);
+        }
+
+        invoke(RpcCall(false, "createSnapshot", &request, &response));
+        return response.snapshotpath();
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+void NamenodeImpl::deleteSnapshot(const std::string & snapshotRoot,
+                                  const std::string & snapshotName)
+/* throw (AccessControlException, HdfsIOException) */{
+    try {
+        DeleteSnapshotRequestProto request;
+        DeleteSnapshotResponseProto response;
+        request.set_snapshotroot(snapshotRoot);
+        request.set_snapshotname(snapshotName);
+        invoke(RpcCall(false, "deleteSnapshot", &request, &response));
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+void NamenodeImpl::renameSnapshot(const std::string & snapshotRoot,
+                                  const std::string & snapshotOldName,
+                                  const std::string & snapshotNewName)
+/* throw (AccessControlException, HdfsIOException) */{
+    try {
+        RenameSnapshotRequestProto request;
+        RenameSnapshotResponseProto response;
+        request.set_snapshotroot(snapshotRoot);
+        request.set_snapshotoldname(snapshotOldName);
+        request.set_snapshotnewname(snapshotNewName);
+        invoke(RpcCall(false, "renameSnapshot", &request, &response));
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+void NamenodeImpl::allowSnapshot(const std::string & snapshotRoot)
+/* throw (AccessControlException, HdfsIOException) */{
+    try {
+        AllowSnapshotRequestProto request;
+        AllowSnapshotResponseProto response;
+        request.set_snapshotroot(snapshotRoot);
+        invoke(RpcCall(false, "allowSnapshot", &request, &response));
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+void NamenodeImpl::disallowSnapshot(const std::string & snapshotRoot)
+/* throw (AccessControlException, HdfsIOException) */{
+    try {
+        DisallowSnapshotRequestProto request;
+        DisallowSnapshotResponseProto response;
+        request.set_snapshotroot(snapshotRoot);
+        invoke(RpcCall(false, "disallowSnapshot", &request, &response));
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+//Idempotent
+void NamenodeImpl::getSnapshottableDirListing(
+    std::vector<SnapshottableDirectoryStatus> & dirs)
+/* throw (HdfsIOException) */{
+    try {
+        GetSnapshottableDirListingRequestProto request;
+        GetSnapshottableDirListingResponseProto response;
+        invoke(RpcCall(true, "getSnapshottableDirListing", &request, &response));
+
+        if (response.has_snapshottabledirlist()) {
+            Convert(dirs, response.snapshottabledirlist());
+        }
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+//Idempotent
+SnapshotDiffReport NamenodeImpl::getSnapshotDiffReport(const std::string & snapshotRoot,
+        const std::string & fromSnapshot, const std::string & toSnapshot)
+/* throw (AccessControlException, HdfsIOException) */{
+    SnapshotDiffReport retval;
+
+    try {
+        GetSnapshotDiffReportRequestProto request;
+        GetSnapshotDiffReportResponseProto response;
+        request.set_snapshotroot(snapshotRoot);
+        request.set_fromsnapshot(fromSnapshot);
+        request.set_tosnapshot(toSnapshot);
+        invoke(RpcCall(true, "getSnapshotDiffReport", &request, &response));
+        Convert(retval, response.diffreport());
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+
+    return retval;
+}
+
+void NamenodeImpl::modifyAclEntries(const std::string & src,
+                                    const std::vector<AclEntry> & aclSpec)
+/* throw (AccessControlException, FileNotFoundException,
+ UnresolvedLinkException, HdfsIOException) */{
+    try {
+        ModifyAclEntriesRequestProto request;
+        ModifyAclEntriesResponseProto response;
+        request.set_src(src);
+        Build(aclSpec, request.mutable_aclspec());
+        invoke(RpcCall(false, "modifyAclEntries", &request, &response));
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, FileNotFoundException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+void NamenodeImpl::removeAclEntries(const std::string & src,
+                                    const std::vector<AclEntry> & aclSpec)
+/* throw (AccessControlException, FileNotFoundException,
+ UnresolvedLinkException, HdfsIOException) */{
+    try {
+        RemoveAclEntriesRequestProto request;
+        RemoveAclEntriesResponseProto response;
+        request.set_src(src);
+        Build(aclSpec, request.mutable_aclspec());
+        invoke(RpcCall(false, "removeAclEntries", &request, &response));
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, FileNotFoundException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+void NamenodeImpl::removeDefaultAcl(const std::string & src)
+/* throw (AccessControlException, FileNotFoundException,
+ UnresolvedLinkException, HdfsIOException) */{
+    try {
+        RemoveDefaultAclRequestProto request;
+        RemoveDefaultAclResponseProto response;
+        request.set_src(src);
+        invoke(RpcCall(false, "removeDefaultAcl", &request, &response));
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, FileNotFoundException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+void NamenodeImpl::removeAcl(const std::string & src)
+/* throw (AccessControlException, FileNotFoundException,
+ UnresolvedLinkException, HdfsIOException) */{
+    try {
+        RemoveAclRequestProto request;
+        RemoveAclResponseProto response;
+        request.set_src(src);
+        invoke(RpcCall(false, "removeAcl", &request, &response));
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, FileNotFoundException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+void NamenodeImpl::setAcl(const std::string & src,
+                          const std::vector<AclEntry> & aclSpec)
+/* throw (AccessControlException, FileNotFoundException,
+ UnresolvedLinkException, HdfsIOException) */{
+    try {
+        SetAclRequestProto request;
+        SetAclResponseProto response;
+        request.set_src(src);
+        Build(aclSpec, request.mutable_aclspec());
+        invoke(RpcCall(false, "setAcl", &request, &response));
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, FileNotFoundException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+//Idempotent
+AclStatus NamenodeImpl::getAclStatus(const std::string & src)
+/* throw (AccessControlException, FileNotFoundException,
+ UnresolvedLinkException, HdfsIOException) */{
+    AclStatus retval;
+
+    try {
+        GetAclStatusRequestProto request;
+        GetAclStatusResponseProto response;
+        request.set_src(src);
+        invoke(RpcCall(true, "getAclStatus", &request, &response));
+        Convert(retval, response.result());
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, FileNotFoundException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+
+    return retval;
+}
+
+void NamenodeImpl::setXAttr(const std::string & src, const XAttr & xAttr, int flag)
+/* throw (AccessControlException, FileNotFoundException,
+ UnresolvedLinkException, HdfsIOException) */{
+    try {
+        SetXAttrRequestProto request;
+        SetXAttrResponseProto response;
+        request.set_src(src);
+        Build(xAttr, request.mutable_xattr());
+        request.set_flag(flag);
+        invoke(RpcCall(false, "setXAttr", &request, &response));
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, FileNotFoundException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
+//Idempotent
+std::vector<XAttr> NamenodeImpl::getXAttrs(const std::string & src,
+        const std::vector<XAttr> & xAttrs)
+/* throw (AccessControlException, FileNotFoundException,
+ UnresolvedLinkException, HdfsIOException) */{
+    std::vector<XAttr> retval;
+
+    try {
+        GetXAttrsRequestProto request;
+        GetXAttrsResponseProto response;
+        request.set_src(src);
+
+        for (size_t i = 0; i < xAttrs.size(); ++i) {
+            Build(xAttrs[i], request.add_xattrs());
+        }
+
+        invoke(RpcCall(true, "getXAttrs", &request, &response));
+        Convert(retval, response.xattrs());
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, FileNotFoundException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+
+    return retval;
+}
+
+//Idempotent
+std::vector<XAttr> NamenodeImpl::listXAttrs(const std::string & src)
+/* throw (AccessControlException, FileNotFoundException,
+ UnresolvedLinkException, HdfsIOException) */{
+    std::vector<XAttr> retval;
+
+    try {
+        ListXAttrsRequestProto request;
+        ListXAttrsResponseProto response;
+        request.set_src(src);
+        invoke(RpcCall(true, "listXAttrs", &request, &response));
+        Convert(retval, response.xattrs());
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, FileNotFoundException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+
+    return retval;
+}
+
+void NamenodeImpl::removeXAttr(const std::string & src, const XAttr & xAttr)
+/* throw (AccessControlException, FileNotFoundException,
+ UnresolvedLinkException, HdfsIOException) */{
+    try {
+        RemoveXAttrRequestProto request;
+        RemoveXAttrResponseProto response;
+        request.set_src(src);
+        Build(xAttr, request.mutable_xattr());
+        invoke(RpcCall(false, "removeXAttr", &request, &response));
+    } catch (const HdfsRpcServerException & e) {
+        UnWrapper<AccessControlException, FileNotFoundException,
+                  UnresolvedLinkException, HdfsIOException> unwrapper(e);
+        unwrapper.unwrap(__FILE__, __LINE__);
+    }
+}
+
 }
 }
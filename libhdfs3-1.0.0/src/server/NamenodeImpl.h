@@ -118,11 +118,11 @@ public:
     void releaseLease(const std::string & src, const std::string & clientName)
     /* throw (HdfsIOException, UnresolvedLinkException) */;
 
-    /*void rename2(const std::string & src, const std::string & dst)
-     throw (AccessControlException, DSQuotaExceededException,
+    void rename2(const std::string & src, const std::string & dst, bool overwrite)
+    /* throw (AccessControlException, DSQuotaExceededException,
      FileAlreadyExistsException, FileNotFoundException,
      NSQuotaExceededException, ParentNotDirectoryException,
-     SafeModeException, UnresolvedLinkException, HdfsIOException) ;*/
+     SafeModeException, UnresolvedLinkException, HdfsIOException) */;
 
     bool deleteFile(const std::string & src, bool recursive)
     /* throw (AccessControlException, FileNotFoundException,
@@ -149,9 +149,17 @@ public:
     bool recoverLease(const std::string & src, const std::string & clientName)
     /* throw (HdfsIOException) */;
 
+    //Idempotent
+    bool isFileClosed(const std::string & src)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */;
+
     //Idempotent
     std::vector<int64_t> getFsStats() /* throw (HdfsIOException) */;
 
+    //Idempotent
+    ServerDefaults getServerDefaults() /* throw (HdfsIOException) */;
+
     void metaSave(const std::string & filename) /* throw (HdfsIOException) */;
 
     //Idempotent
@@ -164,10 +172,10 @@ public:
     /* throw (AccessControlException, UnresolvedLinkException,
      HdfsIOException) */;
 
-    /*    //Idempotent
-        ContentSummary getContentSummary(const std::string & path)
-         throw (AccessControlException, FileNotFoundException,
-         UnresolvedLinkException, HdfsIOException) ;*/
+    //Idempotent
+    ContentSummary getContentSummary(const std::string & path)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */;
 
     //Idempotent
     void setQuota(const std::string & path, int64_t namespaceQuota,
@@ -226,6 +234,81 @@ public:
     bool listEncryptionZones(const int64_t id, std::vector<EncryptionZoneInfo> & ezl);
     /* throw (AccessControlException, UnresolvedLinkException, HdfsIOException) */ 
 
+    std::string getRpcAddress();
+
+    std::string createSnapshot(const std::string & snapshotRoot,
+                               const std::string & snapshotName)
+    /* throw (AccessControlException, HdfsIOException) */;
+
+    void deleteSnapshot(const std::string & snapshotRoot,
+                        const std::string & snapshotName)
+    /* throw (AccessControlException, HdfsIOException) */;
+
+    void renameSnapshot(const std::string & snapshotRoot,
+                        const std::string & snapshotOldName,
+                        const std::string & snapshotNewName)
+    /* throw (AccessControlException, HdfsIOException) */;
+
+    void allowSnapshot(const std::string & snapshotRoot)
+    /* throw (AccessControlException, HdfsIOException) */;
+
+    void disallowSnapshot(const std::string & snapshotRoot)
+    /* throw (AccessControlException, HdfsIOException) */;
+
+    //Idempotent
+    void getSnapshottableDirListing(std::vector<SnapshottableDirectoryStatus> & dirs)
+    /* throw (HdfsIOException) */;
+
+    //Idempotent
+    SnapshotDiffReport getSnapshotDiffReport(const std::string & snapshotRoot,
+            const std::string & fromSnapshot, const std::string & toSnapshot)
+    /* throw (AccessControlException, HdfsIOException) */;
+
+    void modifyAclEntries(const std::string & src,
+                          const std::vector<AclEntry> & aclSpec)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */;
+
+    void removeAclEntries(const std::string & src,
+                          const std::vector<AclEntry> & aclSpec)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */;
+
+    void removeDefaultAcl(const std::string & src)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */;
+
+    void removeAcl(const std::string & src)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */;
+
+    void setAcl(const std::string & src, const std::vector<AclEntry> & aclSpec)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */;
+
+    //Idempotent
+    AclStatus getAclStatus(const std::string & src)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */;
+
+    void setXAttr(const std::string & src, const XAttr & xAttr, int flag)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */;
+
+    //Idempotent
+    std::vector<XAttr> getXAttrs(const std::string & src,
+                                 const std::vector<XAttr> & xAttrs)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */;
+
+    //Idempotent
+    std::vector<XAttr> listXAttrs(const std::string & src)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */;
+
+    void removeXAttr(const std::string & src, const XAttr & xAttr)
+    /* throw (AccessControlException, FileNotFoundException,
+     UnresolvedLinkException, HdfsIOException) */;
 
 private:
     void invoke(const RpcCall & call);
@@ -333,14 +333,19 @@ bool NamenodeProxy::rename(const std::string & src, const std::string & dst) {
     return false;
 }
 
-/*
+void NamenodeProxy::rename2(const std::string & src, const std::string & dst,
+                            bool overwrite) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->rename2(src, dst, overwrite);
+    NAMENODE_HA_RETRY_END();
+}
+
 void NamenodeProxy::concat(const std::string & trg,
                            const std::vector<std::string> & srcs) {
     NAMENODE_HA_RETRY_BEGIN();
     namenode->concat(trg, srcs);
     NAMENODE_HA_RETRY_END();
 }
-*/
 
 bool NamenodeProxy::truncate(const std::string & src, int64_t size,
                              const std::string & clientName) {
@@ -396,14 +401,22 @@ void NamenodeProxy::renewLease(const std::string & clientName) {
     NAMENODE_HA_RETRY_END();
 }
 
-/*bool NamenodeProxy::recoverLease(const std::string & src,
+bool NamenodeProxy::recoverLease(const std::string & src,
                                  const std::string & clientName) {
     NAMENODE_HA_RETRY_BEGIN();
     return namenode->recoverLease(src, clientName);
     NAMENODE_HA_RETRY_END();
     assert(!"should not reach here");
     return false;
-}*/
+}
+
+bool NamenodeProxy::isFileClosed(const std::string & src) {
+    NAMENODE_HA_RETRY_BEGIN();
+    return namenode->isFileClosed(src);
+    NAMENODE_HA_RETRY_END();
+    assert(!"should not reach here");
+    return false;
+}
 
 std::vector<int64_t> NamenodeProxy::getFsStats() {
     NAMENODE_HA_RETRY_BEGIN();
@@ -413,6 +426,14 @@ std::vector<int64_t> NamenodeProxy::getFsStats() {
     return std::vector<int64_t>();
 }
 
+ServerDefaults NamenodeProxy::getServerDefaults() {
+    NAMENODE_HA_RETRY_BEGIN();
+    return namenode->getServerDefaults();
+    NAMENODE_HA_RETRY_END();
+    assert(!"should not reach here");
+    return ServerDefaults();
+}
+
 /*void NamenodeProxy::metaSave(const std::string & filename) {
     NAMENODE_HA_RETRY_BEGIN();
     namenode->metaSave(filename);
@@ -427,28 +448,28 @@ FileStatus NamenodeProxy::getFileInfo(const std::string & src, bool *exist) {
     return FileStatus();
 }
 
-/*FileStatus NamenodeProxy::getFileLinkInfo(const std::string & src) {
+FileStatus NamenodeProxy::getFileLinkInfo(const std::string & src) {
     NAMENODE_HA_RETRY_BEGIN();
     return namenode->getFileLinkInfo(src);
     NAMENODE_HA_RETRY_END();
     assert(!"should not reach here");
     return FileStatus();
-}*/
+}
 
-/*ContentSummary NamenodeProxy::getContentSummary(const std::string & path) {
+ContentSummary NamenodeProxy::getContentSummary(const std::string & path) {
     NAMENODE_HA_RETRY_BEGIN();
     return namenode->getContentSummary(path);
     NAMENODE_HA_RETRY_END();
     assert(!"should not reach here");
     return ContentSummary();
-}*/
+}
 
-/*void NamenodeProxy::setQuota(const std::string & path, int64_t namespaceQuota,
+void NamenodeProxy::setQuota(const std::string & path, int64_t namespaceQuota,
                              int64_t diskspaceQuota) {
     NAMENODE_HA_RETRY_BEGIN();
     namenode->setQuota(path, namespaceQuota, diskspaceQuota);
     NAMENODE_HA_RETRY_END();
-}*/
+}
 
 void NamenodeProxy::fsync(const std::string & src, const std::string & client) {
     NAMENODE_HA_RETRY_BEGIN();
@@ -463,21 +484,21 @@ void NamenodeProxy::setTimes(const std::string & src, int64_t mtime,
     NAMENODE_HA_RETRY_END();
 }
 
-/*void NamenodeProxy::createSymlink(const std::string & target,
+void NamenodeProxy::createSymlink(const std::string & target,
                                   const std::string & link, const Permission & dirPerm,
                                   bool createParent) {
     NAMENODE_HA_RETRY_BEGIN();
     namenode->createSymlink(target, link, dirPerm, createParent);
     NAMENODE_HA_RETRY_END();
-}*/
+}
 
-/*std::string NamenodeProxy::getLinkTarget(const std::string & path) {
+std::string NamenodeProxy::getLinkTarget(const std::string & path) {
     NAMENODE_HA_RETRY_BEGIN();
     return namenode->getLinkTarget(path);
     NAMENODE_HA_RETRY_END();
     assert(!"should not reach here");
     return "";
-}*/
+}
 
 shared_ptr<LocatedBlock> NamenodeProxy::updateBlockForPipeline(
     const ExtendedBlock & block, const std::string & clientName) {
@@ -524,6 +545,133 @@ void NamenodeProxy::close() {
     namenodes.clear();
 }
 
+std::string NamenodeProxy::getRpcAddress() {
+    uint32_t oldValue = 0;
+    return getActiveNamenode(oldValue)->getRpcAddress();
+}
+
+std::string NamenodeProxy::createSnapshot(const std::string & snapshotRoot,
+        const std::string & snapshotName) {
+    NAMENODE_HA_RETRY_BEGIN();
+    return namenode->createSnapshot(snapshotRoot, snapshotName);
+    NAMENODE_HA_RETRY_END();
+    assert(!"should not reach here");
+    return "";
+}
+
+void NamenodeProxy::deleteSnapshot(const std::string & snapshotRoot,
+                                   const std::string & snapshotName) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->deleteSnapshot(snapshotRoot, snapshotName);
+    NAMENODE_HA_RETRY_END();
+}
+
+void NamenodeProxy::renameSnapshot(const std::string & snapshotRoot,
+                                   const std::string & snapshotOldName,
+                                   const std::string & snapshotNewName) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->renameSnapshot(snapshotRoot, snapshotOldName, snapshotNewName);
+    NAMENODE_HA_RETRY_END();
+}
+
+void NamenodeProxy::allowSnapshot(const std::string & snapshotRoot) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->allowSnapshot(snapshotRoot);
+    NAMENODE_HA_RETRY_END();
+}
+
+void NamenodeProxy::disallowSnapshot(const std::string & snapshotRoot) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->disallowSnapshot(snapshotRoot);
+    NAMENODE_HA_RETRY_END();
+}
+
+void NamenodeProxy::getSnapshottableDirListing(
+    std::vector<SnapshottableDirectoryStatus> & dirs) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->getSnapshottableDirListing(dirs);
+    NAMENODE_HA_RETRY_END();
+}
+
+SnapshotDiffReport NamenodeProxy::getSnapshotDiffReport(const std::string & snapshotRoot,
+        const std::string & fromSnapshot, const std::string & toSnapshot) {
+    NAMENODE_HA_RETRY_BEGIN();
+    return namenode->getSnapshotDiffReport(snapshotRoot, fromSnapshot, toSnapshot);
+    NAMENODE_HA_RETRY_END();
+    assert(!"should not reach here");
+    return SnapshotDiffReport();
+}
+
+void NamenodeProxy::modifyAclEntries(const std::string & src,
+                                     const std::vector<AclEntry> & aclSpec) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->modifyAclEntries(src, aclSpec);
+    NAMENODE_HA_RETRY_END();
+}
+
+void NamenodeProxy::removeAclEntries(const std::string & src,
+                                     const std::vector<AclEntry> & aclSpec) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->removeAclEntries(src, aclSpec);
+    NAMENODE_HA_RETRY_END();
+}
+
+void NamenodeProxy::removeDefaultAcl(const std::string & src) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->removeDefaultAcl(src);
+    NAMENODE_HA_RETRY_END();
+}
+
+void NamenodeProxy::removeAcl(const std::string & src) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->removeAcl(src);
+    NAMENODE_HA_RETRY_END();
+}
+
+void NamenodeProxy::setAcl(const std::string & src,
+                           const std::vector<AclEntry> & aclSpec) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->setAcl(src, aclSpec);
+    NAMENODE_HA_RETRY_END();
+}
+
+AclStatus NamenodeProxy::getAclStatus(const std::string & src) {
+    NAMENODE_HA_RETRY_BEGIN();
+    return namenode->getAclStatus(src);
+    NAMENODE_HA_RETRY_END();
+    assert(!"should not reach here");
+    return AclStatus();
+}
+
+void NamenodeProxy::setXAttr(const std::string & src, const XAttr & xAttr, int flag) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->setXAttr(src, xAttr, flag);
+    NAMENODE_HA_RETRY_END();
+}
+
+std::vector<XAttr> NamenodeProxy::getXAttrs(const std::string & src,
+        const std::vector<XAttr> & xAttrs) {
+    NAMENODE_HA_RETRY_BEGIN();
+    return namenode->getXAttrs(src, xAttrs);
+    NAMENODE_HA_RETRY_END();
+    assert(!"should not reach here");
+    return std::vector<XAttr>();
+}
+
+std::vector<XAttr> NamenodeProxy::listXAttrs(const std::string & src) {
+    NAMENODE_HA_RETRY_BEGIN();
+    return namenode->listXAttrs(src);
+    NAMENODE_HA_RETRY_END();
+    assert(!"should not reach here");
+    return std::vector<XAttr>();
+}
+
+void NamenodeProxy::removeXAttr(const std::string & src, const XAttr & xAttr) {
+    NAMENODE_HA_RETRY_BEGIN();
+    namenode->removeXAttr(src, xAttr);
+    NAMENODE_HA_RETRY_END();
+}
+
 bool NamenodeProxy::createEncryptionZone(const std::string & src, const std::string & keyName) {
     NAMENODE_HA_RETRY_BEGIN();
     return namenode->createEncryptionZone(src, keyName);
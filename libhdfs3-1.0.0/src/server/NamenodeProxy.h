@@ -85,11 +85,7 @@ public:
 
     void releaseLease(const std::string & src, const std::string & clientName);
 
-    /*void rename2(const std::string & src, const std::string & dst)
-     throw (AccessControlException, DSQuotaExceededException,
-     FileAlreadyExistsException, FileNotFoundException,
-     NSQuotaExceededException, ParentNotDirectoryException,
-     SafeModeException, UnresolvedLinkException, HdfsIOException) ;*/
+    void rename2(const std::string & src, const std::string & dst, bool overwrite);
 
     bool deleteFile(const std::string & src, bool recursive);
 
@@ -103,14 +99,20 @@ public:
 
     bool recoverLease(const std::string & src, const std::string & clientName);
 
+    bool isFileClosed(const std::string & src);
+
     std::vector<int64_t> getFsStats();
 
+    ServerDefaults getServerDefaults();
+
     void metaSave(const std::string & filename);
 
     FileStatus getFileInfo(const std::string & src, bool *exist);
 
     FileStatus getFileLinkInfo(const std::string & src);
 
+    ContentSummary getContentSummary(const std::string & path);
+
     void setQuota(const std::string & path, int64_t namespaceQuota,
                   int64_t diskspaceQuota);
 
@@ -139,6 +141,47 @@ public:
 
     void close();
 
+    std::string getRpcAddress();
+
+    std::string createSnapshot(const std::string & snapshotRoot,
+                               const std::string & snapshotName);
+
+    void deleteSnapshot(const std::string & snapshotRoot,
+                        const std::string & snapshotName);
+
+    void renameSnapshot(const std::string & snapshotRoot,
+                        const std::string & snapshotOldName,
+                        const std::string & snapshotNewName);
+
+    void allowSnapshot(const std::string & snapshotRoot);
+
+    void disallowSnapshot(const std::string & snapshotRoot);
+
+    void getSnapshottableDirListing(std::vector<SnapshottableDirectoryStatus> & dirs);
+
+    SnapshotDiffReport getSnapshotDiffReport(const std::string & snapshotRoot,
+            const std::string & fromSnapshot, const std::string & toSnapshot);
+
+    void modifyAclEntries(const std::string & src, const std::vector<AclEntry> & aclSpec);
+
+    void removeAclEntries(const std::string & src, const std::vector<AclEntry> & aclSpec);
+
+    void removeDefaultAcl(const std::string & src);
+
+    void removeAcl(const std::string & src);
+
+    void setAcl(const std::string & src, const std::vector<AclEntry> & aclSpec);
+
+    AclStatus getAclStatus(const std::string & src);
+
+    void setXAttr(const std::string & src, const XAttr & xAttr, int flag);
+
+    std::vector<XAttr> getXAttrs(const std::string & src, const std::vector<XAttr> & xAttrs);
+
+    std::vector<XAttr> listXAttrs(const std::string & src);
+
+    void removeXAttr(const std::string & src, const XAttr & xAttr);
+
     bool createEncryptionZone(const std::string & path, const std::string & keyName);
 
     EncryptionZoneInfo getEncryptionZoneInfo(const std::string & src, bool *exist);
@@ -23,8 +23,13 @@
 #define _HDFS_LIBHDFS3_SERVER_RPCHELPER_H_
 
 #include "client/FileStatus.h"
+#include "client/ContentSummary.h"
 #include "client/EncryptionZoneInfo.h"
+#include "client/SnapshotInfo.h"
+#include "client/AclInfo.h"
+#include "client/XAttrInfo.h"
 #include "client/Permission.h"
+#include "client/ServerDefaults.h"
 #include "ClientDatanodeProtocol.pb.h"
 #include "ClientNamenodeProtocol.pb.h"
 #include "DatanodeInfo.h"
@@ -46,6 +51,21 @@ namespace Internal {
 class Nothing {
 };
 
+/*
+ * Does a remote exception class unwrap to T? The namenode reports a symlink in a path
+ * with UnresolvedPathException, a subclass of UnresolvedLinkException.
+ */
+template<typename T>
+static inline bool IsErrClass(const std::string & errClass) {
+    return errClass == T::ReflexName;
+}
+
+template<>
+inline bool IsErrClass<UnresolvedLinkException>(const std::string & errClass) {
+    return errClass == UnresolvedLinkException::ReflexName
+           || errClass == "org.apache.hadoop.hdfs.protocol.UnresolvedPathException";
+}
+
 template < typename T1 = Nothing, typename T2 = Nothing, typename T3 = Nothing,
          typename T4 = Nothing, typename T5 = Nothing, typename T6 = Nothing,
          typename T7 = Nothing, typename T8 = Nothing, typename T9 = Nothing,
@@ -61,7 +81,7 @@ public:
 
     void ATTRIBUTE_NORETURN ATTRIBUTE_NOINLINE unwrap(const char * file,
             int line) {
-        if (e.getErrClass() == T1::ReflexName) {
+        if (IsErrClass<T1>(e.getErrClass())) {
 #ifdef NEED_BOOST
             boost::throw_exception(T1(e.getErrMsg(), SkipPathPrefix(file), line, PrintStack(1, STACK_DEPTH).c_str()));
 #else
@@ -227,6 +247,58 @@ static inline void Convert(const std::string & src,
     }
 }
 
+static inline void Convert(std::vector<SnapshottableDirectoryStatus> & dirs,
+                           const SnapshottableDirectoryListingProto & proto) {
+    RepeatedPtrField<SnapshottableDirectoryStatusProto> ptrproto =
+        proto.snapshottabledirlisting();
+
+    for (int i = 0; i < ptrproto.size(); i++) {
+        const SnapshottableDirectoryStatusProto & item = ptrproto.Get(i);
+        SnapshottableDirectoryStatus status;
+        FileStatus dirStatus;
+        std::string parent = item.parent_fullpath();
+        Convert(parent == "/" ? "" : parent, dirStatus, item.dirstatus());
+        status.setDirStatus(dirStatus);
+        status.setSnapshotQuota(item.snapshot_quota());
+        status.setSnapshotNumber(item.snapshot_number());
+        status.setParentFullPath(parent.c_str());
+        dirs.push_back(status);
+    }
+}
+
+static inline void Convert(SnapshotDiffReport & report,
+                           const SnapshotDiffReportProto & proto) {
+    report.setSnapshotRoot(proto.snapshotroot().c_str());
+    report.setFromSnapshot(proto.fromsnapshot().c_str());
+    report.setToSnapshot(proto.tosnapshot().c_str());
+    RepeatedPtrField<SnapshotDiffReportEntryProto> ptrproto =
+        proto.diffreportentries();
+
+    for (int i = 0; i < ptrproto.size(); i++) {
+        const SnapshotDiffReportEntryProto & item = ptrproto.Get(i);
+        const std::string & label = item.modificationlabel();
+        SnapshotDiffEntry entry;
+
+        if (label == "CREATE" || label == "+") {
+            entry.setType(SnapshotDiffEntry::CREATE);
+        } else if (label == "DELETE" || label == "-") {
+            entry.setType(SnapshotDiffEntry::DELETE);
+        } else if (label == "RENAME" || label == "R") {
+            entry.setType(SnapshotDiffEntry::RENAME);
+        } else {
+            entry.setType(SnapshotDiffEntry::MODIFY);
+        }
+
+        entry.setPath(item.fullpath().c_str());
+
+        if (item.has_targetpath()) {
+            entry.setTargetPath(item.targetpath().c_str());
+        }
+
+        report.getEntries().push_back(entry);
+    }
+}
+
 static inline Token Convert(const TokenProto & proto) {
     Token retval;
     retval.setIdentifier(proto.identifier());
@@ -235,14 +307,68 @@ static inline Token Convert(const TokenProto & proto) {
     return retval;
 }
 
-/*static inline void Convert(ContentSummary & contentSummary, const ContentSummaryProto & proto) {
+static inline void Convert(ContentSummary & contentSummary, const ContentSummaryProto & proto) {
     contentSummary.setDirectoryCount(proto.directorycount());
     contentSummary.setFileCount(proto.filecount());
     contentSummary.setLength(proto.length());
     contentSummary.setQuota(proto.quota());
     contentSummary.setSpaceConsumed(proto.spaceconsumed());
     contentSummary.setSpaceQuota(proto.spacequota());
-}*/
+}
+
+static inline void Convert(ServerDefaults & defaults, const FsServerDefaultsProto & proto) {
+    defaults.setBlockSize(proto.blocksize());
+    defaults.setBytesPerChecksum(proto.bytesperchecksum());
+    defaults.setWritePacketSize(proto.writepacketsize());
+    defaults.setReplication(proto.replication());
+    defaults.setFileBufferSize(proto.filebuffersize());
+    defaults.setEncryptDataTransfer(proto.encryptdatatransfer());
+    defaults.setTrashInterval(proto.trashinterval());
+    defaults.setChecksumType(proto.checksumtype());
+}
+
+static inline void Convert(AclEntry & entry, const AclEntryProto & proto) {
+    entry.setScope(static_cast<AclEntry::Scope>(proto.scope()));
+    entry.setType(static_cast<AclEntry::Type>(proto.type()));
+    entry.setPermissions(proto.permissions());
+
+    if (proto.has_name()) {
+        entry.setName(proto.name().c_str());
+    }
+}
+
+static inline void Convert(AclStatus & status, const AclStatusProto & proto) {
+    status.setOwner(proto.owner().c_str());
+    status.setGroup(proto.group().c_str());
+    status.setSticky(proto.sticky());
+
+    if (proto.has_permission()) {
+        // drop the ACL and encryption bits the namenode sets above the mode
+        status.setPermission(proto.permission().perm() & 01777);
+    }
+
+    for (int i = 0; i < proto.entries_size(); ++i) {
+        AclEntry entry;
+        Convert(entry, proto.entries(i));
+        status.getEntries().push_back(entry);
+    }
+}
+
+static inline void Convert(std::vector<XAttr> & xAttrs,
+                           const RepeatedPtrField<XAttrProto> & proto) {
+    for (int i = 0; i < proto.size(); ++i) {
+        const XAttrProto & item = proto.Get(i);
+        XAttr xAttr;
+        xAttr.setNamespace(static_cast<XAttr::Namespace>(item.namespace_()));
+        xAttr.setName(item.name().c_str());
+
+        if (item.has_value()) {
+            xAttr.setValue(item.value());
+        }
+
+        xAttrs.push_back(xAttr);
+    }
+}
 
 static inline void Build(const Token & token,
                          TokenProto * proto) {
@@ -312,6 +438,30 @@ static inline void Build(const std::vector<DatanodeInfo> & dns,
     }
 }
 
+static inline void Build(const XAttr & xAttr, XAttrProto * proto) {
+    proto->set_namespace_(static_cast<XAttrProto::XAttrNamespaceProto>(xAttr.getNamespace()));
+    proto->set_name(xAttr.getName());
+
+    if (xAttr.hasValue()) {
+        proto->set_value(xAttr.getValue());
+    }
+}
+
+static inline void Build(const std::vector<AclEntry> & entries,
+                         RepeatedPtrField<AclEntryProto> * proto) {
+    for (size_t i = 0; i < entries.size(); ++i) {
+        AclEntryProto * p = proto->Add();
+        p->set_scope(static_cast<AclEntryProto::AclEntryScopeProto>(entries[i].getScope()));
+        p->set_type(static_cast<AclEntryProto::AclEntryTypeProto>(entries[i].getType()));
+        p->set_permissions(
+            static_cast<AclEntryProto::FsActionProto>(entries[i].getPermissions() & 07));
+
+        if (entries[i].getName()[0] != '\0') {
+            p->set_name(entries[i].getName());
+        }
+    }
+}
+
 }
 }
 
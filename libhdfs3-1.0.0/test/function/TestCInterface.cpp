@@ -1540,6 +1540,20 @@ TEST_F(TestCInterface, TestGetHANamenode) {
     EXPECT_NO_THROW(hdfsFreeNamenodeInformation(namenodes, size));
 }
 
+TEST_F(TestCInterface, TestGetActiveNamenode) {
+    char * ret, buffer[1024];
+    //test invalid input
+    ret = hdfsGetActiveNamenode(NULL, buffer, sizeof(buffer));
+    EXPECT_TRUE(ret == 0 && EINVAL == errno);
+    ret = hdfsGetActiveNamenode(fs, NULL, sizeof(buffer));
+    EXPECT_TRUE(ret == 0 && EINVAL == errno);
+    ret = hdfsGetActiveNamenode(fs, buffer, 0);
+    EXPECT_TRUE(ret == 0 && EINVAL == errno);
+    ret = hdfsGetActiveNamenode(fs, buffer, sizeof(buffer));
+    ASSERT_TRUE(ret != NULL);
+    EXPECT_TRUE(strchr(buffer, ':') != NULL);
+}
+
 TEST_F(TestCInterface, TestGetBlockFileLocations_Failure) {
     int size;
     EXPECT_TRUE(NULL == hdfsGetFileBlockLocations(NULL, NULL, 0, 0, NULL));
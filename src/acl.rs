@@ -0,0 +1,333 @@
+use std::ffi::{CStr, CString};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use libc::{c_char, c_int};
+
+use crate::err::HdfsErr;
+use crate::permission::FsAction;
+use crate::{hdfsAclEntry, hdfsAclStatus};
+
+/// Whether an ACL entry applies to the file or directory itself, or is inherited by the
+/// children created in a directory
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AclEntryScope {
+    Access,
+    Default,
+}
+
+/// The class of users an ACL entry applies to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AclEntryType {
+    User,
+    Group,
+    /// The maximum permission of named users, the group and named groups
+    Mask,
+    Other,
+    /// A type this version does not know, with the raw type reported by libhdfs3
+    Unknown(i32),
+}
+
+impl AclEntryType {
+    /// The name of the type in an ACL spec, e.g. `user`, and `unknown` for unknown types,
+    /// which cannot be parsed
+    pub fn name(self) -> &'static str {
+        match self {
+            AclEntryType::User => "user",
+            AclEntryType::Group => "group",
+            AclEntryType::Mask => "mask",
+            AclEntryType::Other => "other",
+            AclEntryType::Unknown(_) => "unknown",
+        }
+    }
+
+    fn from_raw(entry_type: c_int) -> AclEntryType {
+        match entry_type {
+            0 => AclEntryType::User,
+            1 => AclEntryType::Group,
+            2 => AclEntryType::Mask,
+            3 => AclEntryType::Other,
+            other => AclEntryType::Unknown(other),
+        }
+    }
+
+    fn to_raw(self) -> c_int {
+        match self {
+            AclEntryType::User => 0,
+            AclEntryType::Group => 1,
+            AclEntryType::Mask => 2,
+            AclEntryType::Other => 3,
+            AclEntryType::Unknown(raw) => raw,
+        }
+    }
+}
+
+/// An entry of the ACL of a file or directory, as `hdfs dfs -getfacl` lists it, e.g.
+/// `user:alice:rwx` or `default:group::r-x`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AclEntry {
+    pub scope: AclEntryScope,
+    pub entry_type: AclEntryType,
+    /// Name of the user or group, `None` for the owner, the group of the file, the mask and
+    /// others
+    pub name: Option<String>,
+    pub permission: FsAction,
+}
+
+impl AclEntry {
+    pub fn new(
+        scope: AclEntryScope,
+        entry_type: AclEntryType,
+        name: Option<&str>,
+        permission: FsAction,
+    ) -> AclEntry {
+        AclEntry {
+            scope,
+            entry_type,
+            name: name.map(str::to_owned),
+            permission,
+        }
+    }
+
+    /// Parse an entry, e.g. `default:user:alice:rwx`.
+    ///
+    /// * include_permission - whether the entry ends with a permission, which entries to
+    ///   remove, e.g. `user:alice`, do not. Their permission is `FsAction::None`.
+    pub fn parse(entry: &str, include_permission: bool) -> Result<AclEntry, HdfsErr> {
+        let invalid = |reason: &str| HdfsErr::InvalidAclSpec(entry.to_owned(), reason.to_owned());
+        let mut parts: Vec<&str> = entry.split(':').collect();
+        // as in Hadoop, trailing empty fields may be left out, e.g. `mask` for `mask::`
+        while parts.len() > 1 && parts.last() == Some(&"") {
+            parts.pop();
+        }
+        let mut parts = parts.into_iter().peekable();
+        let scope = match parts.next_if_eq(&"default") {
+            Some(_) => AclEntryScope::Default,
+            None => AclEntryScope::Access,
+        };
+        let entry_type = match parts.next().map(str::to_ascii_lowercase).as_deref() {
+            Some("user") => AclEntryType::User,
+            Some("group") => AclEntryType::Group,
+            Some("mask") => AclEntryType::Mask,
+            Some("other") => AclEntryType::Other,
+            _ => return Err(invalid("invalid type")),
+        };
+        let name = parts.next().filter(|name| !name.is_empty());
+        if name.is_some() && matches!(entry_type, AclEntryType::Mask | AclEntryType::Other) {
+            return Err(invalid("mask and other entries cannot have a name"));
+        }
+        let permission = if include_permission {
+            parts
+                .next()
+                .and_then(FsAction::from_symbol)
+                .ok_or_else(|| invalid("invalid permission"))?
+        } else {
+            FsAction::None
+        };
+        if parts.next().is_some() {
+            return Err(invalid("too many fields"));
+        }
+        Ok(AclEntry::new(scope, entry_type, name, permission))
+    }
+
+    /// Parse a comma-separated ACL spec, as given to `hdfs dfs -setfacl`, e.g.
+    /// `user::rwx,group::r-x,other::---,user:alice:rw-`
+    pub fn parse_spec(spec: &str, include_permission: bool) -> Result<Vec<AclEntry>, HdfsErr> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| AclEntry::parse(entry, include_permission))
+            .collect()
+    }
+}
+
+impl FromStr for AclEntry {
+    type Err = HdfsErr;
+
+    /// Parse an entry with a permission, e.g. `user:alice:rwx`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AclEntry::parse(s, true)
+    }
+}
+
+impl Display for AclEntry {
+    /// Format as an ACL spec, e.g. `default:group::r-x`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.scope == AclEntryScope::Default {
+            f.write_str("default:")?;
+        }
+        write!(
+            f,
+            "{}:{}:{}",
+            self.entry_type.name(),
+            self.name.as_deref().unwrap_or(""),
+            self.permission
+        )
+    }
+}
+
+/// The ACL of a file or directory, with its owner, group and permission
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AclStatus {
+    pub owner: String,
+    pub group: String,
+    /// Permission bits of the file, whose group bits are the mask if it has an ACL
+    pub permission: i16,
+    pub sticky: bool,
+    /// The entries besides the owner, group and other entries of the permission
+    pub entries: Vec<AclEntry>,
+}
+
+impl AclStatus {
+    /// All entries of the ACL in the order `hdfs dfs -getfacl` lists them, including those
+    /// of the permission bits: the owner, named users, the group, named groups, the mask,
+    /// others and then the default entries.
+    pub fn full_entries(&self) -> Vec<AclEntry> {
+        let bits = self.permission as u16;
+        let access = |entry_type, shift| {
+            AclEntry::new(
+                AclEntryScope::Access,
+                entry_type,
+                None,
+                FsAction::from_bits(bits >> shift),
+            )
+        };
+        let mut entries = vec![access(AclEntryType::User, 6)];
+        entries.extend(
+            self.entries
+                .iter()
+                .filter(|entry| entry.scope == AclEntryScope::Access)
+                .cloned(),
+        );
+        // with access entries, the group entry is among them and the group bits hold the mask
+        entries.push(if self.has_access_acl() {
+            access(AclEntryType::Mask, 3)
+        } else {
+            access(AclEntryType::Group, 3)
+        });
+        entries.push(access(AclEntryType::Other, 0));
+        entries.extend(
+            self.entries
+                .iter()
+                .filter(|entry| entry.scope == AclEntryScope::Default)
+                .cloned(),
+        );
+        entries
+    }
+
+    /// The permission an entry actually grants, which for named users, the group and named
+    /// groups is limited by the mask of its scope.
+    pub fn effective_permission(&self, entry: &AclEntry) -> FsAction {
+        let masked = match entry.entry_type {
+            AclEntryType::User => entry.name.is_some(),
+            AclEntryType::Group => true,
+            AclEntryType::Mask | AclEntryType::Other | AclEntryType::Unknown(_) => false,
+        };
+        if !masked {
+            return entry.permission;
+        }
+        let mask = match entry.scope {
+            AclEntryScope::Access if self.has_access_acl() => {
+                Some(FsAction::from_bits(self.permission as u16 >> 3))
+            }
+            AclEntryScope::Access => None,
+            AclEntryScope::Default => self
+                .entries
+                .iter()
+                .find(|e| e.scope == AclEntryScope::Default && e.entry_type == AclEntryType::Mask)
+                .map(|e| e.permission),
+        };
+        mask.map_or(entry.permission, |mask| entry.permission.and(mask))
+    }
+
+    /// Does the file have access entries besides its permission bits?
+    fn has_access_acl(&self) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.scope == AclEntryScope::Access)
+    }
+}
+
+/// The entries of an ACL spec as passed to libhdfs3, which borrow the names they own
+pub(crate) struct RawAclSpec {
+    _names: Vec<Option<CString>>,
+    entries: Vec<hdfsAclEntry>,
+}
+
+impl RawAclSpec {
+    pub(crate) fn new(entries: &[AclEntry]) -> Result<RawAclSpec, HdfsErr> {
+        let names = entries
+            .iter()
+            .map(|entry| {
+                entry
+                    .name
+                    .as_deref()
+                    .map(CString::new)
+                    .transpose()
+                    .map_err(|_| {
+                        HdfsErr::InvalidAclSpec(entry.to_string(), "name contains NUL".to_owned())
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let raw = entries
+            .iter()
+            .zip(&names)
+            .map(|(entry, name)| hdfsAclEntry {
+                mScope: entry.scope as c_int,
+                mType: entry.entry_type.to_raw(),
+                mPermissions: entry.permission.bits() as c_int,
+                mName: name
+                    .as_ref()
+                    .map_or(std::ptr::null_mut(), |name| name.as_ptr() as *mut c_char),
+            })
+            .collect();
+        Ok(RawAclSpec {
+            _names: names,
+            entries: raw,
+        })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const hdfsAclEntry {
+        self.entries.as_ptr()
+    }
+
+    pub(crate) fn len(&self) -> c_int {
+        self.entries.len() as c_int
+    }
+}
+
+pub(crate) fn acl_status(status: &hdfsAclStatus) -> AclStatus {
+    let entries = (0..status.mNumEntries.max(0) as usize)
+        .map(|idx| acl_entry(unsafe { &*status.mEntries.add(idx) }))
+        .collect();
+    AclStatus {
+        owner: c_string(status.mOwner),
+        group: c_string(status.mGroup),
+        permission: status.mPermissions,
+        sticky: status.mSticky != 0,
+        entries,
+    }
+}
+
+fn acl_entry(entry: &hdfsAclEntry) -> AclEntry {
+    AclEntry {
+        scope: if entry.mScope == 1 {
+            AclEntryScope::Default
+        } else {
+            AclEntryScope::Access
+        },
+        entry_type: AclEntryType::from_raw(entry.mType),
+        name: Some(c_string(entry.mName)).filter(|name| !name.is_empty()),
+        permission: FsAction::from_bits(entry.mPermissions as u16),
+    }
+}
+
+fn c_string(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned()
+    }
+}
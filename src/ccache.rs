@@ -0,0 +1,377 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use log::*;
+
+use crate::err::HdfsErr;
+
+/// How often the ticket cache file is checked for changes
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long before the expiry of the ticket a warning is logged
+const WARN_BEFORE_EXPIRY: Duration = Duration::from_secs(15 * 60);
+
+/// Realm of the configuration entries stored in a ccache alongside the tickets
+const CONFIG_REALM: &str = "X-CACHECONF:";
+
+/// A Kerberos principal, such as `hdfs/nn1.example.com@EXAMPLE.COM`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Principal {
+    pub name_type: u32,
+    pub realm: String,
+    pub components: Vec<String>,
+}
+
+impl Display for Principal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}@{}", self.components.join("/"), self.realm)
+    }
+}
+
+/// A ticket held in a credential cache.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ticket {
+    pub client: Principal,
+    pub server: Principal,
+    pub auth_time: SystemTime,
+    /// `None` if the ticket is valid from `auth_time`
+    pub start_time: Option<SystemTime>,
+    pub end_time: SystemTime,
+    /// `None` if the ticket is not renewable
+    pub renew_till: Option<SystemTime>,
+    pub flags: u32,
+}
+
+impl Ticket {
+    /// Is this a ticket granting ticket?
+    pub fn is_tgt(&self) -> bool {
+        self.server.components.len() == 2
+            && self.server.components[0] == "krbtgt"
+            && self.server.components[1] == self.server.realm
+    }
+
+    /// Has the ticket expired?
+    pub fn is_expired(&self) -> bool {
+        self.end_time <= SystemTime::now()
+    }
+}
+
+/// The content of an MIT Kerberos credential cache file, as written by `kinit` or `k5start`.
+///
+/// Versions 3 and 4 of the file format are supported.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CredentialCache {
+    pub version: u16,
+    /// The default principal of the cache
+    pub principal: Principal,
+    /// The tickets, without the configuration entries of the cache
+    pub tickets: Vec<Ticket>,
+}
+
+impl CredentialCache {
+    /// Read a credential cache file. A `FILE:` prefix is accepted.
+    pub fn read(path: impl AsRef<Path>) -> Result<CredentialCache, HdfsErr> {
+        let path = path.as_ref();
+        let path = path
+            .to_str()
+            .and_then(|p| p.strip_prefix("FILE:"))
+            .map(Path::new)
+            .unwrap_or(path);
+        let content = std::fs::read(path).map_err(|e| {
+            HdfsErr::Miscellaneous(format!("Could not read {}: {}", path.display(), e))
+        })?;
+        CredentialCache::parse(&content).map_err(|e| match e {
+            HdfsErr::Miscellaneous(reason) => {
+                HdfsErr::Miscellaneous(format!("{}: {}", path.display(), reason))
+            }
+            e => e,
+        })
+    }
+
+    /// Parse the content of a credential cache file.
+    pub fn parse(content: &[u8]) -> Result<CredentialCache, HdfsErr> {
+        let mut reader = Reader::new(content);
+        let version = reader.read_u16()?;
+        match version {
+            0x0503 => {}
+            0x0504 => {
+                // Header tags, such as the KDC time offset, are not needed.
+                let header_len = reader.read_u16()?;
+                reader.read_bytes(header_len as usize)?;
+            }
+            _ => {
+                return Err(invalid(&format!(
+                    "unsupported format version {:#06x}",
+                    version
+                )))
+            }
+        }
+        let principal = reader.read_principal()?;
+
+        let mut tickets = Vec::new();
+        while !reader.is_empty() {
+            let ticket = reader.read_credential(version)?;
+            if ticket.server.realm != CONFIG_REALM {
+                tickets.push(ticket);
+            }
+        }
+
+        Ok(CredentialCache {
+            version: version & 0xff,
+            principal,
+            tickets,
+        })
+    }
+
+    /// The ticket granting ticket of the default principal's realm
+    pub fn tgt(&self) -> Option<&Ticket> {
+        self.tickets
+            .iter()
+            .find(|ticket| ticket.is_tgt() && ticket.server.realm == self.principal.realm)
+    }
+
+    /// Expiry time of the credentials: that of the ticket granting ticket, or of the last
+    /// service ticket to expire if there is none.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        match self.tgt() {
+            Some(tgt) => Some(tgt.end_time),
+            None => self.tickets.iter().map(|ticket| ticket.end_time).max(),
+        }
+    }
+}
+
+/// Path of the ticket cache file used by the native client: the configured path, otherwise
+/// `KRB5CCNAME`, otherwise `/tmp/krb5cc_<uid>`. `None` for caches which are not files, such
+/// as `KEYRING:` or `KCM:` caches.
+pub fn ticket_cache_path(configured: Option<&str>) -> Option<PathBuf> {
+    let name = match configured {
+        Some(path) => path.to_owned(),
+        None => env::var("KRB5CCNAME")
+            .unwrap_or_else(|_| format!("/tmp/krb5cc_{}", unsafe { libc::getuid() })),
+    };
+    match name.split_once(':') {
+        Some(("FILE", path)) => Some(PathBuf::from(path)),
+        Some((kind, _)) if kind.chars().all(|c| c.is_ascii_uppercase()) => None,
+        _ => Some(PathBuf::from(name)),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Watches a ticket cache file, warning before the credentials expire and detecting when
+/// the file is refreshed.
+pub(crate) struct TicketCacheWatch {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    expires_at: Option<SystemTime>,
+    warned: bool,
+    last_check: Instant,
+}
+
+impl TicketCacheWatch {
+    pub(crate) fn new(path: PathBuf) -> TicketCacheWatch {
+        let mut watch = TicketCacheWatch {
+            modified: modified(&path),
+            path,
+            expires_at: None,
+            warned: false,
+            last_check: Instant::now(),
+        };
+        watch.load();
+        watch.warn_before_expiry();
+        watch
+    }
+
+    /// Expiry time of the credentials in the cache, as last read
+    pub(crate) fn expires_at(&self) -> Option<SystemTime> {
+        self.expires_at
+    }
+
+    /// Is a check of the cache file due?
+    pub(crate) fn is_due(&self) -> bool {
+        self.last_check.elapsed() >= CHECK_INTERVAL
+    }
+
+    /// Check the cache file if due, returning true if it has been refreshed since the last
+    /// check.
+    pub(crate) fn check(&mut self) -> bool {
+        if !self.is_due() {
+            return false;
+        }
+        self.last_check = Instant::now();
+
+        let modified = modified(&self.path);
+        let refreshed = modified.is_some() && modified != self.modified;
+        if refreshed {
+            self.modified = modified;
+            self.warned = false;
+            self.load();
+        }
+        self.warn_before_expiry();
+        refreshed
+    }
+
+    fn load(&mut self) {
+        match CredentialCache::read(&self.path) {
+            Ok(cache) => {
+                self.expires_at = cache.expires_at();
+                debug!(
+                    "Kerberos ticket cache {} of {} expires at {:?}",
+                    self.path.display(),
+                    cache.principal,
+                    self.expires_at
+                );
+            }
+            Err(e) => {
+                self.expires_at = None;
+                debug!("Could not inspect Kerberos ticket cache: {}", e);
+            }
+        }
+    }
+
+    fn warn_before_expiry(&mut self) {
+        let expires_at = match self.expires_at {
+            Some(expires_at) if !self.warned => expires_at,
+            _ => return,
+        };
+        match expires_at.duration_since(SystemTime::now()) {
+            Err(_) => {
+                warn!(
+                    "Kerberos ticket in {} has expired, run kinit to refresh it",
+                    self.path.display()
+                );
+                self.warned = true;
+            }
+            Ok(remaining) if remaining < WARN_BEFORE_EXPIRY => {
+                warn!(
+                    "Kerberos ticket in {} expires in {} seconds",
+                    self.path.display(),
+                    remaining.as_secs()
+                );
+                self.warned = true;
+            }
+            Ok(_) => {}
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn invalid(reason: &str) -> HdfsErr {
+    HdfsErr::Miscellaneous(format!("Invalid credential cache: {}", reason))
+}
+
+/// Reader of the big endian encoding of format versions 3 and 4
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Reader<'a> {
+        Reader { buf, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos == self.buf.len()
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], HdfsErr> {
+        if len > self.buf.len() - self.pos {
+            return Err(invalid("unexpected end of data"));
+        }
+        let bytes = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, HdfsErr> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> Result<u16, HdfsErr> {
+        let bytes = self.read_bytes(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u32(&mut self) -> Result<u32, HdfsErr> {
+        let bytes = self.read_bytes(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn read_data(&mut self) -> Result<&'a [u8], HdfsErr> {
+        let len = self.read_u32()?;
+        self.read_bytes(len as usize)
+    }
+
+    fn read_string(&mut self) -> Result<String, HdfsErr> {
+        Ok(String::from_utf8_lossy(self.read_data()?).into_owned())
+    }
+
+    fn read_time(&mut self) -> Result<SystemTime, HdfsErr> {
+        Ok(UNIX_EPOCH + Duration::from_secs(self.read_u32()? as u64))
+    }
+
+    fn read_optional_time(&mut self) -> Result<Option<SystemTime>, HdfsErr> {
+        let time = self.read_time()?;
+        Ok(Some(time).filter(|time| *time != UNIX_EPOCH))
+    }
+
+    fn read_principal(&mut self) -> Result<Principal, HdfsErr> {
+        let name_type = self.read_u32()?;
+        let count = self.read_u32()?;
+        let realm = self.read_string()?;
+        let components = (0..count)
+            .map(|_| self.read_string())
+            .collect::<Result<Vec<String>, HdfsErr>>()?;
+        Ok(Principal {
+            name_type,
+            realm,
+            components,
+        })
+    }
+
+    fn read_credential(&mut self, version: u16) -> Result<Ticket, HdfsErr> {
+        let client = self.read_principal()?;
+        let server = self.read_principal()?;
+
+        // Keyblock: the enctype is written twice in version 3.
+        self.read_u16()?;
+        if version == 0x0503 {
+            self.read_u16()?;
+        }
+        self.read_data()?;
+
+        let auth_time = self.read_time()?;
+        let start_time = self.read_optional_time()?;
+        let end_time = self.read_time()?;
+        let renew_till = self.read_optional_time()?;
+        let _is_skey = self.read_u8()?;
+        let flags = self.read_u32()?;
+
+        // Addresses and authorization data
+        for _ in 0..2 {
+            for _ in 0..self.read_u32()? {
+                self.read_u16()?;
+                self.read_data()?;
+            }
+        }
+        // Ticket and second ticket
+        self.read_data()?;
+        self.read_data()?;
+
+        Ok(Ticket {
+            client,
+            server,
+            auth_time,
+            start_time,
+            end_time,
+            renew_till,
+            flags,
+        })
+    }
+}
@@ -0,0 +1,980 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use log::*;
+use serde::Deserialize;
+
+use crate::err::HdfsErr;
+use crate::kms::{KmsClient, KEY_PROVIDER_PATH, KEY_PROVIDER_URI};
+
+/// A size in bytes, such as a block or buffer size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(transparent)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    pub const fn bytes(n: u64) -> ByteSize {
+        ByteSize(n)
+    }
+
+    pub const fn kib(n: u64) -> ByteSize {
+        ByteSize(n * 1024)
+    }
+
+    pub const fn mib(n: u64) -> ByteSize {
+        ByteSize(n * 1024 * 1024)
+    }
+
+    pub const fn gib(n: u64) -> ByteSize {
+        ByteSize(n * 1024 * 1024 * 1024)
+    }
+
+    #[inline]
+    pub const fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for ByteSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Authentication method used for the namenode RPC connection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthMethod {
+    Simple,
+    Kerberos,
+}
+
+impl AuthMethod {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AuthMethod::Simple => "simple",
+            AuthMethod::Kerberos => "kerberos",
+        }
+    }
+}
+
+/// Log level of the native client library.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum LogSeverity {
+    Fatal,
+    Error,
+    Warning,
+    Info,
+    Debug1,
+    Debug2,
+    Debug3,
+}
+
+impl LogSeverity {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LogSeverity::Fatal => "FATAL",
+            LogSeverity::Error => "ERROR",
+            LogSeverity::Warning => "WARNING",
+            LogSeverity::Info => "INFO",
+            LogSeverity::Debug1 => "DEBUG1",
+            LogSeverity::Debug2 => "DEBUG2",
+            LogSeverity::Debug3 => "DEBUG3",
+        }
+    }
+}
+
+/// The libhdfs3 defaults of the settings, as in `common/SessionConfig.cpp`. They apply to the
+/// settings which neither a [`ClientConfig`] nor the site configuration sets.
+pub const SESSION_CONFIG_DEFAULTS: &[(&str, &str)] = &[
+    ("hadoop.security.authentication", "simple"),
+    ("dfs.client.log.severity", "INFO"),
+    ("dfs.default.replica", "3"),
+    ("dfs.default.blocksize", "67108864"),
+    ("dfs.prefetchsize", "10"),
+    ("dfs.domain.socket.path", ""),
+    ("rpc.client.connect.tcpnodelay", "true"),
+    ("rpc.client.max.idle", "10000"),
+    ("rpc.client.ping.interval", "10000"),
+    ("rpc.client.connect.timeout", "600000"),
+    ("rpc.client.read.timeout", "3600000"),
+    ("rpc.client.write.timeout", "3600000"),
+    ("rpc.client.socekt.linger.timeout", "-1"),
+    ("rpc.client.connect.retry", "10"),
+    ("rpc.client.timeout", "3600000"),
+    ("dfs.client.failover.max.attempts", "15"),
+    ("dfs.client.read.shortcircuit", "true"),
+    ("dfs.client.use.legacy.blockreader.local", "false"),
+    ("dfs.client.read.shortcircuit.streams.cache.size", "256"),
+    ("dfs.client.socketcache.capacity", "16"),
+    ("dfs.client.socketcache.expiryMsec", "3000"),
+    ("input.connect.timeout", "600000"),
+    ("input.read.timeout", "3600000"),
+    ("input.write.timeout", "3600000"),
+    ("input.localread.default.buffersize", "1048576"),
+    ("input.localread.blockinfo.cachesize", "1000"),
+    ("input.localread.mappedfile", "false"),
+    ("input.read.getblockinfo.retry", "3"),
+    ("input.read.max.retry", "60"),
+    ("input.notretry-another-node", "false"),
+    ("output.replace-datanode-on-failure", "true"),
+    ("output.default.chunksize", "512"),
+    ("output.default.packetsize", "65536"),
+    ("output.default.write.retry", "10"),
+    ("output.connect.timeout", "600000"),
+    ("output.read.timeout", "3600000"),
+    ("output.write.timeout", "3600000"),
+    ("output.close.timeout", "3600000"),
+    ("output.packetpool.size", "1024"),
+    ("output.heeartbeat.interval", "10000"),
+];
+
+/// A setting of the effective configuration, see [`ClientConfig::effective`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EffectiveSetting {
+    /// The libhdfs3 key, e.g. `rpc.client.timeout`
+    pub key: &'static str,
+    /// The value as passed to libhdfs3, with durations in milliseconds and sizes in bytes
+    pub value: String,
+    /// Is the value set in the configuration, rather than the libhdfs3 default?
+    pub is_set: bool,
+}
+
+/// Typed HDFS client configuration.
+///
+/// Covers the settings read by libhdfs3 in `common/SessionConfig.cpp`. Only the settings which
+/// are set are passed to libhdfs3, so the others keep the values of the site configuration,
+/// or the libhdfs3 defaults of [`SESSION_CONFIG_DEFAULTS`] if it has none. Its `Debug`
+/// output shows the [effective](ClientConfig::effective) configuration. Use
+/// [`ClientConfig::builder`] to create an instance, and
+/// [`HdfsFs::new_with_client_config`](crate::hdfs3::HdfsFs::new_with_client_config) to
+/// connect with it.
+///
+/// It can also be deserialized, e.g. from a [profile](crate::profile) file, using the field
+/// names as keys. Durations are then given in milliseconds and sizes in bytes.
+#[derive(Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ClientConfig {
+    /// `hadoop.security.authentication`
+    pub auth_method: Option<AuthMethod>,
+    /// `dfs.client.log.severity`
+    pub log_severity: Option<LogSeverity>,
+    /// `dfs.default.replica`
+    pub default_replica: Option<u16>,
+    /// `dfs.default.blocksize`
+    pub default_block_size: Option<ByteSize>,
+    /// `dfs.prefetchsize`, the number of blocks whose information is fetched at once
+    pub prefetch_size: Option<u32>,
+    /// `dfs.domain.socket.path`
+    pub domain_socket_path: Option<String>,
+    /// `dfs.encryption.key.provider.uri`, the KMS which decrypts the keys of files in
+    /// encryption zones, e.g. `kms://http@kms:9600/kms`
+    pub key_provider_uri: Option<String>,
+
+    /// `rpc.client.connect.tcpnodelay`
+    pub rpc_tcp_no_delay: Option<bool>,
+    /// `rpc.client.max.idle`
+    #[serde(with = "option_duration_millis")]
+    pub rpc_max_idle: Option<Duration>,
+    /// `rpc.client.ping.interval`
+    #[serde(with = "option_duration_millis")]
+    pub rpc_ping_interval: Option<Duration>,
+    /// `rpc.client.connect.timeout`
+    #[serde(with = "option_duration_millis")]
+    pub rpc_connect_timeout: Option<Duration>,
+    /// `rpc.client.read.timeout`
+    #[serde(with = "option_duration_millis")]
+    pub rpc_read_timeout: Option<Duration>,
+    /// `rpc.client.write.timeout`
+    #[serde(with = "option_duration_millis")]
+    pub rpc_write_timeout: Option<Duration>,
+    /// `rpc.client.socekt.linger.timeout`
+    #[serde(with = "option_duration_millis")]
+    pub rpc_socket_linger_timeout: Option<Duration>,
+    /// `rpc.client.connect.retry`
+    pub rpc_connect_retry: Option<u32>,
+    /// `rpc.client.timeout`
+    #[serde(with = "option_duration_millis")]
+    pub rpc_timeout: Option<Duration>,
+    /// `dfs.client.failover.max.attempts`, the number of failovers between HA namenodes
+    /// before an RPC fails
+    pub failover_max_attempts: Option<u32>,
+
+    /// `dfs.client.read.shortcircuit`
+    pub read_short_circuit: Option<bool>,
+    /// `dfs.client.use.legacy.blockreader.local`
+    pub legacy_local_block_reader: Option<bool>,
+    /// `dfs.client.read.shortcircuit.streams.cache.size`
+    pub short_circuit_streams_cache_size: Option<u32>,
+    /// `dfs.client.socketcache.capacity`
+    pub socket_cache_capacity: Option<u32>,
+    /// `dfs.client.socketcache.expiryMsec`
+    #[serde(with = "option_duration_millis")]
+    pub socket_cache_expiry: Option<Duration>,
+
+    /// `input.connect.timeout`
+    #[serde(with = "option_duration_millis")]
+    pub input_connect_timeout: Option<Duration>,
+    /// `input.read.timeout`
+    #[serde(with = "option_duration_millis")]
+    pub input_read_timeout: Option<Duration>,
+    /// `input.write.timeout`
+    #[serde(with = "option_duration_millis")]
+    pub input_write_timeout: Option<Duration>,
+    /// `input.localread.default.buffersize`
+    pub local_read_buffer_size: Option<ByteSize>,
+    /// `input.localread.blockinfo.cachesize`
+    pub local_block_info_cache_size: Option<u32>,
+    /// `input.localread.mappedfile`
+    pub local_read_mapped_file: Option<bool>,
+    /// `input.read.getblockinfo.retry`
+    pub get_block_info_retry: Option<u32>,
+    /// `input.read.max.retry`
+    pub read_max_retry: Option<u32>,
+    /// `input.notretry-another-node`
+    pub not_retry_another_node: Option<bool>,
+
+    /// `output.replace-datanode-on-failure`
+    pub replace_datanode_on_failure: Option<bool>,
+    /// `output.default.chunksize`
+    pub chunk_size: Option<ByteSize>,
+    /// `output.default.packetsize`
+    pub packet_size: Option<ByteSize>,
+    /// `output.default.write.retry`
+    pub write_retry: Option<u32>,
+    /// `output.connect.timeout`
+    #[serde(with = "option_duration_millis")]
+    pub output_connect_timeout: Option<Duration>,
+    /// `output.read.timeout`
+    #[serde(with = "option_duration_millis")]
+    pub output_read_timeout: Option<Duration>,
+    /// `output.write.timeout`
+    #[serde(with = "option_duration_millis")]
+    pub output_write_timeout: Option<Duration>,
+    /// `output.close.timeout`
+    #[serde(with = "option_duration_millis")]
+    pub close_timeout: Option<Duration>,
+    /// `output.packetpool.size`
+    pub packet_pool_size: Option<u32>,
+    /// `output.heeartbeat.interval`
+    #[serde(with = "option_duration_millis")]
+    pub heartbeat_interval: Option<Duration>,
+}
+
+/// Shows the effective configuration, each value marked as set or default, e.g.
+/// `{"dfs.default.replica": "3 (default)", "rpc.client.timeout": "30000 (set)", ..}`.
+impl std::fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.effective().into_iter().map(|setting| {
+                let source = if setting.is_set { "set" } else { "default" };
+                (setting.key, format!("{} ({})", setting.value, source))
+            }))
+            .finish()
+    }
+}
+
+impl ClientConfig {
+    /// Create a builder with no settings.
+    pub fn builder() -> ClientConfigBuilder {
+        ClientConfigBuilder {
+            config: ClientConfig::default(),
+        }
+    }
+
+    /// Check that every setting is accepted by the native client.
+    pub fn validate(&self) -> Result<(), HdfsErr> {
+        check_at_least(
+            "dfs.default.replica",
+            self.default_replica.map(u64::from),
+            1,
+        )?;
+        check_multiple_of_512("dfs.default.blocksize", self.default_block_size)?;
+        check_i64("dfs.default.blocksize", bytes(self.default_block_size))?;
+        check_at_least("dfs.prefetchsize", self.prefetch_size.map(u64::from), 1)?;
+        if self.domain_socket_path.as_deref() == Some("") {
+            return Err(invalid("dfs.domain.socket.path", "path must not be empty"));
+        }
+        if let Some(uri) = &self.key_provider_uri {
+            if KmsClient::new(uri).is_err() {
+                return Err(invalid(KEY_PROVIDER_URI, "not a KMS URI"));
+            }
+        }
+
+        check_millis("rpc.client.max.idle", self.rpc_max_idle)?;
+        check_at_least(
+            "rpc.client.max.idle",
+            self.rpc_max_idle.map(|idle| idle.as_millis() as u64),
+            1,
+        )?;
+        check_millis("rpc.client.ping.interval", self.rpc_ping_interval)?;
+        check_millis("rpc.client.connect.timeout", self.rpc_connect_timeout)?;
+        check_millis("rpc.client.read.timeout", self.rpc_read_timeout)?;
+        check_millis("rpc.client.write.timeout", self.rpc_write_timeout)?;
+        check_millis(
+            "rpc.client.socekt.linger.timeout",
+            self.rpc_socket_linger_timeout,
+        )?;
+        let connect_retry = self.rpc_connect_retry.map(u64::from);
+        check_at_least("rpc.client.connect.retry", connect_retry, 1)?;
+        check_i32("rpc.client.connect.retry", connect_retry)?;
+        check_millis("rpc.client.timeout", self.rpc_timeout)?;
+        check_i32(
+            "dfs.client.failover.max.attempts",
+            self.failover_max_attempts.map(u64::from),
+        )?;
+
+        check_i32(
+            "dfs.client.read.shortcircuit.streams.cache.size",
+            self.short_circuit_streams_cache_size.map(u64::from),
+        )?;
+        check_i32(
+            "dfs.client.socketcache.capacity",
+            self.socket_cache_capacity.map(u64::from),
+        )?;
+        check_millis(
+            "dfs.client.socketcache.expiryMsec",
+            self.socket_cache_expiry,
+        )?;
+
+        check_millis("input.connect.timeout", self.input_connect_timeout)?;
+        check_millis("input.read.timeout", self.input_read_timeout)?;
+        check_millis("input.write.timeout", self.input_write_timeout)?;
+        let buffer_size = bytes(self.local_read_buffer_size);
+        check_at_least("input.localread.default.buffersize", buffer_size, 1)?;
+        check_i32("input.localread.default.buffersize", buffer_size)?;
+        let cache_size = self.local_block_info_cache_size.map(u64::from);
+        check_at_least("input.localread.blockinfo.cachesize", cache_size, 1)?;
+        check_i32("input.localread.blockinfo.cachesize", cache_size)?;
+        let block_info_retry = self.get_block_info_retry.map(u64::from);
+        check_at_least("input.read.getblockinfo.retry", block_info_retry, 1)?;
+        check_i32("input.read.getblockinfo.retry", block_info_retry)?;
+        let read_retry = self.read_max_retry.map(u64::from);
+        check_at_least("input.read.max.retry", read_retry, 1)?;
+        check_i32("input.read.max.retry", read_retry)?;
+
+        check_multiple_of_512("output.default.chunksize", self.chunk_size)?;
+        check_i32("output.default.chunksize", bytes(self.chunk_size))?;
+        check_i32("output.default.packetsize", bytes(self.packet_size))?;
+        // the other size may come from the site configuration, so only compare both when set
+        if let (Some(packet_size), Some(chunk_size)) = (self.packet_size, self.chunk_size) {
+            if packet_size < chunk_size {
+                return Err(invalid(
+                    "output.default.packetsize",
+                    "must not be smaller than output.default.chunksize",
+                ));
+            }
+        }
+        let write_retry = self.write_retry.map(u64::from);
+        check_at_least("output.default.write.retry", write_retry, 1)?;
+        check_i32("output.default.write.retry", write_retry)?;
+        check_millis("output.connect.timeout", self.output_connect_timeout)?;
+        check_millis("output.read.timeout", self.output_read_timeout)?;
+        check_millis("output.write.timeout", self.output_write_timeout)?;
+        check_millis("output.close.timeout", self.close_timeout)?;
+        check_i32(
+            "output.packetpool.size",
+            self.packet_pool_size.map(u64::from),
+        )?;
+        check_millis("output.heeartbeat.interval", self.heartbeat_interval)?;
+
+        Ok(())
+    }
+
+    /// Every setting with the value it takes, sorted by key: the value set in this
+    /// configuration, or else the libhdfs3 default of [`SESSION_CONFIG_DEFAULTS`]. A value in
+    /// the site configuration would take the place of a default.
+    pub fn effective(&self) -> Vec<EffectiveSetting> {
+        let mut settings: Vec<EffectiveSetting> = self
+            .entries()
+            .into_iter()
+            .map(|(key, value)| EffectiveSetting {
+                key,
+                value,
+                is_set: true,
+            })
+            .collect();
+        for (key, value) in SESSION_CONFIG_DEFAULTS {
+            if !settings.iter().any(|setting| setting.key == *key) {
+                settings.push(EffectiveSetting {
+                    key,
+                    value: (*value).to_owned(),
+                    is_set: false,
+                });
+            }
+        }
+        settings.sort_by_key(|setting| setting.key);
+        settings
+    }
+
+    /// The settings which are set, as the key value pairs understood by libhdfs3.
+    pub fn to_hdfs_params(&self) -> HashMap<String, String> {
+        self.entries()
+            .into_iter()
+            .map(|(k, v)| (k.to_owned(), v))
+            .collect()
+    }
+
+    fn entries(&self) -> Vec<(&'static str, String)> {
+        let entries = [
+            (
+                "hadoop.security.authentication",
+                self.auth_method.map(|v| v.as_str().to_owned()),
+            ),
+            (
+                "dfs.client.log.severity",
+                self.log_severity.map(|v| v.as_str().to_owned()),
+            ),
+            (
+                "dfs.default.replica",
+                self.default_replica.map(|v| v.to_string()),
+            ),
+            (
+                "dfs.default.blocksize",
+                self.default_block_size.map(|v| v.to_string()),
+            ),
+            (
+                "dfs.prefetchsize",
+                self.prefetch_size.map(|v| v.to_string()),
+            ),
+            ("dfs.domain.socket.path", self.domain_socket_path.clone()),
+            (KEY_PROVIDER_URI, self.key_provider_uri.clone()),
+            (
+                "rpc.client.connect.tcpnodelay",
+                self.rpc_tcp_no_delay.map(|v| v.to_string()),
+            ),
+            ("rpc.client.max.idle", self.rpc_max_idle.map(millis)),
+            (
+                "rpc.client.ping.interval",
+                self.rpc_ping_interval.map(millis),
+            ),
+            (
+                "rpc.client.connect.timeout",
+                self.rpc_connect_timeout.map(millis),
+            ),
+            ("rpc.client.read.timeout", self.rpc_read_timeout.map(millis)),
+            (
+                "rpc.client.write.timeout",
+                self.rpc_write_timeout.map(millis),
+            ),
+            (
+                "rpc.client.socekt.linger.timeout",
+                self.rpc_socket_linger_timeout.map(millis),
+            ),
+            (
+                "rpc.client.connect.retry",
+                self.rpc_connect_retry.map(|v| v.to_string()),
+            ),
+            ("rpc.client.timeout", self.rpc_timeout.map(millis)),
+            (
+                "dfs.client.failover.max.attempts",
+                self.failover_max_attempts.map(|v| v.to_string()),
+            ),
+            (
+                "dfs.client.read.shortcircuit",
+                self.read_short_circuit.map(|v| v.to_string()),
+            ),
+            (
+                "dfs.client.use.legacy.blockreader.local",
+                self.legacy_local_block_reader.map(|v| v.to_string()),
+            ),
+            (
+                "dfs.client.read.shortcircuit.streams.cache.size",
+                self.short_circuit_streams_cache_size.map(|v| v.to_string()),
+            ),
+            (
+                "dfs.client.socketcache.capacity",
+                self.socket_cache_capacity.map(|v| v.to_string()),
+            ),
+            (
+                "dfs.client.socketcache.expiryMsec",
+                self.socket_cache_expiry.map(millis),
+            ),
+            (
+                "input.connect.timeout",
+                self.input_connect_timeout.map(millis),
+            ),
+            ("input.read.timeout", self.input_read_timeout.map(millis)),
+            ("input.write.timeout", self.input_write_timeout.map(millis)),
+            (
+                "input.localread.default.buffersize",
+                self.local_read_buffer_size.map(|v| v.to_string()),
+            ),
+            (
+                "input.localread.blockinfo.cachesize",
+                self.local_block_info_cache_size.map(|v| v.to_string()),
+            ),
+            (
+                "input.localread.mappedfile",
+                self.local_read_mapped_file.map(|v| v.to_string()),
+            ),
+            (
+                "input.read.getblockinfo.retry",
+                self.get_block_info_retry.map(|v| v.to_string()),
+            ),
+            (
+                "input.read.max.retry",
+                self.read_max_retry.map(|v| v.to_string()),
+            ),
+            (
+                "input.notretry-another-node",
+                self.not_retry_another_node.map(|v| v.to_string()),
+            ),
+            (
+                "output.replace-datanode-on-failure",
+                self.replace_datanode_on_failure.map(|v| v.to_string()),
+            ),
+            (
+                "output.default.chunksize",
+                self.chunk_size.map(|v| v.to_string()),
+            ),
+            (
+                "output.default.packetsize",
+                self.packet_size.map(|v| v.to_string()),
+            ),
+            (
+                "output.default.write.retry",
+                self.write_retry.map(|v| v.to_string()),
+            ),
+            (
+                "output.connect.timeout",
+                self.output_connect_timeout.map(millis),
+            ),
+            ("output.read.timeout", self.output_read_timeout.map(millis)),
+            (
+                "output.write.timeout",
+                self.output_write_timeout.map(millis),
+            ),
+            ("output.close.timeout", self.close_timeout.map(millis)),
+            (
+                "output.packetpool.size",
+                self.packet_pool_size.map(|v| v.to_string()),
+            ),
+            (
+                "output.heeartbeat.interval",
+                self.heartbeat_interval.map(millis),
+            ),
+        ];
+        entries
+            .into_iter()
+            .filter_map(|(key, value)| Some((key, value?)))
+            .collect()
+    }
+}
+
+/// Builder for [`ClientConfig`]. Unset settings are left to the site configuration.
+#[derive(Clone, Debug)]
+pub struct ClientConfigBuilder {
+    config: ClientConfig,
+}
+
+impl ClientConfigBuilder {
+    pub fn auth_method(mut self, auth_method: AuthMethod) -> Self {
+        self.config.auth_method = Some(auth_method);
+        self
+    }
+
+    pub fn log_severity(mut self, log_severity: LogSeverity) -> Self {
+        self.config.log_severity = Some(log_severity);
+        self
+    }
+
+    pub fn default_replica(mut self, value: u16) -> Self {
+        self.config.default_replica = Some(value);
+        self
+    }
+
+    pub fn default_block_size(mut self, size: ByteSize) -> Self {
+        self.config.default_block_size = Some(size);
+        self
+    }
+
+    pub fn prefetch_size(mut self, value: u32) -> Self {
+        self.config.prefetch_size = Some(value);
+        self
+    }
+
+    pub fn domain_socket_path(mut self, value: impl Into<String>) -> Self {
+        self.config.domain_socket_path = Some(value.into());
+        self
+    }
+
+    pub fn key_provider_uri(mut self, value: impl Into<String>) -> Self {
+        self.config.key_provider_uri = Some(value.into());
+        self
+    }
+
+    pub fn rpc_tcp_no_delay(mut self, enabled: bool) -> Self {
+        self.config.rpc_tcp_no_delay = Some(enabled);
+        self
+    }
+
+    pub fn rpc_max_idle(mut self, duration: Duration) -> Self {
+        self.config.rpc_max_idle = Some(duration);
+        self
+    }
+
+    pub fn rpc_ping_interval(mut self, duration: Duration) -> Self {
+        self.config.rpc_ping_interval = Some(duration);
+        self
+    }
+
+    pub fn rpc_connect_timeout(mut self, duration: Duration) -> Self {
+        self.config.rpc_connect_timeout = Some(duration);
+        self
+    }
+
+    pub fn rpc_read_timeout(mut self, duration: Duration) -> Self {
+        self.config.rpc_read_timeout = Some(duration);
+        self
+    }
+
+    pub fn rpc_write_timeout(mut self, duration: Duration) -> Self {
+        self.config.rpc_write_timeout = Some(duration);
+        self
+    }
+
+    pub fn rpc_socket_linger_timeout(mut self, duration: Duration) -> Self {
+        self.config.rpc_socket_linger_timeout = Some(duration);
+        self
+    }
+
+    pub fn rpc_connect_retry(mut self, value: u32) -> Self {
+        self.config.rpc_connect_retry = Some(value);
+        self
+    }
+
+    pub fn rpc_timeout(mut self, duration: Duration) -> Self {
+        self.config.rpc_timeout = Some(duration);
+        self
+    }
+
+    pub fn failover_max_attempts(mut self, value: u32) -> Self {
+        self.config.failover_max_attempts = Some(value);
+        self
+    }
+
+    pub fn read_short_circuit(mut self, enabled: bool) -> Self {
+        self.config.read_short_circuit = Some(enabled);
+        self
+    }
+
+    pub fn legacy_local_block_reader(mut self, enabled: bool) -> Self {
+        self.config.legacy_local_block_reader = Some(enabled);
+        self
+    }
+
+    pub fn short_circuit_streams_cache_size(mut self, value: u32) -> Self {
+        self.config.short_circuit_streams_cache_size = Some(value);
+        self
+    }
+
+    pub fn socket_cache_capacity(mut self, value: u32) -> Self {
+        self.config.socket_cache_capacity = Some(value);
+        self
+    }
+
+    pub fn socket_cache_expiry(mut self, duration: Duration) -> Self {
+        self.config.socket_cache_expiry = Some(duration);
+        self
+    }
+
+    pub fn input_connect_timeout(mut self, duration: Duration) -> Self {
+        self.config.input_connect_timeout = Some(duration);
+        self
+    }
+
+    pub fn input_read_timeout(mut self, duration: Duration) -> Self {
+        self.config.input_read_timeout = Some(duration);
+        self
+    }
+
+    pub fn input_write_timeout(mut self, duration: Duration) -> Self {
+        self.config.input_write_timeout = Some(duration);
+        self
+    }
+
+    pub fn local_read_buffer_size(mut self, size: ByteSize) -> Self {
+        self.config.local_read_buffer_size = Some(size);
+        self
+    }
+
+    pub fn local_block_info_cache_size(mut self, value: u32) -> Self {
+        self.config.local_block_info_cache_size = Some(value);
+        self
+    }
+
+    pub fn local_read_mapped_file(mut self, enabled: bool) -> Self {
+        self.config.local_read_mapped_file = Some(enabled);
+        self
+    }
+
+    pub fn get_block_info_retry(mut self, value: u32) -> Self {
+        self.config.get_block_info_retry = Some(value);
+        self
+    }
+
+    pub fn read_max_retry(mut self, value: u32) -> Self {
+        self.config.read_max_retry = Some(value);
+        self
+    }
+
+    pub fn not_retry_another_node(mut self, enabled: bool) -> Self {
+        self.config.not_retry_another_node = Some(enabled);
+        self
+    }
+
+    pub fn replace_datanode_on_failure(mut self, enabled: bool) -> Self {
+        self.config.replace_datanode_on_failure = Some(enabled);
+        self
+    }
+
+    pub fn chunk_size(mut self, size: ByteSize) -> Self {
+        self.config.chunk_size = Some(size);
+        self
+    }
+
+    pub fn packet_size(mut self, size: ByteSize) -> Self {
+        self.config.packet_size = Some(size);
+        self
+    }
+
+    pub fn write_retry(mut self, value: u32) -> Self {
+        self.config.write_retry = Some(value);
+        self
+    }
+
+    pub fn output_connect_timeout(mut self, duration: Duration) -> Self {
+        self.config.output_connect_timeout = Some(duration);
+        self
+    }
+
+    pub fn output_read_timeout(mut self, duration: Duration) -> Self {
+        self.config.output_read_timeout = Some(duration);
+        self
+    }
+
+    pub fn output_write_timeout(mut self, duration: Duration) -> Self {
+        self.config.output_write_timeout = Some(duration);
+        self
+    }
+
+    pub fn close_timeout(mut self, duration: Duration) -> Self {
+        self.config.close_timeout = Some(duration);
+        self
+    }
+
+    pub fn packet_pool_size(mut self, value: u32) -> Self {
+        self.config.packet_pool_size = Some(value);
+        self
+    }
+
+    pub fn heartbeat_interval(mut self, duration: Duration) -> Self {
+        self.config.heartbeat_interval = Some(duration);
+        self
+    }
+
+    /// Validate and return the configuration.
+    pub fn build(self) -> Result<ClientConfig, HdfsErr> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The directory of the Hadoop client configuration when `HADOOP_CONF_DIR` is not set, as
+/// used by the Hadoop client
+pub const DEFAULT_HADOOP_CONF_DIR: &str = "/etc/hadoop/conf";
+
+/// The directory of the Hadoop client configuration, such as `core-site.xml`:
+/// `HADOOP_CONF_DIR`, or else [`DEFAULT_HADOOP_CONF_DIR`] if it exists. `None`, which is
+/// logged as a warning, if neither is available.
+pub fn hadoop_conf_dir() -> Option<PathBuf> {
+    if let Some(conf_dir) = std::env::var_os("HADOOP_CONF_DIR") {
+        return Some(PathBuf::from(conf_dir));
+    }
+    let conf_dir = PathBuf::from(DEFAULT_HADOOP_CONF_DIR);
+    if conf_dir.is_dir() {
+        Some(conf_dir)
+    } else {
+        warn!(
+            "No Hadoop configuration found: HADOOP_CONF_DIR is not set and {} does not exist",
+            DEFAULT_HADOOP_CONF_DIR
+        );
+        None
+    }
+}
+
+/// Read the properties of a Hadoop configuration file such as `core-site.xml`.
+pub(crate) fn read_site_xml(path: &Path) -> Result<HashMap<String, String>, HdfsErr> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        HdfsErr::InvalidConfig(format!("{}: could not be read: {}", path.display(), e))
+    })?;
+    let document = roxmltree::Document::parse(&content).map_err(|e| {
+        HdfsErr::InvalidConfig(format!("{}: could not be parsed: {}", path.display(), e))
+    })?;
+
+    let text = |property: roxmltree::Node, tag: &str| {
+        property
+            .children()
+            .find(|child| child.has_tag_name(tag))
+            .and_then(|child| child.text())
+            .map(|text| text.trim().to_owned())
+    };
+    let properties = document
+        .root_element()
+        .children()
+        .filter(|node| node.has_tag_name("property"))
+        .filter_map(|property| Some((text(property, "name")?, text(property, "value")?)))
+        .collect();
+
+    Ok(properties)
+}
+
+/// The KMS URI configured in `hdfs-site.xml`, or else `core-site.xml`, of a Hadoop
+/// configuration directory: [`KEY_PROVIDER_URI`], or else [`KEY_PROVIDER_PATH`]. Files which
+/// do not exist are skipped.
+pub(crate) fn site_key_provider_uri(conf_dir: &Path) -> Result<Option<String>, HdfsErr> {
+    for file in ["hdfs-site.xml", "core-site.xml"] {
+        let site_xml = conf_dir.join(file);
+        if !site_xml.exists() {
+            continue;
+        }
+        let mut properties = read_site_xml(&site_xml)?;
+        if let Some(uri) = properties
+            .remove(KEY_PROVIDER_URI)
+            .or_else(|| properties.remove(KEY_PROVIDER_PATH))
+        {
+            return Ok(Some(uri));
+        }
+    }
+    Ok(None)
+}
+
+fn invalid(key: &str, reason: &str) -> HdfsErr {
+    HdfsErr::InvalidConfig(format!("{}: {}", key, reason))
+}
+
+fn millis(duration: Duration) -> String {
+    duration.as_millis().to_string()
+}
+
+mod option_duration_millis {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer};
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Option::<u64>::deserialize(deserializer).map(|ms| ms.map(Duration::from_millis))
+    }
+}
+
+fn bytes(size: Option<ByteSize>) -> Option<u64> {
+    size.map(|size| size.as_u64())
+}
+
+fn check_at_least(key: &str, value: Option<u64>, min: u64) -> Result<(), HdfsErr> {
+    match value {
+        Some(value) if value < min => Err(invalid(key, &format!("{} is less than {}", value, min))),
+        _ => Ok(()),
+    }
+}
+
+fn check_i32(key: &str, value: Option<u64>) -> Result<(), HdfsErr> {
+    match value {
+        Some(value) if value > i32::MAX as u64 => {
+            Err(invalid(key, &format!("{} does not fit in 32 bits", value)))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check_i64(key: &str, value: Option<u64>) -> Result<(), HdfsErr> {
+    match value {
+        Some(value) if value > i64::MAX as u64 => {
+            Err(invalid(key, &format!("{} does not fit in 64 bits", value)))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check_millis(key: &str, duration: Option<Duration>) -> Result<(), HdfsErr> {
+    match duration {
+        Some(duration) if duration.as_millis() > i32::MAX as u128 => Err(invalid(
+            key,
+            &format!("{:?} is longer than {} milliseconds", duration, i32::MAX),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn check_multiple_of_512(key: &str, size: Option<ByteSize>) -> Result<(), HdfsErr> {
+    match bytes(size) {
+        Some(size) if size == 0 || size % 512 != 0 => Err(invalid(
+            key,
+            &format!("{} is not a positive multiple of 512", size),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn property(name: &str, value: &str) -> String {
+        format!(
+            "<property><name>{}</name><value>{}</value></property>",
+            name, value
+        )
+    }
+
+    #[test]
+    fn test_site_key_provider_uri() {
+        let conf_dir = tempfile::tempdir().unwrap();
+        let write = |file: &str, properties: &[String]| {
+            let content = format!("<configuration>{}</configuration>", properties.concat());
+            std::fs::write(conf_dir.path().join(file), content).unwrap();
+        };
+        assert_eq!(site_key_provider_uri(conf_dir.path()).unwrap(), None);
+
+        write(
+            "core-site.xml",
+            &[property(KEY_PROVIDER_PATH, "kms://http@core:9600/kms")],
+        );
+        assert_eq!(
+            site_key_provider_uri(conf_dir.path()).unwrap().as_deref(),
+            Some("kms://http@core:9600/kms")
+        );
+
+        // hdfs-site.xml takes precedence, and dfs.encryption.key.provider.uri within a file
+        write("hdfs-site.xml", &[property("dfs.replication", "3")]);
+        assert_eq!(
+            site_key_provider_uri(conf_dir.path()).unwrap().as_deref(),
+            Some("kms://http@core:9600/kms")
+        );
+        write(
+            "hdfs-site.xml",
+            &[
+                property(KEY_PROVIDER_PATH, "kms://http@path:9600/kms"),
+                property(KEY_PROVIDER_URI, "kms://http@uri:9600/kms"),
+            ],
+        );
+        assert_eq!(
+            site_key_provider_uri(conf_dir.path()).unwrap().as_deref(),
+            Some("kms://http@uri:9600/kms")
+        );
+
+        write("hdfs-site.xml", &["<property>".to_owned()]);
+        assert!(matches!(
+            site_key_provider_uri(conf_dir.path()),
+            Err(HdfsErr::InvalidConfig(_))
+        ));
+    }
+}
@@ -0,0 +1,440 @@
+use std::env;
+use std::fmt::Formatter;
+use std::net::ToSocketAddrs;
+use std::path::Path;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+use crate::err::HdfsErr;
+use crate::hdfs3::ConnectionProperties;
+use crate::token::DelegationToken;
+
+/// Environment variable set by YARN to the token storage file of a container
+pub const HADOOP_TOKEN_FILE_LOCATION: &str = "HADOOP_TOKEN_FILE_LOCATION";
+
+/// Kind of the HDFS delegation tokens
+pub const HDFS_DELEGATION_TOKEN_KIND: &str = "HDFS_DELEGATION_TOKEN";
+
+const TOKEN_STORAGE_MAGIC: &[u8] = b"HDTS";
+const WRITABLE_FORMAT: u8 = 0;
+const PROTOBUF_FORMAT: u8 = 1;
+/// Version of `AbstractDelegationTokenIdentifier`
+const DELEGATION_TOKEN_IDENTIFIER_VERSION: u8 = 0;
+
+/// A token read from a token storage file.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Token {
+    pub identifier: Vec<u8>,
+    pub password: Vec<u8>,
+    pub kind: String,
+    pub service: String,
+}
+
+impl Token {
+    /// Convert to a delegation token which can be used to connect.
+    pub fn to_delegation_token(&self) -> DelegationToken {
+        let mut buf = Vec::new();
+        write_vlong(&mut buf, self.identifier.len() as i64);
+        buf.extend_from_slice(&self.identifier);
+        write_vlong(&mut buf, self.password.len() as i64);
+        buf.extend_from_slice(&self.password);
+        write_vlong(&mut buf, self.kind.len() as i64);
+        buf.extend_from_slice(self.kind.as_bytes());
+        write_vlong(&mut buf, self.service.len() as i64);
+        buf.extend_from_slice(self.service.as_bytes());
+        DelegationToken::from_encoded(URL_SAFE_NO_PAD.encode(buf))
+            .expect("URL-safe base64 is a valid token encoding")
+    }
+
+    /// Decode a delegation token from its URL-safe encoding.
+    pub fn from_delegation_token(token: &DelegationToken) -> Result<Token, HdfsErr> {
+        let decode = || {
+            let buf = URL_SAFE_NO_PAD.decode(token.as_str()).ok()?;
+            let mut reader = Reader::new(&buf);
+            let identifier = reader.read_vint_bytes().ok()?.to_vec();
+            let password = reader.read_vint_bytes().ok()?.to_vec();
+            let kind = reader.read_text().ok()?;
+            let service = reader.read_text().ok()?;
+            Some(Token {
+                identifier,
+                password,
+                kind,
+                service,
+            })
+        };
+        decode()
+            .ok_or_else(|| HdfsErr::Miscellaneous("Invalid delegation token encoding".to_owned()))
+    }
+
+    /// The user the token was issued to, which is who a connection authenticated with the
+    /// token acts as. It is read from the token identifier, which starts with a version and
+    /// the owner for every delegation token kind.
+    pub fn owner(&self) -> Result<String, HdfsErr> {
+        let mut reader = Reader::new(&self.identifier);
+        match reader.read_u8()? {
+            DELEGATION_TOKEN_IDENTIFIER_VERSION => reader.read_text(),
+            version => Err(HdfsErr::Miscellaneous(format!(
+                "Unsupported delegation token identifier version {}",
+                version
+            ))),
+        }
+    }
+}
+
+/// The password is a credential, so it is never written to logs.
+impl std::fmt::Debug for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Token")
+            .field("kind", &self.kind)
+            .field("service", &self.service)
+            .finish()
+    }
+}
+
+/// The tokens and secrets of a Hadoop `Credentials` token storage file, as handed to YARN
+/// containers through `HADOOP_TOKEN_FILE_LOCATION`.
+///
+/// Both the Writable based and the protobuf based storage formats are supported.
+#[derive(Clone, Default)]
+pub struct Credentials {
+    tokens: Vec<(String, Token)>,
+    secrets: Vec<(String, Vec<u8>)>,
+}
+
+impl Credentials {
+    /// Read the token storage file given by `HADOOP_TOKEN_FILE_LOCATION`, if it is set.
+    pub fn from_env() -> Result<Option<Credentials>, HdfsErr> {
+        match env::var_os(HADOOP_TOKEN_FILE_LOCATION) {
+            Some(path) => Credentials::read(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Read a token storage file.
+    pub fn read(path: impl AsRef<Path>) -> Result<Credentials, HdfsErr> {
+        let path = path.as_ref();
+        let content = std::fs::read(path).map_err(|e| {
+            HdfsErr::Miscellaneous(format!("Could not read {}: {}", path.display(), e))
+        })?;
+        Credentials::parse(&content).map_err(|e| match e {
+            HdfsErr::Miscellaneous(reason) => {
+                HdfsErr::Miscellaneous(format!("{}: {}", path.display(), reason))
+            }
+            e => e,
+        })
+    }
+
+    /// Parse the content of a token storage file.
+    pub fn parse(content: &[u8]) -> Result<Credentials, HdfsErr> {
+        let mut reader = Reader::new(content);
+        if reader.read_bytes(TOKEN_STORAGE_MAGIC.len())? != TOKEN_STORAGE_MAGIC {
+            return Err(invalid("not a token storage file"));
+        }
+        match reader.read_u8()? {
+            WRITABLE_FORMAT => Credentials::parse_writable(&mut reader),
+            PROTOBUF_FORMAT => {
+                let len = reader.read_varint()?;
+                Credentials::parse_protobuf(reader.read_bytes(len as usize)?)
+            }
+            version => Err(invalid(&format!("unknown version {}", version))),
+        }
+    }
+
+    /// All the tokens, with their aliases
+    pub fn tokens(&self) -> impl Iterator<Item = (&str, &Token)> {
+        self.tokens
+            .iter()
+            .map(|(alias, token)| (alias.as_str(), token))
+    }
+
+    /// Get the secret key with the given alias.
+    pub fn secret(&self, alias: &str) -> Option<&[u8]> {
+        self.secrets
+            .iter()
+            .find(|(secret_alias, _)| secret_alias == alias)
+            .map(|(_, secret)| secret.as_slice())
+    }
+
+    /// Find the HDFS delegation token for the namenode or nameservice of the given connection.
+    ///
+    /// Hadoop names the service of a token after the nameservice (`ha-hdfs:mycluster`), or
+    /// after the address of the namenode, either by host name or by IP address.
+    pub fn hdfs_token(&self, connection_properties: &ConnectionProperties) -> Option<&Token> {
+        let services = token_services(connection_properties);
+        self.tokens
+            .iter()
+            .map(|(_, token)| token)
+            .filter(|token| token.kind == HDFS_DELEGATION_TOKEN_KIND)
+            .find(|token| services.contains(&token.service))
+    }
+
+    fn parse_writable(reader: &mut Reader) -> Result<Credentials, HdfsErr> {
+        let mut credentials = Credentials::default();
+        for _ in 0..reader.read_vint()? {
+            let alias = reader.read_text()?;
+            let identifier = reader.read_vint_bytes()?.to_vec();
+            let password = reader.read_vint_bytes()?.to_vec();
+            let kind = reader.read_text()?;
+            let service = reader.read_text()?;
+            let token = Token {
+                identifier,
+                password,
+                kind,
+                service,
+            };
+            credentials.tokens.push((alias, token));
+        }
+        for _ in 0..reader.read_vint()? {
+            let alias = reader.read_text()?;
+            let secret = reader.read_vint_bytes()?.to_vec();
+            credentials.secrets.push((alias, secret));
+        }
+        Ok(credentials)
+    }
+
+    /// `CredentialsProto` from `Security.proto`.
+    fn parse_protobuf(message: &[u8]) -> Result<Credentials, HdfsErr> {
+        let mut credentials = Credentials::default();
+        let mut reader = Reader::new(message);
+        while let Some((field, value)) = reader.read_proto_field()? {
+            match field {
+                1 => {
+                    let kv = CredentialsKv::parse(value)?;
+                    let token = kv
+                        .token
+                        .ok_or_else(|| invalid("token entry without token"))?;
+                    credentials.tokens.push((kv.alias, token));
+                }
+                2 => {
+                    let kv = CredentialsKv::parse(value)?;
+                    credentials
+                        .secrets
+                        .push((kv.alias, kv.secret.unwrap_or_default()));
+                }
+                _ => {}
+            }
+        }
+        Ok(credentials)
+    }
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("tokens", &self.tokens)
+            .field(
+                "secrets",
+                &self
+                    .secrets
+                    .iter()
+                    .map(|(alias, _)| alias)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// `CredentialsKVProto` from `Security.proto`
+struct CredentialsKv {
+    alias: String,
+    token: Option<Token>,
+    secret: Option<Vec<u8>>,
+}
+
+impl CredentialsKv {
+    fn parse(message: &[u8]) -> Result<CredentialsKv, HdfsErr> {
+        let mut kv = CredentialsKv {
+            alias: String::new(),
+            token: None,
+            secret: None,
+        };
+        let mut reader = Reader::new(message);
+        while let Some((field, value)) = reader.read_proto_field()? {
+            match field {
+                1 => kv.alias = utf8(value)?,
+                2 => kv.token = Some(parse_token_proto(value)?),
+                3 => kv.secret = Some(value.to_vec()),
+                _ => {}
+            }
+        }
+        Ok(kv)
+    }
+}
+
+/// `TokenProto` from `Security.proto`.
+fn parse_token_proto(message: &[u8]) -> Result<Token, HdfsErr> {
+    let mut token = Token {
+        identifier: Vec::new(),
+        password: Vec::new(),
+        kind: String::new(),
+        service: String::new(),
+    };
+    let mut reader = Reader::new(message);
+    while let Some((field, value)) = reader.read_proto_field()? {
+        match field {
+            1 => token.identifier = value.to_vec(),
+            2 => token.password = value.to_vec(),
+            3 => token.kind = utf8(value)?,
+            4 => token.service = utf8(value)?,
+            _ => {}
+        }
+    }
+    Ok(token)
+}
+
+/// The token services which can identify the namenode or nameservice of a connection.
+fn token_services(connection_properties: &ConnectionProperties) -> Vec<String> {
+    let host = &connection_properties.namenode_host;
+    let port = connection_properties.namenode_port;
+    if connection_properties.is_nameservice() || port == 0 {
+        return vec![format!("ha-hdfs:{}", host)];
+    }
+
+    let mut services = vec![format!("{}:{}", host, port)];
+    if let Ok(addrs) = (host.as_str(), port).to_socket_addrs() {
+        services.extend(addrs.map(|addr| format!("{}:{}", addr.ip(), port)));
+    }
+    services
+}
+
+fn invalid(reason: &str) -> HdfsErr {
+    HdfsErr::Miscellaneous(format!("Invalid token storage: {}", reason))
+}
+
+fn utf8(bytes: &[u8]) -> Result<String, HdfsErr> {
+    String::from_utf8(bytes.to_vec()).map_err(|_| invalid("string is not UTF-8"))
+}
+
+/// Hadoop `WritableUtils.writeVLong`
+fn write_vlong(buf: &mut Vec<u8>, value: i64) {
+    if (-112..=127).contains(&value) {
+        buf.push(value as u8);
+        return;
+    }
+    let (value, mut len) = if value < 0 {
+        (!value, -120)
+    } else {
+        (value, -112)
+    };
+    let mut tmp = value;
+    while tmp != 0 {
+        tmp >>= 8;
+        len -= 1;
+    }
+    buf.push(len as u8);
+    let len = if len < -120 {
+        -(len + 120)
+    } else {
+        -(len + 112)
+    };
+    for idx in (0..len).rev() {
+        buf.push((value >> (idx * 8)) as u8);
+    }
+}
+
+/// Reader of Hadoop Writable and protobuf encodings
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Reader<'a> {
+        Reader { buf, pos: 0 }
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], HdfsErr> {
+        if len > self.buf.len() - self.pos {
+            return Err(invalid("unexpected end of data"));
+        }
+        let bytes = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, HdfsErr> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    /// Hadoop `WritableUtils.readVLong`
+    fn read_vlong(&mut self) -> Result<i64, HdfsErr> {
+        let first = self.read_u8()? as i8;
+        if first >= -112 {
+            return Ok(first as i64);
+        }
+        let negative = first < -120;
+        let len = if negative {
+            -(first as i32 + 120)
+        } else {
+            -(first as i32 + 112)
+        };
+        let mut value: i64 = 0;
+        for _ in 0..len {
+            value = (value << 8) | self.read_u8()? as i64;
+        }
+        Ok(if negative { !value } else { value })
+    }
+
+    /// Hadoop `WritableUtils.readVInt`, which must not be negative here
+    fn read_vint(&mut self) -> Result<usize, HdfsErr> {
+        let value = self.read_vlong()?;
+        if (0..=i32::MAX as i64).contains(&value) {
+            Ok(value as usize)
+        } else {
+            Err(invalid(&format!("invalid length {}", value)))
+        }
+    }
+
+    fn read_vint_bytes(&mut self) -> Result<&'a [u8], HdfsErr> {
+        let len = self.read_vint()?;
+        self.read_bytes(len)
+    }
+
+    /// Hadoop `Text`
+    fn read_text(&mut self) -> Result<String, HdfsErr> {
+        utf8(self.read_vint_bytes()?)
+    }
+
+    /// Protobuf base 128 varint
+    fn read_varint(&mut self) -> Result<u64, HdfsErr> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_u8()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(invalid("varint is too long"))
+    }
+
+    /// Read the next protobuf field, returning the field number and, for length delimited
+    /// fields, the value. Other wire types are skipped and have an empty value.
+    fn read_proto_field(&mut self) -> Result<Option<(u64, &'a [u8])>, HdfsErr> {
+        if self.pos == self.buf.len() {
+            return Ok(None);
+        }
+        let key = self.read_varint()?;
+        let value = match key & 0x7 {
+            0 => {
+                self.read_varint()?;
+                &[][..]
+            }
+            1 => {
+                self.read_bytes(8)?;
+                &[][..]
+            }
+            2 => {
+                let len = self.read_varint()?;
+                self.read_bytes(len as usize)?
+            }
+            5 => {
+                self.read_bytes(4)?;
+                &[][..]
+            }
+            wire_type => return Err(invalid(&format!("unknown wire type {}", wire_type))),
+        };
+        Ok(Some((key >> 3, value)))
+    }
+}
@@ -0,0 +1,303 @@
+use std::ffi::CStr;
+use std::fmt::{Debug, Display, Formatter};
+
+use aes::{Aes128, Aes192, Aes256};
+use ctr::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
+use ctr::Ctr128BE;
+use libc::{c_char, c_int};
+
+use crate::err::HdfsErr;
+use crate::path::HdfsPathBuf;
+use crate::{hdfsEncryptionFileInfo, hdfsEncryptionZoneInfo};
+
+/// The cipher the files of an encryption zone are encrypted with
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CipherSuite {
+    /// A suite this client does not know, which it cannot read or write
+    Unknown,
+    AesCtrNoPadding,
+}
+
+impl CipherSuite {
+    /// Size in bytes of the cipher block, which is also the size of the IV
+    pub fn block_size(&self) -> usize {
+        match self {
+            CipherSuite::Unknown => 0,
+            CipherSuite::AesCtrNoPadding => 16,
+        }
+    }
+
+    fn from_raw(suite: c_int) -> CipherSuite {
+        match suite {
+            2 => CipherSuite::AesCtrNoPadding,
+            _ => CipherSuite::Unknown,
+        }
+    }
+}
+
+impl Display for CipherSuite {
+    /// Format as Hadoop names the suite, e.g. `AES/CTR/NoPadding`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CipherSuite::Unknown => "Unknown",
+            CipherSuite::AesCtrNoPadding => "AES/CTR/NoPadding",
+        })
+    }
+}
+
+/// The version of the protocol between clients and the namenode for encrypted files
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CryptoProtocolVersion {
+    Unknown,
+    /// The version introduced with encryption zones in Hadoop 2.6
+    EncryptionZones,
+}
+
+impl CryptoProtocolVersion {
+    fn from_raw(version: c_int) -> CryptoProtocolVersion {
+        match version {
+            2 => CryptoProtocolVersion::EncryptionZones,
+            _ => CryptoProtocolVersion::Unknown,
+        }
+    }
+}
+
+impl Display for CryptoProtocolVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CryptoProtocolVersion::Unknown => "Unknown",
+            CryptoProtocolVersion::EncryptionZones => "Encryption zones",
+        })
+    }
+}
+
+/// A directory whose files are transparently encrypted, as listed by
+/// `hdfs crypto -listZones`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncryptionZone {
+    pub id: i64,
+    /// Path of the root of the zone
+    pub path: HdfsPathBuf,
+    pub suite: CipherSuite,
+    pub protocol_version: CryptoProtocolVersion,
+    /// Name of the key in the KMS which the keys of the files in the zone are encrypted with
+    pub key_name: String,
+}
+
+/// How a file in an encryption zone is encrypted. Its data is encrypted with a data
+/// encryption key (DEK), which is stored encrypted (the EDEK) by the zone key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileEncryptionInfo {
+    pub suite: CipherSuite,
+    pub protocol_version: CryptoProtocolVersion,
+    /// The encrypted data encryption key, which the KMS decrypts
+    pub edek: Vec<u8>,
+    /// The initialization vector of the file
+    pub iv: Vec<u8>,
+    /// Name of the zone key
+    pub key_name: String,
+    /// Version of the zone key the EDEK was encrypted with, e.g. `key1@0`
+    pub ez_key_version_name: String,
+}
+
+/// The AES/CTR keystream of an encrypted file, which encrypts and decrypts its data at any
+/// position, so that reads stay correct after seeking.
+///
+/// As in Hadoop, the counter of the block at byte `pos` is the IV of the file plus
+/// `pos / 16`, as a 128-bit big-endian integer.
+#[derive(Clone)]
+pub struct CtrCipher(CtrKey);
+
+#[derive(Clone)]
+enum CtrKey {
+    Aes128(Ctr128BE<Aes128>),
+    Aes192(Ctr128BE<Aes192>),
+    Aes256(Ctr128BE<Aes256>),
+}
+
+impl CtrCipher {
+    /// * suite - the suite of the file, which must be AES/CTR/NoPadding
+    /// * key - the decrypted data encryption key of the file, 16, 24 or 32 bytes long
+    /// * iv - the IV of the file, 16 bytes long
+    pub fn new(suite: CipherSuite, key: &[u8], iv: &[u8]) -> Result<CtrCipher, HdfsErr> {
+        if suite != CipherSuite::AesCtrNoPadding {
+            return Err(HdfsErr::Miscellaneous(format!(
+                "Unsupported cipher suite {}",
+                suite
+            )));
+        }
+        let invalid = |e| HdfsErr::Miscellaneous(format!("Invalid data encryption key: {}", e));
+        Ok(CtrCipher(match key.len() {
+            16 => CtrKey::Aes128(Ctr128BE::new_from_slices(key, iv).map_err(invalid)?),
+            24 => CtrKey::Aes192(Ctr128BE::new_from_slices(key, iv).map_err(invalid)?),
+            _ => CtrKey::Aes256(Ctr128BE::new_from_slices(key, iv).map_err(invalid)?),
+        }))
+    }
+
+    /// Encrypt or decrypt data in place, which starts at byte `pos` of the file
+    pub fn apply_keystream(&self, pos: u64, buf: &mut [u8]) {
+        fn apply<C: StreamCipher + StreamCipherSeek + Clone>(cipher: &C, pos: u64, buf: &mut [u8]) {
+            let mut cipher = cipher.clone();
+            cipher.seek(pos);
+            cipher.apply_keystream(buf);
+        }
+        match &self.0 {
+            CtrKey::Aes128(cipher) => apply(cipher, pos, buf),
+            CtrKey::Aes192(cipher) => apply(cipher, pos, buf),
+            CtrKey::Aes256(cipher) => apply(cipher, pos, buf),
+        }
+    }
+}
+
+impl Debug for CtrCipher {
+    /// Format without the key
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CtrCipher")
+    }
+}
+
+/// Convert an encryption zone returned by libhdfs3.
+///
+/// # Safety
+///
+/// The strings of `info` must be NUL terminated or null.
+pub(crate) unsafe fn encryption_zone(info: &hdfsEncryptionZoneInfo) -> EncryptionZone {
+    EncryptionZone {
+        id: info.mId,
+        path: HdfsPathBuf::from(c_bytes(info.mPath)),
+        suite: CipherSuite::from_raw(info.mSuite),
+        protocol_version: CryptoProtocolVersion::from_raw(info.mCryptoProtocolVersion),
+        key_name: String::from_utf8_lossy(c_bytes(info.mKeyName)).into_owned(),
+    }
+}
+
+/// Convert the encryption info of a file returned by libhdfs3. The EDEK and the IV are
+/// binary, and are read with the lengths given by `mKeyLength` and `mIvLength`.
+///
+/// # Safety
+///
+/// The strings of `info` must be NUL terminated or null, and the EDEK and the IV must
+/// hold at least as many bytes as their lengths.
+pub(crate) unsafe fn file_encryption_info(info: &hdfsEncryptionFileInfo) -> FileEncryptionInfo {
+    FileEncryptionInfo {
+        suite: CipherSuite::from_raw(info.mSuite),
+        protocol_version: CryptoProtocolVersion::from_raw(info.mCryptoProtocolVersion),
+        edek: raw_bytes(info.mKey, info.mKeyLength).to_vec(),
+        iv: raw_bytes(info.mIv, info.mIvLength).to_vec(),
+        key_name: String::from_utf8_lossy(c_bytes(info.mKeyName)).into_owned(),
+        ez_key_version_name: String::from_utf8_lossy(c_bytes(info.mEzKeyVersionName)).into_owned(),
+    }
+}
+
+fn c_bytes<'a>(ptr: *const c_char) -> &'a [u8] {
+    if ptr.is_null() {
+        &[]
+    } else {
+        unsafe { CStr::from_ptr(ptr) }.to_bytes()
+    }
+}
+
+/// Binary data of the given length, which may contain NUL bytes
+fn raw_bytes<'a>(ptr: *const c_char, len: c_int) -> &'a [u8] {
+    if ptr.is_null() || len <= 0 {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(ptr as *const u8, len as usize) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use super::*;
+
+    #[test]
+    fn test_encryption_zone() {
+        let path = CString::new(&b"/zones/\xffz"[..]).unwrap();
+        let key_name = CString::new("key1").unwrap();
+        let info = hdfsEncryptionZoneInfo {
+            mSuite: 2,
+            mCryptoProtocolVersion: 2,
+            mId: 16386,
+            mPath: path.as_ptr() as *mut c_char,
+            mKeyName: key_name.as_ptr() as *mut c_char,
+        };
+        let zone = unsafe { encryption_zone(&info) };
+        assert_eq!(zone.id, 16386);
+        // paths need not be UTF-8
+        assert_eq!(zone.path.as_bytes(), b"/zones/\xffz");
+        assert_eq!(zone.suite, CipherSuite::AesCtrNoPadding);
+        assert_eq!(
+            zone.protocol_version,
+            CryptoProtocolVersion::EncryptionZones
+        );
+        assert_eq!(zone.key_name, "key1");
+
+        let info = hdfsEncryptionZoneInfo {
+            mSuite: 1,
+            mCryptoProtocolVersion: 1,
+            mPath: std::ptr::null_mut(),
+            mKeyName: std::ptr::null_mut(),
+            ..info
+        };
+        let zone = unsafe { encryption_zone(&info) };
+        assert_eq!(zone.suite, CipherSuite::Unknown);
+        assert_eq!(zone.protocol_version, CryptoProtocolVersion::Unknown);
+        assert_eq!(zone.path.as_bytes(), b"");
+        assert_eq!(zone.key_name, "");
+    }
+
+    #[test]
+    fn test_file_encryption_info() {
+        // the EDEK and the IV are binary, so may contain NUL bytes and are not NUL terminated
+        let edek = [0x5a, 0x00, 0x17, 0x00, 0xff];
+        let iv: Vec<u8> = (0..16).collect();
+        let key_name = CString::new("key1").unwrap();
+        let version_name = CString::new("key1@0").unwrap();
+        let info = hdfsEncryptionFileInfo {
+            mSuite: 2,
+            mCryptoProtocolVersion: 2,
+            mKey: edek.as_ptr() as *mut c_char,
+            mKeyName: key_name.as_ptr() as *mut c_char,
+            mIv: iv.as_ptr() as *mut c_char,
+            mEzKeyVersionName: version_name.as_ptr() as *mut c_char,
+            mKeyLength: edek.len() as c_int,
+            mIvLength: iv.len() as c_int,
+        };
+        let file_info = unsafe { file_encryption_info(&info) };
+        assert_eq!(
+            file_info,
+            FileEncryptionInfo {
+                suite: CipherSuite::AesCtrNoPadding,
+                protocol_version: CryptoProtocolVersion::EncryptionZones,
+                edek: edek.to_vec(),
+                iv: iv.clone(),
+                key_name: "key1".to_owned(),
+                ez_key_version_name: "key1@0".to_owned(),
+            }
+        );
+
+        // only the given lengths are read
+        let info = hdfsEncryptionFileInfo {
+            mKeyLength: 2,
+            mIvLength: 0,
+            ..info
+        };
+        let file_info = unsafe { file_encryption_info(&info) };
+        assert_eq!(file_info.edek, vec![0x5a, 0x00]);
+        assert!(file_info.iv.is_empty());
+
+        // negative lengths and null pointers give empty values
+        let info = hdfsEncryptionFileInfo {
+            mKey: std::ptr::null_mut(),
+            mIvLength: -1,
+            mEzKeyVersionName: std::ptr::null_mut(),
+            ..info
+        };
+        let file_info = unsafe { file_encryption_info(&info) };
+        assert!(file_info.edek.is_empty());
+        assert!(file_info.iv.is_empty());
+        assert_eq!(file_info.ez_key_version_name, "");
+    }
+}
@@ -1,7 +1,11 @@
 use std::fmt::Display;
 
-/// Errors that can occur when accessing HDFS
+use crate::permission::FsAction;
+
+/// Errors that can occur when accessing HDFS. Variants may be added, so a match on them
+/// needs a wildcard arm.
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum HdfsErr {
     /// File path
     FileNotFound(String),
@@ -11,6 +15,30 @@ pub enum HdfsErr {
     CannotConnectToNameNode(String),
     /// URL
     InvalidUrl(String),
+    /// Configuration key and reason
+    InvalidConfig(String),
+    /// Path component and the action it denies
+    AccessDenied(String, FsAction),
+    /// Path containing a symlink, which the client does not follow
+    UnresolvedLink(String),
+    /// Path which only its owner or the superuser may change, e.g. its ACL
+    NotOwner(String),
+    /// Path which should be a directory but is a file
+    NotADirectory(String),
+    /// Directory whose namespace or disk space quota would be exceeded
+    QuotaExceeded(String),
+    /// File which cannot be concatenated and the reason
+    CannotConcat(String, String),
+    /// Path or name which is not UTF-8, with invalid bytes replaced
+    InvalidUtf8(String),
+    /// ACL entry or spec which cannot be parsed and the reason
+    InvalidAclSpec(String, String),
+    /// Extended attribute name which is not a namespace prefix, a dot and a name
+    InvalidXAttrName(String),
+    /// Path and the name of an extended attribute it does not have
+    XAttrNotFound(String, String),
+    /// Path and the name of an extended attribute it already has
+    XAttrAlreadyExists(String, String),
     /// Description
     Miscellaneous(String),
 }
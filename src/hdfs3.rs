@@ -1,28 +1,395 @@
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::env;
 use std::ffi::CStr;
 use std::fmt::Formatter;
+use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use lazy_static::lazy_static;
-use libc::{c_int, c_short, c_void};
+use libc::{c_char, c_int, c_short, c_void};
 use log::*;
 use std::sync::RwLock;
 use std::{ffi::CString, marker::PhantomData};
 
+use serde::Deserialize;
+use url::Url;
+
+use crate::acl::{acl_status, AclEntry, AclStatus, RawAclSpec};
+use crate::ccache::{ticket_cache_path, CredentialCache, TicketCacheWatch};
+use crate::config::{
+    hadoop_conf_dir, read_site_xml, site_key_provider_uri, ClientConfig, DEFAULT_HADOOP_CONF_DIR,
+};
+use crate::credentials::{Credentials, Token, HADOOP_TOKEN_FILE_LOCATION};
+use crate::encryption::{
+    encryption_zone, file_encryption_info, CtrCipher, EncryptionZone, FileEncryptionInfo,
+};
 use crate::err::HdfsErr;
+use crate::kerberos::AuthToLocal;
+use crate::kms::{KmsClient, KEY_PROVIDER_PATH, KEY_PROVIDER_URI};
+use crate::path::{HdfsPath, HdfsPathBuf, HDFS_SCHEME};
+use crate::permission::{
+    local_groups, local_user, parse_access_denied, parse_not_owner, permitted_action, FsAction,
+};
+use crate::profile::Profiles;
+use crate::snapshot::{
+    snapshot_diff_report, snapshottable_directory, SnapshotDiffReport, SnapshottableDirectory,
+};
+use crate::stats::{fs_stats, server_defaults, FsStats, ServerDefaults};
+use crate::summary::{content_summary, quota_value, ContentSummary, QUOTA_RESET};
+use crate::token::DelegationToken;
+use crate::xattr::{
+    full_name, xattr, xattr_error, xattr_not_found_error, RawXAttrs, XAttrSetFlags,
+};
 use crate::*;
 
 const O_RDONLY: c_int = 0;
 const O_WRONLY: c_int = 1;
 const O_APPEND: c_int = 1024;
 
+/// Namenode RPC port used when `fs.defaultFS` does not give one
+const DEFAULT_NAMENODE_PORT: u16 = 8020;
+/// Configuration key of the group whose members are superusers
+const SUPERGROUP: &str = "dfs.permissions.superusergroup";
+/// Default of `dfs.permissions.superusergroup`
+const DEFAULT_SUPERGROUP: &str = "supergroup";
+
 /// Encapsulate Namenode connection properties
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// Create them with [`ConnectionProperties::new`] or one of the other constructors, and set
+/// the optional properties with the builder methods, e.g.
+/// `ConnectionProperties::new("localhost", 8020).namenode_user("etl")`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+#[non_exhaustive]
 pub struct ConnectionProperties {
+    /// Namenode host, or the name of an HA nameservice when `namenode_port` is 0
     pub namenode_host: String,
     pub namenode_port: u16,
     pub namenode_user: Option<String>,
+    /// User to impersonate, like Hadoop's `UserGroupInformation.createProxyUser`. With
+    /// Kerberos, the connection authenticates as the principal, which the namenode only lets
+    /// impersonate the users allowed by its `hadoop.proxyuser` settings. With simple
+    /// authentication no real user is sent, so requests simply run as this user and the
+    /// `hadoop.proxyuser` settings are not checked.
+    pub proxy_user: Option<String>,
     pub kerberos_ticket_cache_path: Option<String>,
+    /// RPC addresses (`host:port`) of the namenodes of an HA nameservice
+    pub namenodes: Vec<String>,
+    /// Delegation token to authenticate with instead of a Kerberos ticket. If it is not set,
+    /// the token is looked up in the file given by `HADOOP_TOKEN_FILE_LOCATION`.
+    #[serde(skip)]
+    pub delegation_token: Option<DelegationToken>,
+    /// Decrypt and encrypt the data of files in encryption zones with keys from the KMS.
+    /// `None` enables it only if a KMS is configured, or set with
+    /// [`HdfsFs::set_kms_client`]. Files are looked up when they are opened or created.
+    pub transparent_encryption: Option<bool>,
+}
+
+impl ConnectionProperties {
+    /// Create connection properties for a single namenode.
+    pub fn new(namenode_host: &str, namenode_port: u16) -> ConnectionProperties {
+        ConnectionProperties {
+            namenode_host: namenode_host.to_owned(),
+            namenode_port,
+            ..Default::default()
+        }
+    }
+
+    /// Create connection properties from the environment, the way Hadoop clients do:
+    ///
+    /// * `fs.defaultFS` is read from `core-site.xml` in `HADOOP_CONF_DIR`, or
+    ///   `/etc/hadoop/conf` if it is not set, see [`hadoop_conf_dir`]. If it names an HA
+    ///   nameservice, its namenodes are read from `hdfs-site.xml` in the same directory.
+    /// * `HADOOP_USER_NAME` sets the user, falling back to the user of `fs.defaultFS`.
+    /// * `KRB5CCNAME` sets the Kerberos ticket cache.
+    pub fn from_env() -> Result<ConnectionProperties, HdfsErr> {
+        let conf_dir = hadoop_conf_dir().ok_or_else(|| {
+            HdfsErr::InvalidConfig(format!(
+                "fs.defaultFS: HADOOP_CONF_DIR is not set and {} does not exist",
+                DEFAULT_HADOOP_CONF_DIR
+            ))
+        })?;
+        let core_site = read_site_xml(&conf_dir.join("core-site.xml"))?;
+        let default_fs = core_site
+            .get("fs.defaultFS")
+            .or_else(|| core_site.get("fs.default.name"))
+            .ok_or_else(|| HdfsErr::InvalidConfig("fs.defaultFS: not configured".to_owned()))?;
+
+        let url = Url::parse(default_fs).map_err(|_| HdfsErr::InvalidUrl(default_fs.to_owned()))?;
+        let host = match url.host_str() {
+            Some(host) if url.scheme() == "hdfs" => host,
+            _ => return Err(HdfsErr::InvalidUrl(default_fs.to_owned())),
+        };
+
+        let mut connection_properties = match url.port() {
+            Some(port) => ConnectionProperties {
+                namenode_host: host.to_owned(),
+                namenode_port: port,
+                ..Default::default()
+            },
+            None => {
+                let hdfs_site_path = conf_dir.join("hdfs-site.xml");
+                let hdfs_site = if hdfs_site_path.exists() {
+                    read_site_xml(&hdfs_site_path)?
+                } else {
+                    HashMap::new()
+                };
+                match hdfs_site.get(&format!("dfs.ha.namenodes.{}", host)) {
+                    Some(ids) => {
+                        let namenodes = ids
+                            .split(',')
+                            .map(|id| {
+                                let key =
+                                    format!("dfs.namenode.rpc-address.{}.{}", host, id.trim());
+                                hdfs_site.get(&key).map(String::as_str).ok_or_else(|| {
+                                    HdfsErr::InvalidConfig(format!("{}: not configured", key))
+                                })
+                            })
+                            .collect::<Result<Vec<&str>, HdfsErr>>()?;
+                        ConnectionProperties::with_namenodes(host, &namenodes)
+                    }
+                    None => ConnectionProperties {
+                        namenode_host: host.to_owned(),
+                        namenode_port: DEFAULT_NAMENODE_PORT,
+                        ..Default::default()
+                    },
+                }
+            }
+        };
+
+        connection_properties.namenode_user = env::var("HADOOP_USER_NAME")
+            .ok()
+            .or_else(|| Some(url.username().to_owned()).filter(|user| !user.is_empty()));
+        connection_properties.kerberos_ticket_cache_path = env::var("KRB5CCNAME").ok();
+
+        Ok(connection_properties)
+    }
+
+    /// Create connection properties for an HA nameservice, e.g. `mycluster`.
+    ///
+    /// The namenodes of the nameservice are resolved from the client configuration
+    /// file given by the `LIBHDFS3_CONF` environment variable, or `hdfs-client.xml`
+    /// in the working directory. The client fails over between them as needed.
+    pub fn nameservice(nameservice: &str) -> Result<ConnectionProperties, HdfsErr> {
+        let namenodes = ha_namenodes(nameservice)?;
+        Ok(ConnectionProperties {
+            namenode_host: nameservice.to_owned(),
+            namenode_port: 0,
+            namenodes: namenodes.into_iter().map(|nn| nn.rpc_addr).collect(),
+            ..Default::default()
+        })
+    }
+
+    /// Create connection properties for an HA nameservice from the RPC addresses of its
+    /// namenodes, e.g. `["nn1.example.com:8020", "nn2.example.com:8020"]`.
+    ///
+    /// No client configuration file is needed: the HA settings of the nameservice are
+    /// generated when connecting.
+    pub fn with_namenodes(nameservice: &str, namenodes: &[&str]) -> ConnectionProperties {
+        ConnectionProperties {
+            namenode_host: nameservice.to_owned(),
+            namenode_port: 0,
+            namenodes: namenodes.iter().map(|nn| nn.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Connect as the given user rather than the local one.
+    pub fn namenode_user(self, user: &str) -> ConnectionProperties {
+        ConnectionProperties {
+            namenode_user: Some(user.to_owned()),
+            ..self
+        }
+    }
+
+    /// Authenticate with the Kerberos tickets of the given credential cache.
+    pub fn kerberos_ticket_cache_path(self, path: &str) -> ConnectionProperties {
+        ConnectionProperties {
+            kerberos_ticket_cache_path: Some(path.to_owned()),
+            ..self
+        }
+    }
+
+    /// Authenticate with a delegation token instead of a Kerberos ticket.
+    pub fn delegation_token(self, token: DelegationToken) -> ConnectionProperties {
+        ConnectionProperties {
+            delegation_token: Some(token),
+            ..self
+        }
+    }
+
+    /// Act on behalf of the given user, see the `proxy_user` field. Connections
+    /// are cached per effective user, so permission checks on the namenode apply to the
+    /// impersonated user.
+    pub fn proxy_user(self, user: &str) -> ConnectionProperties {
+        ConnectionProperties {
+            proxy_user: Some(user.to_owned()),
+            ..self
+        }
+    }
+
+    /// Enable or disable the transparent encryption of files in encryption zones, see the
+    /// `transparent_encryption` field.
+    pub fn transparent_encryption(self, enabled: bool) -> ConnectionProperties {
+        ConnectionProperties {
+            transparent_encryption: Some(enabled),
+            ..self
+        }
+    }
+
+    /// Does this connect to an HA nameservice rather than a single namenode?
+    #[inline]
+    pub fn is_nameservice(&self) -> bool {
+        !self.namenodes.is_empty()
+    }
+
+    /// Check that the proxy user and HA nameservice settings are usable by the native client.
+    pub fn validate(&self) -> Result<(), HdfsErr> {
+        if let Some(proxy_user) = &self.proxy_user {
+            if proxy_user.is_empty() {
+                return Err(HdfsErr::InvalidConfig(
+                    "proxy_user: must not be empty".to_owned(),
+                ));
+            }
+            if self.delegation_token.is_some() {
+                return Err(HdfsErr::InvalidConfig(
+                    "proxy_user: must not be set with a delegation token".to_owned(),
+                ));
+            }
+        }
+        if !self.is_nameservice() {
+            return Ok(());
+        }
+        if self.namenode_host.is_empty() || self.namenode_host.contains([':', '/']) {
+            return Err(HdfsErr::InvalidConfig(format!(
+                "dfs.nameservices: invalid nameservice '{}'",
+                self.namenode_host
+            )));
+        }
+        if self.namenode_port != 0 {
+            return Err(HdfsErr::InvalidConfig(format!(
+                "dfs.nameservices: nameservice {} must not have a port",
+                self.namenode_host
+            )));
+        }
+        for namenode in &self.namenodes {
+            let valid = match namenode.rsplit_once(':') {
+                Some((host, port)) => !host.is_empty() && port.parse::<u16>().is_ok(),
+                None => false,
+            };
+            if !valid {
+                return Err(HdfsErr::InvalidConfig(format!(
+                    "dfs.namenode.rpc-address.{}: '{}' is not host:port",
+                    self.namenode_host, namenode
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The HA settings of the nameservice, which the native client reads instead of
+    /// a client configuration file. Empty unless this connects to a nameservice.
+    pub fn to_hdfs_params(&self) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        if !self.is_nameservice() {
+            return params;
+        }
+
+        let nameservice = &self.namenode_host;
+        let ids = (1..=self.namenodes.len())
+            .map(|i| format!("nn{}", i))
+            .collect::<Vec<String>>();
+        params.insert("dfs.nameservices".to_owned(), nameservice.clone());
+        params.insert(format!("dfs.ha.namenodes.{}", nameservice), ids.join(","));
+        for (id, namenode) in ids.iter().zip(&self.namenodes) {
+            params.insert(
+                format!("dfs.namenode.rpc-address.{}.{}", nameservice, id),
+                namenode.clone(),
+            );
+        }
+        params
+    }
+}
+
+/// Addresses of a namenode in an HA nameservice
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NamenodeAddress {
+    /// RPC address, as `host:port`
+    pub rpc_addr: String,
+    /// HTTP address, as `host:port`, if configured
+    pub http_addr: Option<String>,
+}
+
+/// Get the namenodes of an HA nameservice from the client configuration file given by the
+/// `LIBHDFS3_CONF` environment variable, or `hdfs-client.xml` in the working directory.
+///
+/// * nameservice - the HA nameservice id, e.g. `mycluster`
+pub fn ha_namenodes(nameservice: &str) -> Result<Vec<NamenodeAddress>, HdfsErr> {
+    let mut size: c_int = 0;
+    let ptr = unsafe {
+        let cstr_nameservice = CString::new(nameservice).unwrap();
+        hdfsGetHANamenodes(cstr_nameservice.as_ptr(), &mut size)
+    };
+    namenode_addresses(nameservice, ptr, size)
+}
+
+/// Get the namenodes of an HA nameservice from the given client configuration file.
+///
+/// * config_path - path of the client configuration file
+/// * nameservice - the HA nameservice id, e.g. `mycluster`
+pub fn ha_namenodes_with_config(
+    config_path: &str,
+    nameservice: &str,
+) -> Result<Vec<NamenodeAddress>, HdfsErr> {
+    let mut size: c_int = 0;
+    let ptr = unsafe {
+        let cstr_config_path = CString::new(config_path).unwrap();
+        let cstr_nameservice = CString::new(nameservice).unwrap();
+        hdfsGetHANamenodesWithConfig(
+            cstr_config_path.as_ptr(),
+            cstr_nameservice.as_ptr(),
+            &mut size,
+        )
+    };
+    namenode_addresses(nameservice, ptr, size)
+}
+
+/// Copy the namenode array returned by libhdfs3 into owned addresses and free it.
+fn namenode_addresses(
+    nameservice: &str,
+    ptr: *mut Namenode,
+    size: c_int,
+) -> Result<Vec<NamenodeAddress>, HdfsErr> {
+    if ptr.is_null() {
+        return Err(HdfsErr::InvalidConfig(format!(
+            "dfs.ha.namenodes.{}: no HA namenodes configured",
+            nameservice
+        )));
+    }
+
+    let to_string = |s: *const c_char| unsafe { CStr::from_ptr(s) }.to_string_lossy().into_owned();
+    let namenodes = unsafe { std::slice::from_raw_parts(ptr, size as usize) }
+        .iter()
+        .map(|nn| {
+            if nn.rpc_addr.is_null() {
+                Err(HdfsErr::InvalidConfig(format!(
+                    "dfs.namenode.rpc-address.{}: missing for a namenode",
+                    nameservice
+                )))
+            } else {
+                Ok(NamenodeAddress {
+                    rpc_addr: to_string(nn.rpc_addr),
+                    http_addr: (!nn.http_addr.is_null()).then(|| to_string(nn.http_addr)),
+                })
+            }
+        })
+        .collect();
+    unsafe { hdfsFreeNamenodeInformation(ptr, size) };
+
+    namenodes
 }
 
 /// since HDFS client handles are completely thread safe, here we implement Send + Sync trait
@@ -41,10 +408,46 @@ lazy_static! {
 #[derive(Clone)]
 pub struct HdfsFs {
     connection_properties: ConnectionProperties,
-    raw: hdfsFS,
+    connection: Arc<RwLock<Connection>>,
+    /// The KMS which decrypts the keys of files in encryption zones, created on first use
+    kms: Arc<RwLock<Option<KmsClient>>>,
     _marker: PhantomData<()>,
 }
 
+/// The native handle of an HdfsFs, which is rebuilt when the Kerberos ticket cache is
+/// refreshed by kinit or k5start.
+struct Connection {
+    raw: Arc<RawFs>,
+    params: HashMap<String, String>,
+    /// The delegation token of the connection properties or of `HADOOP_TOKEN_FILE_LOCATION`
+    delegation_token: Option<DelegationToken>,
+    ticket_cache: Option<TicketCacheWatch>,
+    /// The working directory, the home directory until it is set. It is kept here rather
+    /// than in the native handle so that it survives reconnecting.
+    working_dir: Option<HdfsPathBuf>,
+}
+
+/// A native filesystem handle, disconnected once neither its connection nor any file opened
+/// with it uses it any more
+struct RawFs(hdfsFS);
+
+unsafe impl Send for RawFs {}
+unsafe impl Sync for RawFs {}
+
+impl RawFs {
+    fn as_ptr(&self) -> hdfsFS {
+        self.0
+    }
+}
+
+impl Drop for RawFs {
+    fn drop(&mut self) {
+        if unsafe { hdfsDisconnect(self.0) } != 0 {
+            warn!("Could not disconnect from HDFS");
+        }
+    }
+}
+
 impl std::fmt::Debug for HdfsFs {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("HdfsFs")
@@ -72,6 +475,47 @@ impl HdfsFs {
     pub fn new_with_hdfs_params(
         connection_properties: ConnectionProperties,
         hdfs_params: HashMap<String, String>,
+    ) -> Result<HdfsFs, HdfsErr> {
+        HdfsFs::new_internal(connection_properties, None, hdfs_params)
+    }
+
+    /// Create an instance of HdfsFs configured with a typed client configuration. A global
+    /// cache is used to ensure that only one instance is created per namenode uri.
+    ///
+    /// * connection_properties - Namenode connection parameters
+    /// * client_config - HDFS client side configuration
+    pub fn new_with_client_config(
+        connection_properties: ConnectionProperties,
+        client_config: ClientConfig,
+    ) -> Result<HdfsFs, HdfsErr> {
+        HdfsFs::new_internal(connection_properties, Some(client_config), HashMap::new())
+    }
+
+    /// Create an instance of HdfsFs authenticated with a delegation token, e.g. on a worker
+    /// which has no Kerberos credentials. `hadoop.security.authentication` must be set to
+    /// `kerberos`, and `namenode_user` must not be set since the user is taken from the token.
+    ///
+    /// * connection_properties - Namenode connection parameters
+    /// * token - delegation token obtained with [`HdfsFs::get_delegation_token`]
+    pub fn new_with_token(
+        connection_properties: ConnectionProperties,
+        token: DelegationToken,
+    ) -> Result<HdfsFs, HdfsErr> {
+        HdfsFs::new(connection_properties.delegation_token(token))
+    }
+
+    /// Create an instance of HdfsFs for a named cluster profile, such as `prod`, from the
+    /// default profile file. See [`Profiles::default_path`].
+    pub fn profile(name: &str) -> Result<HdfsFs, HdfsErr> {
+        let profiles = Profiles::load_default()?;
+        let profile = profiles.get(name)?;
+        HdfsFs::new_with_client_config(profile.connection.clone(), profile.config.clone())
+    }
+
+    fn new_internal(
+        connection_properties: ConnectionProperties,
+        client_config: Option<ClientConfig>,
+        hdfs_params: HashMap<String, String>,
     ) -> Result<HdfsFs, HdfsErr> {
         // Try to get from cache if an entry exists.
         {
@@ -86,220 +530,1849 @@ impl HdfsFs {
         let mut cache = HDFS_CACHE
             .write()
             .expect("Could not aquire write lock on HDFS cache");
-        let hdfsFs = cache
-            .entry(connection_properties.clone())
-            .or_insert_with(|| {
-                let hdfs_fs = create_hdfs_fs(connection_properties.clone(), hdfs_params)
-                    .expect("Could not create HDFS connection");
-                HdfsFs {
-                    connection_properties,
-                    raw: hdfs_fs,
-                    _marker: PhantomData,
-                }
-            });
+        if let Some(hdfs_fs) = cache.get(&connection_properties) {
+            return Ok(hdfs_fs.clone());
+        }
+
+        let params =
+            merge_hdfs_params(&connection_properties, client_config.as_ref(), hdfs_params)?;
+        let delegation_token = connection_properties
+            .delegation_token
+            .clone()
+            .or_else(|| token_from_env(&connection_properties));
+        let raw = create_hdfs_fs(&connection_properties, &params, delegation_token.as_ref())?;
+        let ticket_cache =
+            kerberos_ticket_cache(&connection_properties, &params, delegation_token.is_some())
+                .map(TicketCacheWatch::new);
+        let hdfs_fs = HdfsFs {
+            connection_properties: connection_properties.clone(),
+            connection: Arc::new(RwLock::new(Connection {
+                raw: Arc::new(raw),
+                params,
+                delegation_token,
+                ticket_cache,
+                working_dir: None,
+            })),
+            kms: Arc::new(RwLock::new(None)),
+            _marker: PhantomData,
+        };
+        cache.insert(connection_properties, hdfs_fs.clone());
 
-        Ok(hdfsFs.clone())
+        Ok(hdfs_fs)
     }
 
-    /// Open a file for append
-    pub fn append(&self, path: &str) -> Result<HdfsFile, HdfsErr> {
-        if !self.exist(path) {
-            return Err(HdfsErr::FileNotFound(path.to_owned()));
+    /// The native handle, after reconnecting if the Kerberos ticket cache has been refreshed
+    /// since the last check. Callers hold on to it for as long as they use the pointer.
+    ///
+    /// The previous handle is disconnected once the files opened with it are dropped.
+    fn raw(&self) -> Arc<RawFs> {
+        {
+            let connection = self
+                .connection
+                .read()
+                .expect("Could not aquire read lock on HDFS connection");
+            match &connection.ticket_cache {
+                Some(ticket_cache) if ticket_cache.is_due() => {}
+                _ => return connection.raw.clone(),
+            }
         }
-        let file = unsafe {
-            let cstr_path = CString::new(path).unwrap();
-            hdfsOpenFile(self.raw, cstr_path.as_ptr(), O_APPEND, 0, 0, 0)
+
+        let (params, delegation_token) = {
+            let mut connection = self
+                .connection
+                .write()
+                .expect("Could not aquire write lock on HDFS connection");
+            let refreshed = match connection.ticket_cache.as_mut() {
+                Some(ticket_cache) => ticket_cache.check(),
+                None => false,
+            };
+            if !refreshed {
+                return connection.raw.clone();
+            }
+            (
+                connection.params.clone(),
+                connection.delegation_token.clone(),
+            )
         };
-        self.new_hdfs_file(path, file)
+
+        // connect without holding the lock, so that other callers keep using the current
+        // handle meanwhile
+        match create_hdfs_fs(
+            &self.connection_properties,
+            &params,
+            delegation_token.as_ref(),
+        ) {
+            Ok(raw) => {
+                info!(
+                    "Reconnected to {} with refreshed Kerberos ticket cache",
+                    self.connection_properties.namenode_host
+                );
+                let raw = Arc::new(raw);
+                let previous = {
+                    let mut connection = self
+                        .connection
+                        .write()
+                        .expect("Could not aquire write lock on HDFS connection");
+                    std::mem::replace(&mut connection.raw, raw.clone())
+                };
+                drop(previous);
+                raw
+            }
+            Err(e) => {
+                warn!(
+                    "Could not reconnect with refreshed Kerberos ticket cache: {}",
+                    e
+                );
+                self.connection
+                    .read()
+                    .expect("Could not aquire read lock on HDFS connection")
+                    .raw
+                    .clone()
+            }
+        }
     }
 
-    /// Create the given path as read-only
-    #[inline]
-    pub fn create(&self, path: &str) -> Result<HdfsFile, HdfsErr> {
-        self.create_with_params(path, false, 0, 0, 0)
+    /// Expiry time of the Kerberos credentials used by the connection, as read from the
+    /// ticket cache. `None` if the connection does not use a Kerberos ticket cache, or if it
+    /// could not be read.
+    pub fn kerberos_ticket_expiry(&self) -> Option<SystemTime> {
+        self.raw();
+        let connection = self
+            .connection
+            .read()
+            .expect("Could not aquire read lock on HDFS connection");
+        connection
+            .ticket_cache
+            .as_ref()
+            .and_then(TicketCacheWatch::expires_at)
     }
 
-    /// Create the given path as writable
+    /// The user requests are executed as: the proxy user, the short name of the owner of the
+    /// delegation token, the short name of the Kerberos principal of the ticket cache,
+    /// `namenode_user`, or the local user, in that order.
+    pub fn effective_user(&self) -> Result<String, HdfsErr> {
+        match &self.connection_properties.proxy_user {
+            Some(proxy_user) => Ok(proxy_user.clone()),
+            None => self.login_user(),
+        }
+    }
+
+    /// The user the connection authenticates as, which is the effective user unless a proxy
+    /// user is set.
+    fn login_user(&self) -> Result<String, HdfsErr> {
+        let (delegation_token, ticket_cache) = {
+            let connection = self
+                .connection
+                .read()
+                .expect("Could not aquire read lock on HDFS connection");
+            (
+                connection.delegation_token.clone(),
+                kerberos_ticket_cache(
+                    &self.connection_properties,
+                    &connection.params,
+                    connection.delegation_token.is_some(),
+                ),
+            )
+        };
+        if let Some(token) = delegation_token {
+            return AuthToLocal::from_env()?.short_name(&token.owner()?);
+        }
+        if let Some(path) = ticket_cache {
+            let cache = CredentialCache::read(path)?;
+            return AuthToLocal::from_env()?.short_name(&cache.principal.to_string());
+        }
+        if let Some(user) = &self.connection_properties.namenode_user {
+            return Ok(user.clone());
+        }
+        local_user()
+            .ok_or_else(|| HdfsErr::Miscellaneous("Could not determine the local user".to_owned()))
+    }
+
+    /// The home directory of the effective user, e.g. `/user/alice`, which is the initial
+    /// working directory.
+    pub fn home_dir(&self) -> Result<HdfsPathBuf, HdfsErr> {
+        self.read_dir(hdfsGetHomeDirectory, "home")
+    }
+
+    /// The directory relative paths are resolved against. It is shared by every `HdfsFs`
+    /// connected to the same namenode as the same user.
+    pub fn working_dir(&self) -> Result<HdfsPathBuf, HdfsErr> {
+        let working_dir = self
+            .connection
+            .read()
+            .expect("Could not aquire read lock on HDFS connection")
+            .working_dir
+            .clone();
+        match working_dir {
+            Some(working_dir) => Ok(working_dir),
+            None => self.home_dir(),
+        }
+    }
+
+    /// Change the working directory, which may itself be given relative to the current one.
+    /// The directory is not checked to exist.
+    pub fn set_working_dir(&self, path: impl AsRef<HdfsPath>) -> Result<(), HdfsErr> {
+        let path = self.qualify(path)?;
+        self.connection
+            .write()
+            .expect("Could not aquire write lock on HDFS connection")
+            .working_dir = Some(path);
+        Ok(())
+    }
+
+    /// The absolute, normalized form of a path, which every method applies to its paths.
+    /// `~` is the home directory and other relative paths are relative to the working
+    /// directory. `.` and `..` components are resolved as by [`HdfsPath::normalize`].
+    ///
+    /// URIs are reduced to their path, and must name this filesystem, e.g.
+    /// `hdfs://namenode:8020/data`, or the default filesystem, e.g. `hdfs:///data`. Paths
+    /// containing a NUL fail with [`HdfsErr::InvalidUrl`], as libhdfs3 cannot be given them.
+    pub fn qualify(&self, path: impl AsRef<HdfsPath>) -> Result<HdfsPathBuf, HdfsErr> {
+        let path = path.as_ref();
+        if path.as_bytes().contains(&0) {
+            return Err(HdfsErr::InvalidUrl(path.to_string()));
+        }
+        if let (Some(scheme), Some(authority)) = (path.scheme(), path.authority()) {
+            if !scheme.eq_ignore_ascii_case(HDFS_SCHEME)
+                || !(authority.is_empty() || self.is_authority(authority))
+            {
+                return Err(HdfsErr::InvalidUrl(path.to_string()));
+            }
+            return Ok(HdfsPath::new("/").join(path.without_authority()));
+        }
+        if path.is_absolute() {
+            Ok(path.normalize())
+        } else if path.is_home_relative() {
+            Ok(self.home_dir()?.join(path.strip_prefix("~").unwrap()))
+        } else {
+            Ok(self.working_dir()?.join(path))
+        }
+    }
+
+    /// The `hdfs://` URI of a path on this filesystem, e.g. `hdfs://namenode:8020/data`
+    pub fn to_uri(&self, path: impl AsRef<HdfsPath>) -> Result<HdfsPathBuf, HdfsErr> {
+        self.qualify(path)?.to_uri(&self.authority())
+    }
+
+    /// The authority of the URIs of this filesystem, `host:port` or the nameservice
+    fn authority(&self) -> String {
+        let properties = &self.connection_properties;
+        if properties.namenode_port == 0 {
+            properties.namenode_host.clone()
+        } else {
+            format!("{}:{}", properties.namenode_host, properties.namenode_port)
+        }
+    }
+
+    /// Does the authority of a URI name this filesystem? A namenode URI without a port
+    /// refers to the default port.
+    fn is_authority(&self, authority: &str) -> bool {
+        let properties = &self.connection_properties;
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse::<u16>().ok()),
+            None => (authority, None),
+        };
+        let port = match (port, properties.namenode_port) {
+            (Some(port), _) => port,
+            (None, 0) => 0,
+            (None, _) => DEFAULT_NAMENODE_PORT,
+        };
+        host.eq_ignore_ascii_case(&properties.namenode_host) && port == properties.namenode_port
+    }
+
+    /// Read a directory into a buffer, growing it until the path fits
+    fn read_dir(
+        &self,
+        get: unsafe extern "C" fn(hdfsFS, *mut c_char, size_t) -> *mut c_char,
+        name: &str,
+    ) -> Result<HdfsPathBuf, HdfsErr> {
+        let mut buf = vec![0u8; 1024];
+        loop {
+            let ptr = unsafe {
+                get(
+                    self.raw().as_ptr(),
+                    buf.as_mut_ptr() as *mut c_char,
+                    buf.len() as size_t,
+                )
+            };
+            if !ptr.is_null() {
+                let dir = unsafe { CStr::from_ptr(ptr) };
+                return Ok(HdfsPathBuf::from(dir.to_bytes()));
+            }
+            if std::io::Error::last_os_error().raw_os_error() != Some(libc::ENOMEM)
+                || buf.len() >= 64 * 1024
+            {
+                return Err(HdfsErr::Miscellaneous(format!(
+                    "Could not get the {} directory",
+                    name
+                )));
+            }
+            buf.resize(buf.len() * 2, 0);
+        }
+    }
+
+    /// Check that the effective user may perform an action on a path, using the groups of
+    /// the user on the local system. See [`HdfsFs::check_access_as`].
+    pub fn check_access(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        action: FsAction,
+    ) -> Result<(), HdfsErr> {
+        let user = self.effective_user()?;
+        let groups = local_groups(&user);
+        self.check_access_as(path, action, &user, &groups)
+    }
+
+    /// Check that a user may perform an action on a path, the way the namenode would, to
+    /// fail fast before starting work.
+    ///
+    /// Every directory on the way to the path must be searchable (`x`). If the path does not
+    /// exist and the action includes writing, the deepest existing directory must be
+    /// writable so that the path can be created. Fails with [`HdfsErr::AccessDenied`]
+    /// naming the first component which denies access, [`HdfsErr::NotADirectory`] naming a
+    /// file on the way to the path, or [`HdfsErr::FileNotFound`] if the path does not exist
+    /// and the action does not include writing.
+    ///
+    /// Like the namenode, permissions are not checked for the superuser or for members of
+    /// the `dfs.permissions.superusergroup` group, as configured for the connection or in
+    /// `hdfs-site.xml`, `supergroup` by default. The superuser is the user running the
+    /// namenode, which the client cannot ask for, so it is assumed to be the owner of `/`.
+    ///
+    /// Only the owner, group and other permission bits are evaluated: ACL entries of named
+    /// users and groups, and the mask, are ignored. On paths with ACLs the result is only
+    /// advisory, and the namenode may allow or deny the action regardless.
+    ///
+    /// * path - the path, which may be relative to the working directory
+    /// * action - the action to check, e.g. [`FsAction::Read`]
+    /// * user - the user performing the action
+    /// * groups - the groups of the user
+    pub fn check_access_as(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        action: FsAction,
+        user: &str,
+        groups: &[String],
+    ) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let check = |path: &HdfsPath, status: &FileStatus, needed: FsAction| {
+            let permitted = permitted_action(
+                &status.owner(),
+                &status.group(),
+                status.permission() as u16,
+                user,
+                groups,
+            );
+            if permitted.implies(needed) {
+                Ok(())
+            } else {
+                Err(HdfsErr::AccessDenied(path.to_string(), needed))
+            }
+        };
+
+        let mut dir = HdfsPathBuf::from("/");
+        let mut dir_status = self.existing_status(&dir)?;
+        let superuser = dir_status.owner() == user || groups.contains(&self.supergroup());
+        for name in path.components() {
+            if !dir_status.is_directory() {
+                return Err(HdfsErr::NotADirectory(dir.to_string()));
+            }
+            if !superuser {
+                check(&dir, &dir_status, FsAction::Execute)?;
+            }
+
+            let child = dir.join(name);
+            match self.existing_status(&child) {
+                Ok(status) => {
+                    dir_status = status;
+                    dir = child;
+                }
+                Err(HdfsErr::FileNotFound(_)) if action.implies(FsAction::Write) => {
+                    return if superuser {
+                        Ok(())
+                    } else {
+                        check(&dir, &dir_status, FsAction::Write)
+                    };
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        if superuser {
+            Ok(())
+        } else {
+            check(&dir, &dir_status, action)
+        }
+    }
+
+    /// The group whose members are superusers: `dfs.permissions.superusergroup` as
+    /// configured for the connection, or else in `hdfs-site.xml` of the Hadoop configuration
+    /// directory, or else `supergroup`.
+    fn supergroup(&self) -> String {
+        let configured = self
+            .connection
+            .read()
+            .expect("Could not aquire read lock on HDFS connection")
+            .params
+            .get(SUPERGROUP)
+            .cloned();
+        configured
+            .or_else(|| {
+                let hdfs_site = hadoop_conf_dir()?.join("hdfs-site.xml");
+                if !hdfs_site.exists() {
+                    return None;
+                }
+                match read_site_xml(&hdfs_site) {
+                    Ok(mut properties) => properties.remove(SUPERGROUP),
+                    Err(e) => {
+                        warn!("Could not read {}: {}", SUPERGROUP, e);
+                        None
+                    }
+                }
+            })
+            .unwrap_or_else(|| DEFAULT_SUPERGROUP.to_owned())
+    }
+
+    /// Get the RPC address (`host:port`) of the namenode currently serving requests.
+    ///
+    /// For an HA nameservice this changes when the client fails over to another namenode,
+    /// so the result should not be cached.
     #[inline]
-    pub fn create_with_overwrite(&self, path: &str, overwrite: bool) -> Result<HdfsFile, HdfsErr> {
-        self.create_with_params(path, overwrite, 0, 0, 0)
+    pub fn active_namenode(&self) -> Result<String, HdfsErr> {
+        get_active_namenode(self.raw().as_ptr())
     }
 
-    /// Create the given path
-    pub fn create_with_params(
+    /// Get a delegation token from the namenode. The filesystem must be Kerberos
+    /// authenticated.
+    ///
+    /// * renewer - the user allowed to renew the token
+    pub fn get_delegation_token(&self, renewer: &str) -> Result<DelegationToken, HdfsErr> {
+        let ptr = unsafe {
+            let cstr_renewer = CString::new(renewer).unwrap();
+            hdfsGetDelegationToken(self.raw().as_ptr(), cstr_renewer.as_ptr())
+        };
+        if ptr.is_null() {
+            return Err(HdfsErr::Miscellaneous(format!(
+                "Could not get delegation token for renewer {}",
+                renewer
+            )));
+        }
+        let encoded = unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned();
+        unsafe { hdfsFreeDelegationToken(ptr) };
+        DelegationToken::from_encoded(encoded)
+    }
+
+    /// Renew a delegation token, returning its new expiration time.
+    pub fn renew_delegation_token(&self, token: &DelegationToken) -> Result<SystemTime, HdfsErr> {
+        let ret = unsafe {
+            let cstr_token = CString::new(token.as_str()).unwrap();
+            hdfsRenewDelegationToken(self.raw().as_ptr(), cstr_token.as_ptr())
+        };
+        if ret < 0 {
+            Err(HdfsErr::Miscellaneous(
+                "Could not renew delegation token".to_owned(),
+            ))
+        } else {
+            Ok(UNIX_EPOCH + Duration::from_millis(ret as u64))
+        }
+    }
+
+    /// Cancel a delegation token.
+    pub fn cancel_delegation_token(&self, token: &DelegationToken) -> Result<bool, HdfsErr> {
+        let ret = unsafe {
+            let cstr_token = CString::new(token.as_str()).unwrap();
+            hdfsCancelDelegationToken(self.raw().as_ptr(), cstr_token.as_ptr())
+        };
+        if ret == 0 {
+            Ok(true)
+        } else {
+            Err(HdfsErr::Miscellaneous(
+                "Could not cancel delegation token".to_owned(),
+            ))
+        }
+    }
+
+    /// Create a snapshot of a snapshottable directory, returning the path of the snapshot,
+    /// e.g. `/data/.snapshot/s1`.
+    ///
+    /// * path - the snapshottable directory
+    /// * name - the name of the snapshot, `None` to let the namenode generate one from the
+    ///   current time
+    pub fn create_snapshot(
         &self,
-        path: &str,
-        overwrite: bool,
-        buf_size: i32,
-        replica_num: i16,
-        block_size: i64,
-    ) -> Result<HdfsFile, HdfsErr> {
-        if !overwrite && self.exist(path) {
-            return Err(HdfsErr::FileAlreadyExists(path.to_owned()));
+        path: impl AsRef<HdfsPath>,
+        name: Option<&str>,
+    ) -> Result<HdfsPathBuf, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let mut buf = vec![0 as c_char; 4096];
+        let ptr = unsafe {
+            let cstr_path = path.to_c_string()?;
+            let cstr_name = name
+                .map(|name| HdfsPath::new(name).to_c_string())
+                .transpose()?;
+            hdfsCreateSnapshot(
+                self.raw().as_ptr(),
+                cstr_path.as_ptr(),
+                cstr_name
+                    .as_ref()
+                    .map_or(std::ptr::null(), |name| name.as_ptr()),
+                buf.as_mut_ptr(),
+                buf.len() as size_t,
+            )
+        };
+        if ptr.is_null() {
+            Err(HdfsErr::Miscellaneous(format!(
+                "Could not create snapshot of {}",
+                path
+            )))
+        } else {
+            Ok(HdfsPathBuf::from(unsafe { CStr::from_ptr(ptr) }.to_bytes()))
+        }
+    }
+
+    /// Delete a snapshot of a snapshottable directory.
+    pub fn delete_snapshot(&self, path: impl AsRef<HdfsPath>, name: &str) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            let cstr_name = HdfsPath::new(name).to_c_string()?;
+            hdfsDeleteSnapshot(self.raw().as_ptr(), cstr_path.as_ptr(), cstr_name.as_ptr())
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(HdfsErr::Miscellaneous(format!(
+                "Could not delete snapshot {} of {}",
+                name, path
+            )))
+        }
+    }
+
+    /// Rename a snapshot of a snapshottable directory.
+    pub fn rename_snapshot(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            let cstr_old_name = HdfsPath::new(old_name).to_c_string()?;
+            let cstr_new_name = HdfsPath::new(new_name).to_c_string()?;
+            hdfsRenameSnapshot(
+                self.raw().as_ptr(),
+                cstr_path.as_ptr(),
+                cstr_old_name.as_ptr(),
+                cstr_new_name.as_ptr(),
+            )
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(HdfsErr::Miscellaneous(format!(
+                "Could not rename snapshot {} of {} to {}",
+                old_name, path, new_name
+            )))
+        }
+    }
+
+    /// Allow snapshots to be taken of a directory. Requires superuser privilege.
+    pub fn allow_snapshot(&self, path: impl AsRef<HdfsPath>) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsAllowSnapshot(self.raw().as_ptr(), cstr_path.as_ptr())
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(HdfsErr::Miscellaneous(format!(
+                "Could not allow snapshots of {}",
+                path
+            )))
+        }
+    }
+
+    /// Disallow snapshots of a directory, which must not have any snapshots left. Requires
+    /// superuser privilege.
+    pub fn disallow_snapshot(&self, path: impl AsRef<HdfsPath>) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsDisallowSnapshot(self.raw().as_ptr(), cstr_path.as_ptr())
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(HdfsErr::Miscellaneous(format!(
+                "Could not disallow snapshots of {}",
+                path
+            )))
+        }
+    }
+
+    /// Get the snapshottable directories owned by the current user, or all of them for the
+    /// superuser.
+    pub fn snapshottable_directories(&self) -> Result<Vec<SnapshottableDirectory>, HdfsErr> {
+        let mut entry_num: c_int = -1;
+        let ptr = unsafe { hdfsGetSnapshottableDirListing(self.raw().as_ptr(), &mut entry_num) };
+        if ptr.is_null() {
+            return if entry_num == 0 {
+                Ok(Vec::new())
+            } else {
+                Err(HdfsErr::Miscellaneous(
+                    "Could not list snapshottable directories".to_owned(),
+                ))
+            };
+        }
+        let list = (0..entry_num as usize)
+            .map(|idx| snapshottable_directory(unsafe { &*ptr.add(idx) }))
+            .collect();
+        unsafe { hdfsFreeSnapshottableDirInfo(ptr, entry_num) };
+        Ok(list)
+    }
+
+    /// Get the differences between two snapshots of a directory.
+    ///
+    /// * path - the snapshottable directory
+    /// * from_snapshot - the earlier snapshot, `None` for the current state of the directory
+    /// * to_snapshot - the later snapshot, `None` for the current state of the directory
+    pub fn snapshot_diff(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        from_snapshot: Option<&str>,
+        to_snapshot: Option<&str>,
+    ) -> Result<SnapshotDiffReport, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ptr = unsafe {
+            let cstr_path = path.to_c_string()?;
+            let cstr_from = HdfsPath::new(from_snapshot.unwrap_or("")).to_c_string()?;
+            let cstr_to = HdfsPath::new(to_snapshot.unwrap_or("")).to_c_string()?;
+            hdfsGetSnapshotDiffReport(
+                self.raw().as_ptr(),
+                cstr_path.as_ptr(),
+                cstr_from.as_ptr(),
+                cstr_to.as_ptr(),
+            )
+        };
+        if ptr.is_null() {
+            return Err(HdfsErr::Miscellaneous(format!(
+                "Could not get snapshot diff report of {}",
+                path
+            )));
+        }
+        let report = snapshot_diff_report(unsafe { &*ptr });
+        unsafe { hdfsFreeSnapshotDiffReport(ptr) };
+        Ok(report)
+    }
+
+    /// Get the space usage and block health of the cluster, like `hdfs dfsadmin -report`.
+    pub fn stats(&self) -> Result<FsStats, HdfsErr> {
+        let ptr = unsafe { hdfsGetFsStats(self.raw().as_ptr()) };
+        if ptr.is_null() {
+            return Err(HdfsErr::Miscellaneous(
+                "Could not get filesystem stats".to_owned(),
+            ));
+        }
+        let stats = fs_stats(unsafe { &*ptr });
+        unsafe { hdfsFreeFsStats(ptr) };
+        Ok(stats)
+    }
+
+    /// Get the defaults the namenode applies to new files, such as the block size and
+    /// replication, so that writers can match the cluster.
+    pub fn server_defaults(&self) -> Result<ServerDefaults, HdfsErr> {
+        let ptr = unsafe { hdfsGetServerDefaults(self.raw().as_ptr()) };
+        if ptr.is_null() {
+            return Err(HdfsErr::Miscellaneous(
+                "Could not get server defaults".to_owned(),
+            ));
+        }
+        let defaults = server_defaults(unsafe { &*ptr });
+        unsafe { hdfsFreeServerDefaults(ptr) };
+        Ok(defaults)
+    }
+
+    /// Get the disk usage, file and directory counts and quotas of a directory tree, like
+    /// `hdfs dfs -count -q`.
+    ///
+    /// If the namenode does not support the call, the counts are computed by walking the
+    /// tree instead, which is much slower for large trees and does not report quotas.
+    pub fn content_summary(&self, path: impl AsRef<HdfsPath>) -> Result<ContentSummary, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ptr = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsGetContentSummary(self.raw().as_ptr(), cstr_path.as_ptr())
+        };
+        if ptr.is_null() {
+            if std::io::Error::last_os_error().raw_os_error() == Some(libc::ENOTSUP) {
+                debug!(
+                    "getContentSummary is not supported, walking the tree at {} instead",
+                    path
+                );
+                return self.walk_content_summary(path);
+            }
+            return Err(HdfsErr::Miscellaneous(format!(
+                "Could not get content summary of {}",
+                path
+            )));
+        }
+        let summary = content_summary(unsafe { &*ptr });
+        unsafe { hdfsFreeContentSummary(ptr) };
+        Ok(summary)
+    }
+
+    /// Compute the content summary of a tree by listing each of its directories.
+    fn walk_content_summary(&self, path: &HdfsPath) -> Result<ContentSummary, HdfsErr> {
+        let mut summary = ContentSummary::default();
+        let mut pending = vec![self.get_file_status(path)?];
+        while let Some(status) = pending.pop() {
+            if status.is_directory() {
+                summary.add_directory();
+                pending.extend(self.list_status(status.path())?);
+            } else {
+                summary.add_file(status.len() as u64, status.replica_count().max(0) as u16);
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Set the quotas of a directory. Requires superuser privilege.
+    ///
+    /// * path - the directory
+    /// * quota - the maximum number of files and directories in the tree, including the
+    ///   directory itself, `None` to leave it unchanged
+    /// * space_quota - the maximum disk space in bytes consumed by the tree, including
+    ///   replicas, `None` to leave it unchanged
+    pub fn set_quota(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        quota: Option<u64>,
+        space_quota: Option<u64>,
+    ) -> Result<(), HdfsErr> {
+        self.set_quota_values(path, quota_value(quota), quota_value(space_quota))
+    }
+
+    /// Clear the namespace and disk space quotas of a directory. Requires superuser
+    /// privilege.
+    pub fn clear_quota(&self, path: impl AsRef<HdfsPath>) -> Result<(), HdfsErr> {
+        self.set_quota_values(path, QUOTA_RESET, QUOTA_RESET)
+    }
+
+    fn set_quota_values(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        quota: i64,
+        space_quota: i64,
+    ) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsSetQuota(self.raw().as_ptr(), cstr_path.as_ptr(), quota, space_quota)
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(HdfsErr::Miscellaneous(format!(
+                "Could not set quota of {}",
+                path
+            )))
+        }
+    }
+
+    /// Create a symlink to a file or directory. Other calls do not follow symlinks, and fail
+    /// with `HdfsErr::UnresolvedLink` on paths containing one.
+    ///
+    /// * target - the path the link points to, which is not resolved or checked
+    /// * link - the path of the link
+    /// * create_parent - if true, create missing parent directories of the link
+    pub fn create_symlink(
+        &self,
+        target: impl AsRef<HdfsPath>,
+        link: impl AsRef<HdfsPath>,
+        create_parent: bool,
+    ) -> Result<(), HdfsErr> {
+        let target = target.as_ref();
+        let link = &*self.qualify(link)?;
+        let ret = unsafe {
+            let cstr_target = target.to_c_string()?;
+            let cstr_link = link.to_c_string()?;
+            hdfsCreateSymlink(
+                self.raw().as_ptr(),
+                cstr_target.as_ptr(),
+                cstr_link.as_ptr(),
+                create_parent as c_int,
+            )
+        };
+        if ret == 0 {
+            Ok(())
+        } else if std::io::Error::last_os_error().raw_os_error() == Some(libc::EEXIST) {
+            Err(HdfsErr::FileAlreadyExists(link.to_string()))
+        } else {
+            Err(path_error(
+                link,
+                format!("Could not create symlink {} to {}", link, target),
+            ))
+        }
+    }
+
+    /// Get the target of a symlink.
+    pub fn read_link(&self, path: impl AsRef<HdfsPath>) -> Result<HdfsPathBuf, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ptr = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsGetLinkTarget(self.raw().as_ptr(), cstr_path.as_ptr())
+        };
+        if ptr.is_null() {
+            return Err(path_error(path, format!("Could not read symlink {}", path)));
+        }
+        let target = HdfsPathBuf::from(unsafe { CStr::from_ptr(ptr) }.to_bytes());
+        unsafe { hdfsFreeLinkTarget(ptr) };
+        Ok(target)
+    }
+
+    /// Get the file status of a path, of the symlink itself if it is one.
+    pub fn symlink_metadata(&self, path: impl AsRef<HdfsPath>) -> Result<FileStatus, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ptr = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsGetPathLinkInfo(self.raw().as_ptr(), cstr_path.as_ptr())
+        };
+        if ptr.is_null() {
+            Err(path_error(
+                path,
+                format!("Could not get symlink status for {}", path),
+            ))
+        } else {
+            Ok(FileStatus::new(ptr))
+        }
+    }
+
+    /// Open a file for append
+    pub fn append(&self, path: impl AsRef<HdfsPath>) -> Result<HdfsFile, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        if !self.exist(path) {
+            return Err(HdfsErr::FileNotFound(path.to_string()));
+        }
+        let raw = self.raw();
+        let file = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsOpenFile(raw.as_ptr(), cstr_path.as_ptr(), O_APPEND, 0, 0, 0)
+        };
+        self.new_hdfs_file(raw, path, file)
+    }
+
+    /// Create the given path as read-only
+    #[inline]
+    pub fn create(&self, path: impl AsRef<HdfsPath>) -> Result<HdfsFile, HdfsErr> {
+        self.create_with_params(path, false, 0, 0, 0)
+    }
+
+    /// Create the given path as writable
+    #[inline]
+    pub fn create_with_overwrite(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        overwrite: bool,
+    ) -> Result<HdfsFile, HdfsErr> {
+        self.create_with_params(path, overwrite, 0, 0, 0)
+    }
+
+    /// Create the given path
+    pub fn create_with_params(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        overwrite: bool,
+        buf_size: i32,
+        replica_num: i16,
+        block_size: i64,
+    ) -> Result<HdfsFile, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        if !overwrite && self.exist(path) {
+            return Err(HdfsErr::FileAlreadyExists(path.to_string()));
+        }
+        let raw = self.raw();
+        let file = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsOpenFile(
+                raw.as_ptr(),
+                cstr_path.as_ptr(),
+                O_WRONLY,
+                buf_size as c_int,
+                replica_num as c_short,
+                block_size as tOffset,
+            )
+        };
+        self.new_hdfs_file(raw, path, file)
+    }
+
+    pub fn get_file_status(&self, path: impl AsRef<HdfsPath>) -> Result<FileStatus, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ptr = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsGetPathInfo(self.raw().as_ptr(), cstr_path.as_ptr())
+        };
+        if ptr.is_null() {
+            Err(path_error(
+                path,
+                format!("Could not get file status for {}", path),
+            ))
+        } else {
+            Ok(FileStatus::new(ptr))
+        }
+    }
+
+    /// Delete the content at the given path.
+    ///
+    /// * path - the path on the filesystem
+    /// * recursive - if true, delete the content recursively.
+    pub fn delete(&self, path: impl AsRef<HdfsPath>, recursive: bool) -> Result<bool, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let res = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsDelete(self.raw().as_ptr(), cstr_path.as_ptr(), recursive as c_int)
+        };
+        if res == 0 {
+            Ok(true)
+        } else {
+            Err(path_error(path, format!("Could not delete path: {}", path)))
+        }
+    }
+
+    /// Check if the given path exists on the filesystem
+    pub fn exist(&self, path: impl AsRef<HdfsPath>) -> bool {
+        let cstr_path = match self.qualify(path).and_then(|path| path.to_c_string()) {
+            Ok(cstr_path) => cstr_path,
+            Err(_) => return false,
+        };
+        (unsafe { hdfsExists(self.raw().as_ptr(), cstr_path.as_ptr()) } == 0)
+    }
+
+    /// Get the file status of each entry under the specified path
+    /// Note that it is an error to list an empty directory.
+    pub fn list_status(&self, path: impl AsRef<HdfsPath>) -> Result<Vec<FileStatus>, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let mut entry_num: c_int = 0;
+        let ptr = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsListDirectory(self.raw().as_ptr(), cstr_path.as_ptr(), &mut entry_num)
+        };
+        if ptr.is_null() {
+            Err(path_error(
+                path,
+                format!("Could not list content of path: {}", path),
+            ))
+        } else {
+            let shared_ptr = Rc::new(HdfsFileInfoPtr::new_array(ptr, entry_num));
+
+            let list = (0..entry_num)
+                .map(|idx| FileStatus::from_array(shared_ptr.clone(), idx as u32))
+                .collect::<Vec<FileStatus>>();
+
+            Ok(list)
+        }
+    }
+
+    pub fn mkdir(&self, path: impl AsRef<HdfsPath>) -> Result<bool, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let res = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsCreateDirectory(self.raw().as_ptr(), cstr_path.as_ptr())
+        };
+        if res == 0 {
+            Ok(true)
+        } else {
+            Err(path_error(
+                path,
+                format!("Could not create directory at path: {}", path),
+            ))
+        }
+    }
+
+    #[inline]
+    pub fn open(&self, path: impl AsRef<HdfsPath>) -> Result<HdfsFile, HdfsErr> {
+        self.open_with_buf_size(path, 0)
+    }
+
+    pub fn open_with_buf_size(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        buf_size: i32,
+    ) -> Result<HdfsFile, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let raw = self.raw();
+        let file = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsOpenFile(
+                raw.as_ptr(),
+                cstr_path.as_ptr(),
+                O_RDONLY,
+                buf_size as c_int,
+                0,
+                0,
+            )
+        };
+        self.new_hdfs_file(raw, path, file)
+    }
+
+    pub fn open_for_writing(&self, path: impl AsRef<HdfsPath>) -> Result<HdfsFile, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let raw = self.raw();
+        let file = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsOpenFile(raw.as_ptr(), cstr_path.as_ptr(), O_WRONLY, 0, 0, 0)
+        };
+        self.new_hdfs_file(raw, path, file)
+    }
+
+    fn new_hdfs_file(
+        &self,
+        raw: Arc<RawFs>,
+        path: &HdfsPath,
+        file: hdfsFile,
+    ) -> Result<HdfsFile, HdfsErr> {
+        if file.is_null() {
+            return Err(path_error(
+                path,
+                format!("Could not open HDFS file at path {}", path),
+            ));
+        }
+        let cipher = if self.transparent_encryption() {
+            match self.file_cipher(&raw, path) {
+                Ok(cipher) => cipher,
+                Err(e) => {
+                    unsafe { hdfsCloseFile(raw.as_ptr(), file) };
+                    return Err(e);
+                }
+            }
+        } else {
+            None
+        };
+        Ok(HdfsFile {
+            fs: self.clone(),
+            raw,
+            path: path.to_owned(),
+            file,
+            cipher,
+            _market: PhantomData,
+        })
+    }
+
+    /// Are files in encryption zones decrypted and encrypted? See
+    /// [`ConnectionProperties::transparent_encryption`].
+    fn transparent_encryption(&self) -> bool {
+        if let Some(enabled) = self.connection_properties.transparent_encryption {
+            return enabled;
+        }
+        if self
+            .kms
+            .read()
+            .expect("Could not aquire read lock on KMS client")
+            .is_some()
+        {
+            return true;
+        }
+        let connection = self
+            .connection
+            .read()
+            .expect("Could not aquire read lock on HDFS connection");
+        if connection.params.contains_key(KEY_PROVIDER_URI)
+            || connection.params.contains_key(KEY_PROVIDER_PATH)
+        {
+            return true;
+        }
+        match hadoop_conf_dir().map(|conf_dir| site_key_provider_uri(&conf_dir)) {
+            Some(Ok(uri)) => uri.is_some(),
+            Some(Err(e)) => {
+                warn!("Could not read the KMS configuration: {}", e);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// The cipher of a file in an encryption zone, whose key is decrypted by the KMS, `None`
+    /// if the file is not encrypted. It is looked up by path with the handle the file was
+    /// just opened with, as libhdfs3 does not return the encryption info of an open file, so
+    /// a file replaced in between is not detected.
+    fn file_cipher(&self, raw: &RawFs, path: &HdfsPath) -> Result<Option<CtrCipher>, HdfsErr> {
+        let ptr = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsGetPathInfo(raw.as_ptr(), cstr_path.as_ptr())
+        };
+        if ptr.is_null() {
+            return Err(path_error(
+                path,
+                format!("Could not get file status for {}", path),
+            ));
+        }
+        let info = match FileStatus::new(ptr).encryption_info() {
+            Some(info) => info,
+            None => return Ok(None),
+        };
+        let key = self.kms_client(path)?.decrypt_edek(&info)?;
+        CtrCipher::new(info.suite, &key, &info.iv).map(Some)
+    }
+
+    /// Set the KMS which decrypts the keys of files in encryption zones, instead of the one
+    /// configured in `dfs.encryption.key.provider.uri` or `hadoop.security.key.provider.path`.
+    /// It is shared by every `HdfsFs` connected to the same namenode.
+    pub fn set_kms_client(&self, kms: KmsClient) {
+        *self
+            .kms
+            .write()
+            .expect("Could not aquire write lock on KMS client") = Some(kms);
+    }
+
+    /// The KMS client, which is created on first use from the KMS URI of the connection
+    /// parameters, or else of `hdfs-site.xml` or `core-site.xml` in [`hadoop_conf_dir`]. It
+    /// authenticates with SPNEGO if the connection is Kerberos authenticated, or else as the
+    /// login user with simple authentication, and acts on behalf of the proxy user if set.
+    fn kms_client(&self, path: &HdfsPath) -> Result<KmsClient, HdfsErr> {
+        if let Some(kms) = &*self
+            .kms
+            .read()
+            .expect("Could not aquire read lock on KMS client")
+        {
+            return Ok(kms.clone());
+        }
+        let (uri, ticket_cache) = {
+            let connection = self
+                .connection
+                .read()
+                .expect("Could not aquire read lock on HDFS connection");
+            let params = &connection.params;
+            (
+                params
+                    .get(KEY_PROVIDER_URI)
+                    .or_else(|| params.get(KEY_PROVIDER_PATH))
+                    .cloned(),
+                kerberos_ticket_cache(
+                    &self.connection_properties,
+                    params,
+                    connection.delegation_token.is_some(),
+                ),
+            )
+        };
+        let uri = match uri {
+            Some(uri) => uri,
+            None => {
+                let conf_dir = hadoop_conf_dir();
+                let uri = match &conf_dir {
+                    Some(conf_dir) => site_key_provider_uri(conf_dir)?,
+                    None => None,
+                };
+                uri.ok_or_else(|| {
+                    let searched = match &conf_dir {
+                        Some(conf_dir) => format!(
+                            "the connection parameters, nor in hdfs-site.xml or core-site.xml \
+                             of {}",
+                            conf_dir.display()
+                        ),
+                        None => "the connection parameters, and there is no Hadoop \
+                                 configuration directory"
+                            .to_owned(),
+                    };
+                    HdfsErr::Miscellaneous(format!(
+                        "{} is encrypted, but neither {} nor {} is set in {}",
+                        path, KEY_PROVIDER_URI, KEY_PROVIDER_PATH, searched
+                    ))
+                })?
+            }
+        };
+        let kms = KmsClient::new(&uri)?;
+        let kms = match ticket_cache {
+            Some(ticket_cache) => kms.with_kerberos(Some(ticket_cache)),
+            None => kms.with_user(self.login_user()?),
+        };
+        let kms = match &self.connection_properties.proxy_user {
+            Some(proxy_user) => kms.with_proxy_user(proxy_user.clone()),
+            None => kms,
+        };
+        self.set_kms_client(kms.clone());
+        Ok(kms)
+    }
+
+    /// Rename a file
+    ///
+    /// old_path - the path to rename
+    /// new_path - the new name
+    ///
+    /// Note that the destination directory must exist, and that the rename fails if
+    /// `new_path` exists. See [`HdfsFs::rename_with_options`] to replace it.
+    pub fn rename(
+        &self,
+        old_path: impl AsRef<HdfsPath>,
+        new_path: impl AsRef<HdfsPath>,
+    ) -> Result<bool, HdfsErr> {
+        let old_path = &*self.qualify(old_path)?;
+        let new_path = &*self.qualify(new_path)?;
+        let ret = unsafe {
+            let cstr_old_path = old_path.to_c_string()?;
+            let cstr_new_path = new_path.to_c_string()?;
+            hdfsRename(
+                self.raw().as_ptr(),
+                cstr_old_path.as_ptr(),
+                cstr_new_path.as_ptr(),
+            )
+        };
+        if ret == 0 {
+            Ok(true)
+        } else {
+            Err(HdfsErr::Miscellaneous(format!(
+                "Could not reanme {} to {}",
+                old_path, new_path
+            )))
+        }
+    }
+
+    /// Rename a file or directory, e.g. to atomically replace an output file with
+    /// `overwrite` set.
+    ///
+    /// The rename itself is atomic, but creating the parent of `dst` is a separate step
+    /// which is not undone if the rename fails. Fails with
+    ///
+    /// * [`HdfsErr::FileNotFound`] if `src` or the parent of `dst` does not exist
+    /// * [`HdfsErr::FileAlreadyExists`] if `dst` exists and `overwrite` is not set
+    /// * [`HdfsErr::NotADirectory`] if the parent of `dst` is a file
+    /// * [`HdfsErr::QuotaExceeded`] if the rename would exceed a quota of the parent of `dst`
+    /// * [`HdfsErr::AccessDenied`] if the parent of `src` or `dst` is not writable
+    /// * [`HdfsErr::UnresolvedLink`] if `src` or `dst` contains a symlink
+    ///
+    /// Replacing a non-empty directory fails with [`HdfsErr::Miscellaneous`].
+    pub fn rename_with_options(
+        &self,
+        src: impl AsRef<HdfsPath>,
+        dst: impl AsRef<HdfsPath>,
+        options: RenameOptions,
+    ) -> Result<(), HdfsErr> {
+        let src = &*self.qualify(src)?;
+        let dst = &*self.qualify(dst)?;
+        let parent = parent_dir(dst);
+        if options.create_parent && !self.exist(parent) {
+            let res = unsafe {
+                let cstr_parent = parent.to_c_string()?;
+                hdfsCreateDirectory(self.raw().as_ptr(), cstr_parent.as_ptr())
+            };
+            if res != 0 {
+                return Err(create_parent_error(parent));
+            }
+        }
+        let ret = unsafe {
+            let cstr_src = src.to_c_string()?;
+            let cstr_dst = dst.to_c_string()?;
+            hdfsRename2(
+                self.raw().as_ptr(),
+                cstr_src.as_ptr(),
+                cstr_dst.as_ptr(),
+                options.overwrite as c_int,
+            )
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(rename_error(src, dst))
+        }
+    }
+
+    /// Concatenate files onto a target file on the namenode by moving their blocks, without
+    /// copying any data. The sources are deleted.
+    ///
+    /// The namenode only accepts sources which are distinct, non-empty files in the same
+    /// directory as the target, with a block size no larger than that of the target. These
+    /// constraints are checked first, failing with [`HdfsErr::CannotConcat`] naming the
+    /// offending file, or [`HdfsErr::FileNotFound`]. Other rejections by the namenode, e.g.
+    /// of files which are still being written, fail with [`HdfsErr::CannotConcat`] and its
+    /// message.
+    ///
+    /// * target - an existing file, which may be empty
+    /// * sources - the files to append to the target, in order
+    pub fn concat(
+        &self,
+        target: impl AsRef<HdfsPath>,
+        sources: &[impl AsRef<HdfsPath>],
+    ) -> Result<(), HdfsErr> {
+        let target = &*self.qualify(target)?;
+        let sources = sources
+            .iter()
+            .map(|source| self.qualify(source))
+            .collect::<Result<Vec<_>, _>>()?;
+        let sources: Vec<&HdfsPath> = sources.iter().map(HdfsPathBuf::as_path).collect();
+        self.check_concat(target, &sources)?;
+        let cstr_target = target.to_c_string()?;
+        let cstr_sources: Vec<CString> = sources
+            .iter()
+            .map(|source| source.to_c_string())
+            .collect::<Result<_, _>>()?;
+        let mut ptrs: Vec<*const c_char> = cstr_sources.iter().map(|s| s.as_ptr()).collect();
+        ptrs.push(std::ptr::null());
+        let ret =
+            unsafe { hdfsConcat(self.raw().as_ptr(), cstr_target.as_ptr(), ptrs.as_mut_ptr()) };
+        if ret == 0 {
+            return Ok(());
+        }
+        let errno = std::io::Error::last_os_error().raw_os_error();
+        let message = last_error();
+        // the path the namenode names, the longest one in case one is a prefix of another
+        let path = sources
+            .iter()
+            .copied()
+            .chain(std::iter::once(target))
+            .filter(|path| message.contains(&path.to_string()))
+            .max_by_key(|path| path.as_bytes().len())
+            .unwrap_or(target);
+        Err(match errno {
+            Some(libc::ENOENT) => HdfsErr::FileNotFound(path.to_string()),
+            Some(libc::ENOLINK) => HdfsErr::UnresolvedLink(path.to_string()),
+            Some(libc::EINVAL) => {
+                HdfsErr::CannotConcat(path.to_string(), namenode_message(&message).to_owned())
+            }
+            _ => HdfsErr::Miscellaneous(format!(
+                "Could not concat {} onto {}",
+                sources
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                target
+            )),
+        })
+    }
+
+    /// Check the constraints the namenode puts on concat, with the status of each path.
+    fn check_concat(&self, target: &HdfsPath, sources: &[&HdfsPath]) -> Result<(), HdfsErr> {
+        let cannot =
+            |path: &HdfsPath, reason: String| Err(HdfsErr::CannotConcat(path.to_string(), reason));
+
+        if sources.is_empty() {
+            return cannot(target, "no source files given".to_owned());
+        }
+        let target_status = self.existing_status(target)?;
+        if !target_status.is_file() {
+            return cannot(target, "target is not a file".to_owned());
+        }
+        let block_size = target_status.block_size();
+
+        let mut seen: Vec<&HdfsPath> = Vec::with_capacity(sources.len());
+        for source in sources {
+            if *source == target {
+                return cannot(source, "source is the target".to_owned());
+            }
+            if seen.contains(source) {
+                return cannot(source, "source is given more than once".to_owned());
+            }
+            if parent_dir(source) != parent_dir(target) {
+                return cannot(
+                    source,
+                    format!("source is not in the directory of the target {}", target),
+                );
+            }
+            let source_status = self.existing_status(source)?;
+            if !source_status.is_file() {
+                return cannot(source, "source is not a file".to_owned());
+            }
+            if source_status.len() == 0 {
+                return cannot(source, "source is empty".to_owned());
+            }
+            if source_status.block_size() > block_size {
+                return cannot(
+                    source,
+                    format!(
+                        "block size {} is larger than the block size {} of the target",
+                        source_status.block_size(),
+                        block_size
+                    ),
+                );
+            }
+            seen.push(source);
+        }
+        Ok(())
+    }
+
+    /// The status of a path, failing with [`HdfsErr::FileNotFound`] if it does not exist.
+    fn existing_status(&self, path: &HdfsPath) -> Result<FileStatus, HdfsErr> {
+        let ptr = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsGetPathInfo(self.raw().as_ptr(), cstr_path.as_ptr())
+        };
+        if !ptr.is_null() {
+            Ok(FileStatus::new(ptr))
+        } else if std::io::Error::last_os_error().raw_os_error() == Some(libc::ENOENT) {
+            Err(HdfsErr::FileNotFound(path.to_string()))
+        } else {
+            Err(path_error(
+                path,
+                format!("Could not get file status for {}", path),
+            ))
+        }
+    }
+
+    /// Start recovery of the lease on a file whose writer has died, so that the namenode
+    /// closes it and others may append to it. Returns `true` if the file is already closed,
+    /// otherwise recovery runs in the background; see [`HdfsFs::wait_for_close`].
+    pub fn recover_lease(&self, path: impl AsRef<HdfsPath>) -> Result<bool, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsRecoverLease(self.raw().as_ptr(), cstr_path.as_ptr())
+        };
+        lease_result(
+            path,
+            ret,
+            format!("Could not recover the lease on {}", path),
+        )
+    }
+
+    /// Is the file closed, i.e. not open for writing under a lease?
+    pub fn is_file_closed(&self, path: impl AsRef<HdfsPath>) -> Result<bool, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsIsFileClosed(self.raw().as_ptr(), cstr_path.as_ptr())
+        };
+        lease_result(
+            path,
+            ret,
+            format!("Could not check whether {} is closed", path),
+        )
+    }
+
+    /// Wait for a file to be closed, e.g. after [`HdfsFs::recover_lease`], polling with
+    /// exponential backoff. Returns `false` if the file is still open after the timeout.
+    pub fn wait_for_close(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        timeout: Duration,
+    ) -> Result<bool, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let deadline = Instant::now() + timeout;
+        let mut backoff = Duration::from_millis(100);
+        loop {
+            if self.is_file_closed(path)? {
+                return Ok(true);
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(false);
+            }
+            debug!("{} is still open, checking again in {:?}", path, backoff);
+            std::thread::sleep(backoff.min(remaining));
+            backoff = (backoff * 2).min(Duration::from_secs(5));
+        }
+    }
+
+    /// Truncate a file to `new_len` bytes, which must not be more than its length.
+    ///
+    /// Returns `true` if the new length falls inside a block, which the datanodes then
+    /// recover in the background. The file stays open until recovery finishes, so it cannot
+    /// be appended to before then; see [`HdfsFs::truncate_and_wait`].
+    pub fn truncate(&self, path: impl AsRef<HdfsPath>, new_len: u64) -> Result<bool, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        if new_len > tOffset::MAX as u64 {
+            return Err(HdfsErr::Miscellaneous(format!(
+                "Cannot truncate {} to {} bytes, more than any file can hold",
+                path, new_len
+            )));
+        }
+        let mut should_wait: c_int = 0;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsTruncate(
+                self.raw().as_ptr(),
+                cstr_path.as_ptr(),
+                new_len as tOffset,
+                &mut should_wait,
+            )
+        };
+        if ret == 0 {
+            return Ok(should_wait != 0);
+        }
+        match std::io::Error::last_os_error().raw_os_error() {
+            Some(libc::ENOENT) => Err(HdfsErr::FileNotFound(path.to_string())),
+            // e.g. "Cannot truncate to a larger file size. Current size: 3, truncate size: 5."
+            Some(libc::EINVAL) => Err(HdfsErr::Miscellaneous(format!(
+                "Could not truncate {} to {} bytes: {}",
+                path,
+                new_len,
+                namenode_message(&last_error())
+            ))),
+            _ => Err(path_error(
+                path,
+                format!("Could not truncate {} to {} bytes", path, new_len),
+            )),
+        }
+    }
+
+    /// Truncate a file to `new_len` bytes and wait for any recovery of its last block to
+    /// finish, so that the file is closed again. Fails if it is still open after the timeout.
+    pub fn truncate_and_wait(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        new_len: u64,
+        timeout: Duration,
+    ) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        if self.truncate(path, new_len)? && !self.wait_for_close(path, timeout)? {
+            return Err(HdfsErr::Miscellaneous(format!(
+                "Block recovery of {} did not finish within {:?} of truncating it",
+                path, timeout
+            )));
         }
-        let file = unsafe {
-            let cstr_path = CString::new(path).unwrap();
-            hdfsOpenFile(
-                self.raw,
+        Ok(())
+    }
+
+    /// Make an empty directory an encryption zone, whose files are encrypted with keys
+    /// encrypted by a key in the KMS. Requires superuser privilege.
+    ///
+    /// * path - an existing, empty directory
+    /// * key_name - the name of the zone key, which must exist in the KMS
+    pub fn create_encryption_zone(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        key_name: &str,
+    ) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            let cstr_key_name = CString::new(key_name).map_err(|_| {
+                HdfsErr::InvalidConfig(format!("{:?}: key names cannot contain a NUL", key_name))
+            })?;
+            hdfsCreateEncryptionZone(
+                self.raw().as_ptr(),
                 cstr_path.as_ptr(),
-                O_WRONLY,
-                buf_size as c_int,
-                replica_num as c_short,
-                block_size as tOffset,
+                cstr_key_name.as_ptr(),
             )
         };
-        self.new_hdfs_file(path, file)
+        if ret == 0 {
+            return Ok(());
+        }
+        match std::io::Error::last_os_error().raw_os_error() {
+            Some(libc::ENOENT) => Err(HdfsErr::FileNotFound(path.to_string())),
+            Some(libc::EACCES) => Err(HdfsErr::AccessDenied(path.to_string(), FsAction::Write)),
+            _ => Err(path_error(
+                path,
+                format!(
+                    "Could not create encryption zone {} with key {}",
+                    path, key_name
+                ),
+            )),
+        }
     }
 
-    pub fn get_file_status(&self, path: &str) -> Result<FileStatus, HdfsErr> {
+    /// Get the encryption zone a path is in, `None` if it is not in one.
+    pub fn encryption_zone_for(
+        &self,
+        path: impl AsRef<HdfsPath>,
+    ) -> Result<Option<EncryptionZone>, HdfsErr> {
+        let path = &*self.qualify(path)?;
         let ptr = unsafe {
-            let cstr_path = CString::new(path).unwrap();
-            hdfsGetPathInfo(self.raw, cstr_path.as_ptr())
+            let cstr_path = path.to_c_string()?;
+            hdfsGetEZForPath(self.raw().as_ptr(), cstr_path.as_ptr())
         };
-        if ptr.is_null() {
-            Err(HdfsErr::Miscellaneous(format!(
-                "Could not get file status for {}",
-                path
-            )))
-        } else {
-            Ok(FileStatus::new(ptr))
+        if !ptr.is_null() {
+            let zone = unsafe { encryption_zone(&*ptr) };
+            unsafe { hdfsFreeEncryptionZoneInfo(ptr, 1) };
+            return Ok(Some(zone));
+        }
+        match std::io::Error::last_os_error().raw_os_error() {
+            // the namenode does not tell a path outside any zone from a missing one
+            Some(libc::ENOENT) if self.exist(path) => Ok(None),
+            Some(libc::ENOENT) => Err(HdfsErr::FileNotFound(path.to_string())),
+            _ => Err(path_error(
+                path,
+                format!("Could not get the encryption zone of {}", path),
+            )),
         }
     }
 
-    /// Delete the content at the given path.
+    /// Get all encryption zones, like `hdfs crypto -listZones`. Requires superuser
+    /// privilege.
     ///
-    /// * path - the path on the filesystem
-    /// * recursive - if true, delete the content recursively.
-    pub fn delete(&self, path: &str, recursive: bool) -> Result<bool, HdfsErr> {
-        let res = unsafe {
-            let cstr_path = CString::new(path).unwrap();
-            hdfsDelete(self.raw, cstr_path.as_ptr(), recursive as c_int)
+    /// The zones are all fetched before this returns, so a failure is reported here rather
+    /// than while iterating.
+    pub fn encryption_zones(&self) -> Result<impl Iterator<Item = EncryptionZone>, HdfsErr> {
+        let mut entry_num: c_int = -1;
+        let ptr = unsafe { hdfsListEncryptionZones(self.raw().as_ptr(), &mut entry_num) };
+        if ptr.is_null() {
+            return Err(HdfsErr::Miscellaneous(
+                "Could not list encryption zones".to_owned(),
+            ));
+        }
+        let zones: Vec<EncryptionZone> = (0..entry_num.max(0) as usize)
+            .map(|idx| unsafe { encryption_zone(&*ptr.add(idx)) })
+            .collect();
+        unsafe { hdfsFreeEncryptionZoneInfo(ptr, entry_num.max(0)) };
+        Ok(zones.into_iter())
+    }
+
+    /// Get the ACL of a file or directory, like `hdfs dfs -getfacl`.
+    pub fn acl_status(&self, path: impl AsRef<HdfsPath>) -> Result<AclStatus, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ptr = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsGetAclStatus(self.raw().as_ptr(), cstr_path.as_ptr())
         };
-        if res == 0 {
-            Ok(true)
-        } else {
-            Err(HdfsErr::Miscellaneous(format!(
-                "Could not delete path: {}",
-                path
-            )))
+        if ptr.is_null() {
+            return Err(namespace_error(
+                path,
+                FsAction::Execute,
+                format!("Could not get the ACL of {}", path),
+            ));
         }
+        let status = acl_status(unsafe { &*ptr });
+        unsafe { hdfsFreeAclStatus(ptr) };
+        Ok(status)
     }
 
-    /// Check if the given path exists on the filesystem
-    pub fn exist(&self, path: &str) -> bool {
-        (unsafe {
-            let cstr_path = CString::new(path).unwrap();
-            hdfsExists(self.raw, cstr_path.as_ptr())
-        } == 0)
+    /// Replace the ACL of a file or directory, like `hdfs dfs -setfacl --set`. The entries
+    /// must include those of the owner, group and others, e.g.
+    /// `user::rwx,group::r-x,other::---,user:alice:rw-`. Only the owner or the superuser
+    /// may change an ACL.
+    pub fn set_acl(&self, path: impl AsRef<HdfsPath>, entries: &[AclEntry]) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        self.change_acl(path, entries, hdfsSetAcl, "set the ACL")
     }
 
-    /// Get the file status of each entry under the specified path
-    /// Note that it is an error to list an empty directory.
-    pub fn list_status(&self, path: &str) -> Result<Vec<FileStatus>, HdfsErr> {
-        let mut entry_num: c_int = 0;
-        let ptr = unsafe {
-            let cstr_path = CString::new(path).unwrap();
-            hdfsListDirectory(self.raw, cstr_path.as_ptr(), &mut entry_num)
+    /// Add entries to the ACL of a file or directory, or update the permissions of its
+    /// entries of the same users and groups, like `hdfs dfs -setfacl -m`.
+    pub fn modify_acl_entries(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        entries: &[AclEntry],
+    ) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        self.change_acl(path, entries, hdfsModifyAclEntries, "modify the ACL")
+    }
+
+    /// Remove entries from the ACL of a file or directory, like `hdfs dfs -setfacl -x`. The
+    /// permissions of the entries are ignored.
+    pub fn remove_acl_entries(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        entries: &[AclEntry],
+    ) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        self.change_acl(
+            path,
+            entries,
+            hdfsRemoveAclEntries,
+            "remove entries of the ACL",
+        )
+    }
+
+    /// Remove the default entries of the ACL of a directory, like `hdfs dfs -setfacl -k`.
+    pub fn remove_default_acl(&self, path: impl AsRef<HdfsPath>) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsRemoveDefaultAcl(self.raw().as_ptr(), cstr_path.as_ptr())
         };
-        if ptr.is_null() {
-            Err(HdfsErr::Miscellaneous(format!(
-                "Could not list content of path: {}",
-                path
-            )))
+        if ret == 0 {
+            Ok(())
         } else {
-            let shared_ptr = Rc::new(HdfsFileInfoPtr::new_array(ptr, entry_num));
-
-            let list = (0..entry_num)
-                .into_iter()
-                .map(|idx| FileStatus::from_array(shared_ptr.clone(), idx as u32))
-                .collect::<Vec<FileStatus>>();
+            Err(owner_error(
+                path,
+                format!("Could not remove the default ACL of {}", path),
+            ))
+        }
+    }
 
-            Ok(list)
+    /// Remove all entries of the ACL of a file or directory except those of its permission
+    /// bits, like `hdfs dfs -setfacl -b`.
+    pub fn remove_acl(&self, path: impl AsRef<HdfsPath>) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsRemoveAcl(self.raw().as_ptr(), cstr_path.as_ptr())
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(owner_error(
+                path,
+                format!("Could not remove the ACL of {}", path),
+            ))
         }
     }
 
-    pub fn mkdir(&self, path: &str) -> Result<bool, HdfsErr> {
-        let res = unsafe {
-            let cstr_path = CString::new(path).unwrap();
-            hdfsCreateDirectory(self.raw, cstr_path.as_ptr())
+    /// Call a libhdfs3 function which changes the ACL of a path with entries.
+    fn change_acl(
+        &self,
+        path: &HdfsPath,
+        entries: &[AclEntry],
+        f: unsafe extern "C" fn(hdfsFS, *const c_char, *const hdfsAclEntry, c_int) -> c_int,
+        action: &str,
+    ) -> Result<(), HdfsErr> {
+        let spec = RawAclSpec::new(entries)?;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            f(
+                self.raw().as_ptr(),
+                cstr_path.as_ptr(),
+                spec.as_ptr(),
+                spec.len(),
+            )
         };
-        if res == 0 {
-            Ok(true)
+        if ret == 0 {
+            Ok(())
         } else {
-            Err(HdfsErr::Miscellaneous(format!(
-                "Could not create directory at path: {}",
-                path
-            )))
+            Err(owner_error(
+                path,
+                format!("Could not {} of {}", action, path),
+            ))
         }
     }
 
-    #[inline]
-    pub fn open(&self, path: &str) -> Result<HdfsFile, HdfsErr> {
-        self.open_with_buf_size(path, 0)
+    /// Get the value of an extended attribute of a file or directory by its full name, e.g.
+    /// `user.checksum`, like `hdfs dfs -getfattr -n`.
+    /// `XAttrNotFound` if the file does not have it, while an attribute set without a value
+    /// has an empty one.
+    pub fn get_xattr(&self, path: impl AsRef<HdfsPath>, name: &str) -> Result<Vec<u8>, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let mut values = self.get_xattrs(path, &[name])?;
+        values
+            .remove(&full_name(name)?)
+            .ok_or_else(|| HdfsErr::XAttrNotFound(path.to_string(), name.to_owned()))
     }
 
-    pub fn open_with_buf_size(&self, path: &str, buf_size: i32) -> Result<HdfsFile, HdfsErr> {
-        let file = unsafe {
-            let cstr_path = CString::new(path).unwrap();
-            hdfsOpenFile(
-                self.raw,
+    /// Get extended attributes of a file or directory with their values by full name, or all
+    /// of those the user may read if `names` is empty, like `hdfs dfs -getfattr -d`. Values
+    /// are binary; an attribute set without a value has an empty one.
+    pub fn get_xattrs(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        names: &[&str],
+    ) -> Result<BTreeMap<String, Vec<u8>>, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let raw_names = RawXAttrs::names(names)?;
+        let mut entry_num: c_int = -1;
+        let ptr = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsGetXAttrs(
+                self.raw().as_ptr(),
                 cstr_path.as_ptr(),
-                O_RDONLY,
-                buf_size as c_int,
-                0,
-                0,
+                if names.is_empty() {
+                    std::ptr::null()
+                } else {
+                    raw_names.as_ptr()
+                },
+                raw_names.len(),
+                &mut entry_num,
             )
         };
-        self.new_hdfs_file(path, file)
+        if ptr.is_null() && entry_num != 0 {
+            let error = namespace_error(
+                path,
+                FsAction::Read,
+                format!("Could not get extended attributes of {}", path),
+            );
+            return Err(self.xattr_not_found(path, names, error));
+        }
+        let values = (0..entry_num.max(0) as usize)
+            .map(|idx| xattr(unsafe { &*ptr.add(idx) }))
+            .collect();
+        unsafe { hdfsFreeXAttrs(ptr, entry_num.max(0)) };
+        Ok(values)
     }
 
-    pub fn open_for_writing(&self, path: &str) -> Result<HdfsFile, HdfsErr> {
-        let file = unsafe {
-            let cstr_path = CString::new(path).unwrap();
-            hdfsOpenFile(self.raw, cstr_path.as_ptr(), O_WRONLY, 0, 0, 0)
+    /// List the full names of the extended attributes of a file or directory which the user
+    /// may read, e.g. `user.checksum`.
+    pub fn list_xattrs(&self, path: impl AsRef<HdfsPath>) -> Result<Vec<String>, HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let mut entry_num: c_int = -1;
+        let ptr = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsListXAttrs(self.raw().as_ptr(), cstr_path.as_ptr(), &mut entry_num)
         };
-        self.new_hdfs_file(path, file)
+        if ptr.is_null() && entry_num != 0 {
+            return Err(namespace_error(
+                path,
+                FsAction::Read,
+                format!("Could not list extended attributes of {}", path),
+            ));
+        }
+        let names = (0..entry_num.max(0) as usize)
+            .map(|idx| xattr(unsafe { &*ptr.add(idx) }).0)
+            .collect();
+        unsafe { hdfsFreeXAttrs(ptr, entry_num.max(0)) };
+        Ok(names)
     }
 
-    fn new_hdfs_file(&self, path: &str, file: hdfsFile) -> Result<HdfsFile, HdfsErr> {
-        if file.is_null() {
-            Err(HdfsErr::Miscellaneous(format!(
-                "Could not open HDFS file at path {}",
-                path
-            )))
-        } else {
-            Ok(HdfsFile {
-                fs: self.clone(),
-                path: path.to_owned(),
-                file,
-                _market: PhantomData,
-            })
+    /// Set an extended attribute of a file or directory by its full name to a binary value,
+    /// like `hdfs dfs -setfattr`. The flags decide whether the attribute may be created,
+    /// replaced or either.
+    pub fn set_xattr(
+        &self,
+        path: impl AsRef<HdfsPath>,
+        name: &str,
+        value: &[u8],
+        flags: XAttrSetFlags,
+    ) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let raw = RawXAttrs::with_value(name, value)?;
+        let ret = unsafe {
+            let cstr_path = path.to_c_string()?;
+            hdfsSetXAttr(
+                self.raw().as_ptr(),
+                cstr_path.as_ptr(),
+                raw.as_ptr(),
+                flags.bits() as c_int,
+            )
+        };
+        if ret == 0 {
+            return Ok(());
         }
+        let error = namespace_error(
+            path,
+            FsAction::Write,
+            format!("Could not set extended attribute {} of {}", name, path),
+        );
+        Err(xattr_error(path, name, &last_error(), error))
     }
 
-    /// Rename a file
-    ///
-    /// old_path - the path to rename
-    /// new_path - the new name
-    ///
-    /// Note that the destination directory must exist.
-    pub fn rename(&self, old_path: &str, new_path: &str) -> Result<bool, HdfsErr> {
+    /// Remove an extended attribute of a file or directory by its full name, like
+    /// `hdfs dfs -setfattr -x`.
+    pub fn remove_xattr(&self, path: impl AsRef<HdfsPath>, name: &str) -> Result<(), HdfsErr> {
+        let path = &*self.qualify(path)?;
+        let raw = RawXAttrs::names(&[name])?;
         let ret = unsafe {
-            let cstr_old_path = CString::new(old_path).unwrap();
-            let cstr_new_path = CString::new(new_path).unwrap();
-            hdfsRename(self.raw, cstr_old_path.as_ptr(), cstr_new_path.as_ptr())
+            let cstr_path = path.to_c_string()?;
+            hdfsRemoveXAttr(self.raw().as_ptr(), cstr_path.as_ptr(), raw.as_ptr())
         };
         if ret == 0 {
-            Ok(true)
-        } else {
-            Err(HdfsErr::Miscellaneous(format!(
-                "Could not reanme {} to {}",
-                old_path, new_path
-            )))
+            return Ok(());
+        }
+        let error = namespace_error(
+            path,
+            FsAction::Write,
+            format!("Could not remove extended attribute {} of {}", name, path),
+        );
+        Err(xattr_error(path, name, &last_error(), error))
+    }
+
+    /// Turn the plain error of a failed get of extended attributes into `XAttrNotFound` if
+    /// one of the names is missing, since the message of the namenode does not name it.
+    fn xattr_not_found(&self, path: &HdfsPath, names: &[&str], error: HdfsErr) -> HdfsErr {
+        if !matches!(error, HdfsErr::Miscellaneous(_)) {
+            return error;
+        }
+        match self.list_xattrs(path) {
+            Ok(existing) => xattr_not_found_error(path, names, &existing, error),
+            Err(_) => error,
         }
     }
 }
 
+/// Options for [`HdfsFs::rename_with_options`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RenameOptions {
+    /// Replace `dst` if it is a file or an empty directory
+    pub overwrite: bool,
+    /// Create the parent directories of `dst` if they do not exist
+    pub create_parent: bool,
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// Safely deallocatable hdfsFileinfo pointer
@@ -358,11 +2431,28 @@ impl FileStatus {
         unsafe { self.raw.ptr.offset(self.idx as isize) }
     }
 
-    /// Get the name of the file
+    /// Get the path of the file, which HDFS does not require to be UTF-8
+    #[inline]
+    pub fn path(&self) -> &HdfsPath {
+        HdfsPath::new(self.name_bytes())
+    }
+
+    /// Get the name of the file, failing with `HdfsErr::InvalidUtf8` if it is not UTF-8
+    #[inline]
+    pub fn name(&self) -> Result<&str, HdfsErr> {
+        self.path().to_str()
+    }
+
+    /// Get the name of the file as it is stored by the namenode
+    #[inline]
+    pub fn name_bytes(&self) -> &[u8] {
+        unsafe { CStr::from_ptr((*self.ptr()).mName) }.to_bytes()
+    }
+
+    /// Get the name of the file, with invalid UTF-8 replaced by `U+FFFD`
     #[inline]
-    pub fn name(&self) -> &str {
-        let slice = unsafe { CStr::from_ptr((*self.ptr()).mName) }.to_bytes();
-        std::str::from_utf8(slice).unwrap()
+    pub fn name_lossy(&self) -> Cow<'_, str> {
+        self.path().to_string_lossy()
     }
 
     /// Is this a file?
@@ -371,6 +2461,7 @@ impl FileStatus {
         match unsafe { &*self.ptr() }.mKind {
             tObjectKind::kObjectKindFile => true,
             tObjectKind::kObjectKindDirectory => false,
+            tObjectKind::kObjectKindSymlink => false,
         }
     }
 
@@ -380,27 +2471,44 @@ impl FileStatus {
         match unsafe { &*self.ptr() }.mKind {
             tObjectKind::kObjectKindFile => false,
             tObjectKind::kObjectKindDirectory => true,
+            tObjectKind::kObjectKindSymlink => false,
+        }
+    }
+
+    /// Is this a symlink? Only the status returned by `HdfsFs::symlink_metadata` or
+    /// `HdfsFs::list_status` can be one, as `HdfsFs::get_file_status` resolves symlinks.
+    #[inline]
+    pub fn is_symlink(&self) -> bool {
+        unsafe { &*self.ptr() }.mKind == tObjectKind::kObjectKindSymlink
+    }
+
+    /// Get the target of a symlink, `None` if this is not one
+    #[inline]
+    pub fn symlink_target(&self) -> Option<&HdfsPath> {
+        let ptr = unsafe { &*self.ptr() }.mSymlink;
+        if ptr.is_null() {
+            None
+        } else {
+            Some(HdfsPath::new(unsafe { CStr::from_ptr(ptr) }.to_bytes()))
         }
     }
 
-    /// Get the owner of the file
+    /// Get the owner of the file, with invalid UTF-8 replaced by `U+FFFD`
     #[inline]
-    pub fn owner(&self) -> &str {
-        let slice = unsafe { CStr::from_ptr((*self.ptr()).mOwner) }.to_bytes();
-        std::str::from_utf8(slice).unwrap()
+    pub fn owner(&self) -> Cow<'_, str> {
+        unsafe { CStr::from_ptr((*self.ptr()).mOwner) }.to_string_lossy()
     }
 
-    /// Get the group associated with the file
+    /// Get the group associated with the file, with invalid UTF-8 replaced by `U+FFFD`
     #[inline]
-    pub fn group(&self) -> &str {
-        let slice = unsafe { CStr::from_ptr((*self.ptr()).mGroup) }.to_bytes();
-        std::str::from_utf8(slice).unwrap()
+    pub fn group(&self) -> Cow<'_, str> {
+        unsafe { CStr::from_ptr((*self.ptr()).mGroup) }.to_string_lossy()
     }
 
     /// Get the permissions associated with the file
     #[inline]
     pub fn permission(&self) -> i16 {
-        unsafe { &*self.ptr() }.mPermissions as i16
+        unsafe { &*self.ptr() }.mPermissions
     }
 
     /// Get the length of this file, in bytes.
@@ -419,7 +2527,7 @@ impl FileStatus {
     /// Get the replication factor of a file.
     #[inline]
     pub fn replica_count(&self) -> i16 {
-        unsafe { &*self.ptr() }.mReplication as i16
+        unsafe { &*self.ptr() }.mReplication
     }
 
     /// Get the last modification time for the file in seconds
@@ -433,6 +2541,17 @@ impl FileStatus {
     pub fn last_access(&self) -> time_t {
         unsafe { &*self.ptr() }.mLastAccess
     }
+
+    /// Get how the file is encrypted, `None` if it is not in an encryption zone
+    #[inline]
+    pub fn encryption_info(&self) -> Option<FileEncryptionInfo> {
+        let ptr = unsafe { &*self.ptr() }.mHdfsEncryptionFileInfo;
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { file_encryption_info(&*ptr) })
+        }
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -441,8 +2560,13 @@ impl FileStatus {
 #[derive(Clone)]
 pub struct HdfsFile {
     fs: HdfsFs,
-    path: String,
+    /// The handle the file was opened with, which stays connected while the file is open
+    raw: Arc<RawFs>,
+    path: HdfsPathBuf,
     file: hdfsFile,
+    /// The cipher of a file in an encryption zone, which its data is read and written with,
+    /// looked up when the file is opened
+    cipher: Option<CtrCipher>,
     _market: PhantomData<()>,
 }
 impl std::fmt::Debug for HdfsFile {
@@ -450,6 +2574,7 @@ impl std::fmt::Debug for HdfsFile {
         f.debug_struct("HdfsFile")
             .field("connection_properties", &self.fs.connection_properties)
             .field("path", &self.path)
+            .field("encrypted", &self.cipher.is_some())
             .finish()
     }
 }
@@ -461,13 +2586,13 @@ impl HdfsFile {
     }
 
     #[inline]
-    pub fn path(&self) -> &str {
+    pub fn path(&self) -> &HdfsPath {
         &self.path
     }
 
     ///  Number of bytes that can be read from this file without blocking.
     pub fn available(&self) -> Result<i32, HdfsErr> {
-        let ret = unsafe { hdfsAvailable(self.fs.raw, self.file) };
+        let ret = unsafe { hdfsAvailable(self.raw.as_ptr(), self.file) };
         if ret < 0 {
             Err(HdfsErr::Miscellaneous(format!(
                 "Could not determine HDFS availability for {}",
@@ -480,7 +2605,7 @@ impl HdfsFile {
 
     /// Close the opened file
     pub fn close(&self) -> Result<bool, HdfsErr> {
-        if unsafe { hdfsCloseFile(self.fs.raw, self.file) } == 0 {
+        if unsafe { hdfsCloseFile(self.raw.as_ptr(), self.file) } == 0 {
             Ok(true)
         } else {
             Err(HdfsErr::Miscellaneous(format!(
@@ -495,17 +2620,44 @@ impl HdfsFile {
         self.fs.get_file_status(self.path())
     }
 
+    /// Is the file in an encryption zone? Its data is then decrypted when read and encrypted
+    /// when written. Always `false` if transparent encryption is disabled.
+    pub fn is_encrypted(&self) -> bool {
+        self.cipher.is_some()
+    }
+
+    /// The current offset in the file, in bytes
+    pub fn tell(&self) -> Result<u64, HdfsErr> {
+        let pos = unsafe { hdfsTell(self.raw.as_ptr(), self.file) };
+        if pos < 0 {
+            Err(HdfsErr::Miscellaneous(format!(
+                "Could not get the offset in {}",
+                self.path
+            )))
+        } else {
+            Ok(pos as u64)
+        }
+    }
+
     /// Read data from an open file
     pub fn read(&self, buf: &mut [u8]) -> Result<i32, HdfsErr> {
+        let cipher = self.cipher.as_ref();
+        let pos = match cipher {
+            Some(_) => self.tell()?,
+            None => 0,
+        };
         let read_len = unsafe {
             hdfsRead(
-                self.fs.raw,
+                self.raw.as_ptr(),
                 self.file,
                 buf.as_ptr() as *mut c_void,
                 buf.len() as tSize,
             )
         };
         if read_len > 0 {
+            if let Some(cipher) = cipher {
+                cipher.apply_keystream(pos, &mut buf[..read_len as usize]);
+            }
             Ok(read_len as i32)
         } else {
             Err(HdfsErr::Miscellaneous(format!(
@@ -515,15 +2667,50 @@ impl HdfsFile {
         }
     }
 
+    /// Read data from a given offset in the file, without changing the current offset
+    pub fn read_at(&self, pos: u64, buf: &mut [u8]) -> Result<i32, HdfsErr> {
+        let cipher = self.cipher.as_ref();
+        let read_len = unsafe {
+            hdfsPread(
+                self.raw.as_ptr(),
+                self.file,
+                pos as tOffset,
+                buf.as_mut_ptr() as *mut c_void,
+                buf.len() as tSize,
+            )
+        };
+        if read_len > 0 {
+            if let Some(cipher) = cipher {
+                cipher.apply_keystream(pos, &mut buf[..read_len as usize]);
+            }
+            Ok(read_len as i32)
+        } else {
+            Err(HdfsErr::Miscellaneous(format!(
+                "Failed to read from {} at {}",
+                self.path, pos
+            )))
+        }
+    }
+
     /// Seek to given offset in file.
     pub fn seek(&self, offset: u64) -> bool {
-        (unsafe { hdfsSeek(self.fs.raw, self.file, offset as tOffset) }) == 0
+        (unsafe { hdfsSeek(self.raw.as_ptr(), self.file, offset as tOffset) }) == 0
     }
 
     pub fn write(&self, buf: &[u8]) -> Result<i32, HdfsErr> {
+        let encrypted;
+        let buf = match &self.cipher {
+            Some(cipher) => {
+                let mut data = buf.to_vec();
+                cipher.apply_keystream(self.tell()?, &mut data);
+                encrypted = data;
+                &encrypted[..]
+            }
+            None => buf,
+        };
         let written_len = unsafe {
             hdfsWrite(
-                self.fs.raw,
+                self.raw.as_ptr(),
                 self.file,
                 buf.as_ptr() as *mut c_void,
                 buf.len() as tSize,
@@ -542,32 +2729,72 @@ impl HdfsFile {
 
 // -------------------------------------------------------------------------------------------------
 
-/// Create an instance of hdfsFs.
+/// Build the HDFS client side configuration of a connection.
 ///
 /// * connection_properties - Namenode connection parameters
+/// * client_config - optional typed client configuration
 /// * hdfs_params - optional key value pairs that need to be passed to configure
-///   the HDFS client side
-fn create_hdfs_fs(
-    connection_properties: ConnectionProperties,
+///   the HDFS client side. These take precedence over `client_config`, which in turn
+///   takes precedence over the generated HA nameservice settings.
+fn merge_hdfs_params(
+    connection_properties: &ConnectionProperties,
+    client_config: Option<&ClientConfig>,
     hdfs_params: HashMap<String, String>,
-) -> Result<hdfsFS, HdfsErr> {
+) -> Result<HashMap<String, String>, HdfsErr> {
+    connection_properties.validate()?;
+    let mut params = connection_properties.to_hdfs_params();
+    if let Some(client_config) = client_config {
+        client_config.validate()?;
+        debug!("HDFS client configuration: {:?}", client_config);
+        params.extend(client_config.to_hdfs_params());
+    }
+    params.extend(hdfs_params);
+    Ok(params)
+}
+
+/// Create an instance of hdfsFs.
+///
+/// * connection_properties - Namenode connection parameters
+/// * params - HDFS client side configuration, see [`merge_hdfs_params`]
+/// * delegation_token - the token to authenticate with, if any
+fn create_hdfs_fs(
+    connection_properties: &ConnectionProperties,
+    params: &HashMap<String, String>,
+    delegation_token: Option<&DelegationToken>,
+) -> Result<RawFs, HdfsErr> {
     let hdfs_fs = unsafe {
         let hdfs_builder = hdfsNewBuilder();
 
         let cstr_host = CString::new(connection_properties.namenode_host.as_bytes()).unwrap();
-        for (k, v) in hdfs_params {
-            let cstr_k = CString::new(k).unwrap();
-            let cstr_v = CString::new(v).unwrap();
+        for (k, v) in params {
+            let cstr_k = CString::new(k.as_str()).unwrap();
+            let cstr_v = CString::new(v.as_str()).unwrap();
             hdfsBuilderConfSetStr(hdfs_builder, cstr_k.as_ptr(), cstr_v.as_ptr());
         }
         hdfsBuilderSetNameNode(hdfs_builder, cstr_host.as_ptr());
         hdfsBuilderSetNameNodePort(hdfs_builder, connection_properties.namenode_port);
 
+        if let Some(token) = delegation_token {
+            if connection_properties.namenode_user.is_some() {
+                hdfsFreeBuilder(hdfs_builder);
+                return Err(HdfsErr::InvalidConfig(
+                    "namenode_user: must not be set with a delegation token".to_owned(),
+                ));
+            }
+            let cstr_token = CString::new(token.as_str()).unwrap();
+            hdfsBuilderSetToken(hdfs_builder, cstr_token.as_ptr());
+        }
+
         if let Some(user) = connection_properties.namenode_user.clone() {
             let cstr_user = CString::new(user.as_bytes()).unwrap();
             hdfsBuilderSetUserName(hdfs_builder, cstr_user.as_ptr());
         }
 
+        if let Some(proxy_user) = connection_properties.proxy_user.clone() {
+            let cstr_proxy_user = CString::new(proxy_user.as_bytes()).unwrap();
+            hdfsBuilderSetProxyUser(hdfs_builder, cstr_proxy_user.as_ptr());
+        }
+
         if let Some(kerb_ticket_cache_path) =
             connection_properties.kerberos_ticket_cache_path.clone()
         {
@@ -576,23 +2803,276 @@ fn create_hdfs_fs(
             hdfsBuilderSetKerbTicketCachePath(hdfs_builder, cstr_kerb_ticket_cache_path.as_ptr());
         }
 
-        info!(
-            "Connecting to Namenode, host: {}, port: {}, user: {:?}, krb_ticket_cache: {:?}",
-            connection_properties.namenode_host,
-            connection_properties.namenode_port,
-            connection_properties.namenode_user,
-            connection_properties.kerberos_ticket_cache_path
-        );
+        if connection_properties.is_nameservice() {
+            info!(
+                "Connecting to nameservice: {}, namenodes: {:?}, user: {:?}, proxy_user: {:?}, krb_ticket_cache: {:?}",
+                connection_properties.namenode_host,
+                connection_properties.namenodes,
+                connection_properties.namenode_user,
+                connection_properties.proxy_user,
+                connection_properties.kerberos_ticket_cache_path
+            );
+        } else {
+            info!(
+                "Connecting to Namenode, host: {}, port: {}, user: {:?}, proxy_user: {:?}, krb_ticket_cache: {:?}",
+                connection_properties.namenode_host,
+                connection_properties.namenode_port,
+                connection_properties.namenode_user,
+                connection_properties.proxy_user,
+                connection_properties.kerberos_ticket_cache_path
+            );
+        }
 
         hdfsBuilderConnect(hdfs_builder)
     };
 
     if hdfs_fs.is_null() {
-        Err(HdfsErr::CannotConnectToNameNode(format!(
+        return Err(HdfsErr::CannotConnectToNameNode(format!(
             "{}:{}",
             connection_properties.namenode_host, connection_properties.namenode_port
-        )))
+        )));
+    }
+
+    if connection_properties.is_nameservice() {
+        report_active_namenode(connection_properties, hdfs_fs);
+    }
+
+    Ok(RawFs(hdfs_fs))
+}
+
+/// The Kerberos ticket cache file used by a connection, if it is Kerberos authenticated
+/// without a delegation token.
+fn kerberos_ticket_cache(
+    connection_properties: &ConnectionProperties,
+    params: &HashMap<String, String>,
+    has_delegation_token: bool,
+) -> Option<PathBuf> {
+    let kerberos = connection_properties.kerberos_ticket_cache_path.is_some()
+        || params
+            .get("hadoop.security.authentication")
+            .is_some_and(|auth| auth.eq_ignore_ascii_case("kerberos"));
+    if !kerberos || has_delegation_token {
+        return None;
+    }
+    ticket_cache_path(connection_properties.kerberos_ticket_cache_path.as_deref())
+}
+
+/// Find the HDFS delegation token of the connection in the token storage file given by
+/// `HADOOP_TOKEN_FILE_LOCATION`, as set up by YARN for its containers. A token is only
+/// looked up when no user or proxy user is set, since the user is taken from the token.
+fn token_from_env(connection_properties: &ConnectionProperties) -> Option<DelegationToken> {
+    if connection_properties.namenode_user.is_some() || connection_properties.proxy_user.is_some() {
+        return None;
+    }
+    match Credentials::from_env() {
+        Ok(Some(credentials)) => {
+            let token = credentials.hdfs_token(connection_properties);
+            match token {
+                Some(token) => info!("Using HDFS delegation token for {}", token.service),
+                None => debug!(
+                    "No HDFS delegation token for {} in {}",
+                    connection_properties.namenode_host, HADOOP_TOKEN_FILE_LOCATION
+                ),
+            }
+            token.map(Token::to_delegation_token)
+        }
+        Ok(None) => None,
+        Err(e) => {
+            warn!("Could not read HDFS delegation tokens: {}", e);
+            None
+        }
+    }
+}
+
+/// Log the active namenode of a nameservice connection.
+///
+/// The namenode proxy only fails over from a standby namenode when an RPC is rejected,
+/// so a cheap RPC is issued first to make sure the reported namenode is the active one.
+fn report_active_namenode(connection_properties: &ConnectionProperties, hdfs_fs: hdfsFS) {
+    unsafe {
+        let cstr_root = CString::new("/").unwrap();
+        hdfsExists(hdfs_fs, cstr_root.as_ptr());
+    }
+    match get_active_namenode(hdfs_fs) {
+        Ok(active) => info!(
+            "Connected to nameservice {}, active namenode: {}",
+            connection_properties.namenode_host, active
+        ),
+        Err(e) => warn!(
+            "Could not determine the active namenode of nameservice {}: {}",
+            connection_properties.namenode_host, e
+        ),
+    }
+}
+
+/// Get the RPC address of the namenode currently serving requests for the given hdfsFS.
+fn get_active_namenode(hdfs_fs: hdfsFS) -> Result<String, HdfsErr> {
+    let mut buf = vec![0 as c_char; 1024];
+    let ptr = unsafe { hdfsGetActiveNamenode(hdfs_fs, buf.as_mut_ptr(), buf.len() as size_t) };
+    if ptr.is_null() {
+        Err(HdfsErr::Miscellaneous(
+            "Could not get the active namenode".to_owned(),
+        ))
     } else {
-        Ok(hdfs_fs)
+        Ok(unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned())
+    }
+}
+
+/// The error of a failed call on a path, `HdfsErr::UnresolvedLink` if the path contains a
+/// symlink, which libhdfs3 does not follow. It reads `errno`, so must be called straight after
+/// the failed call.
+fn path_error(path: &HdfsPath, message: String) -> HdfsErr {
+    if std::io::Error::last_os_error().raw_os_error() == Some(libc::ENOLINK) {
+        HdfsErr::UnresolvedLink(path.to_string())
+    } else {
+        HdfsErr::Miscellaneous(message)
+    }
+}
+
+/// The error of a failed namespace call on a path, such as one on its ACL or extended
+/// attributes. It reads `errno`, so must be called straight after the failed call.
+fn namespace_error(path: &HdfsPath, action: FsAction, message: String) -> HdfsErr {
+    match std::io::Error::last_os_error().raw_os_error() {
+        Some(libc::ENOENT) => HdfsErr::FileNotFound(path.to_string()),
+        Some(libc::EACCES) => HdfsErr::AccessDenied(path.to_string(), action),
+        _ => path_error(path, message),
+    }
+}
+
+/// The error of a failed call which only the owner of a path or the superuser may make, such
+/// as changing its ACL. An access denied is `NotOwner` unless it was denied on the way to
+/// the path. It reads `errno`, so must be called straight after the failed call.
+fn owner_error(path: &HdfsPath, message: String) -> HdfsErr {
+    match std::io::Error::last_os_error().raw_os_error() {
+        Some(libc::EACCES) => {
+            let error = last_error();
+            match parse_access_denied(&error) {
+                Some((path, action)) => HdfsErr::AccessDenied(path, action),
+                None => {
+                    HdfsErr::NotOwner(parse_not_owner(&error).unwrap_or_else(|| path.to_string()))
+                }
+            }
+        }
+        _ => namespace_error(path, FsAction::Write, message),
+    }
+}
+
+/// The message of the last failed libhdfs3 call on this thread, which is that of the
+/// namenode for a failed RPC.
+pub(crate) fn last_error() -> String {
+    let ptr = unsafe { hdfsGetLastError() };
+    if ptr.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// The message of a namenode exception without its class name and stack trace, e.g.
+/// `concat: source file /a is invalid or empty or underConstruction`.
+fn namenode_message(message: &str) -> &str {
+    let line = message.lines().next().unwrap_or_default();
+    match line.split_once(": ") {
+        Some((class, rest)) if class.contains('.') && !class.contains(' ') => rest,
+        _ => line,
+    }
+}
+
+/// An access denied by the namenode, naming the path and the action its message gives, or
+/// else `path` and `action`. It must be called straight after the failed call.
+fn access_denied(path: &HdfsPath, action: FsAction) -> HdfsErr {
+    match parse_access_denied(&last_error()) {
+        Some((path, action)) => HdfsErr::AccessDenied(path, action),
+        None => HdfsErr::AccessDenied(path.to_string(), action),
+    }
+}
+
+/// Map the `errno` and the message of a failed rename to an error naming the path at fault,
+/// without asking the namenode again, as the paths may have changed since. It must be
+/// called straight after the rename.
+fn rename_error(src: &HdfsPath, dst: &HdfsPath) -> HdfsErr {
+    let errno = std::io::Error::last_os_error().raw_os_error();
+    let parent = parent_dir(dst);
+    match errno {
+        // "rename destination parent /a/b not found."
+        Some(libc::ENOENT) if last_error().contains("destination parent") => {
+            HdfsErr::FileNotFound(parent.to_string())
+        }
+        Some(libc::ENOENT) => HdfsErr::FileNotFound(src.to_string()),
+        Some(libc::EEXIST) => HdfsErr::FileAlreadyExists(dst.to_string()),
+        Some(libc::ENOTDIR) => HdfsErr::NotADirectory(parent.to_string()),
+        Some(libc::EDQUOT) | Some(libc::ENOSPC) => HdfsErr::QuotaExceeded(parent.to_string()),
+        Some(libc::EACCES) => {
+            let message = last_error();
+            match parse_access_denied(&message) {
+                Some((path, action)) => HdfsErr::AccessDenied(path, action),
+                // either parent may have denied the write
+                None => HdfsErr::Miscellaneous(format!(
+                    "Could not rename {} to {}, permission denied on {} or {}: {}",
+                    src,
+                    dst,
+                    parent_dir(src),
+                    parent,
+                    namenode_message(&message)
+                )),
+            }
+        }
+        // the message of an UnresolvedPathException is the path being resolved
+        Some(libc::ENOLINK) => {
+            let message = last_error();
+            let message = namenode_message(&message);
+            if mentions_path(message, dst) {
+                HdfsErr::UnresolvedLink(dst.to_string())
+            } else if mentions_path(message, src) {
+                HdfsErr::UnresolvedLink(src.to_string())
+            } else {
+                HdfsErr::Miscellaneous(format!(
+                    "Could not rename {} to {}, a symlink is in the way of either: {}",
+                    src, dst, message
+                ))
+            }
+        }
+        _ => HdfsErr::Miscellaneous(format!("Could not rename {} to {}", src, dst)),
+    }
+}
+
+/// Whether a namenode message names `path` as a whole word, so that `/a/b` does not match
+/// a message about `/a/b2`.
+fn mentions_path(message: &str, path: &HdfsPath) -> bool {
+    let path = path.to_string();
+    message
+        .split(|c: char| c.is_whitespace() || c == '"' || c == '\'' || c == ',')
+        .any(|word| word.trim_end_matches(['.', ':']) == path)
+}
+
+/// Map the `errno` of failing to create the parent directory of a rename destination.
+fn create_parent_error(parent: &HdfsPath) -> HdfsErr {
+    match std::io::Error::last_os_error().raw_os_error() {
+        // a file is in the way
+        Some(libc::EEXIST) | Some(libc::ENOTDIR) => HdfsErr::NotADirectory(parent.to_string()),
+        Some(libc::EDQUOT) | Some(libc::ENOSPC) => HdfsErr::QuotaExceeded(parent.to_string()),
+        Some(libc::EACCES) => access_denied(parent, FsAction::Write),
+        Some(libc::ENOLINK) => HdfsErr::UnresolvedLink(parent.to_string()),
+        _ => HdfsErr::Miscellaneous(format!("Could not create directory {}", parent)),
+    }
+}
+
+/// The parent directory of a path, `/` for a top-level path and `.` for a relative file name
+fn parent_dir(path: &HdfsPath) -> &HdfsPath {
+    path.parent().unwrap_or_else(|| HdfsPath::new("."))
+}
+
+/// The result of a lease call which returns 1 for true, 0 for false and -1 on error
+fn lease_result(path: &HdfsPath, ret: c_int, message: String) -> Result<bool, HdfsErr> {
+    match ret {
+        -1 if std::io::Error::last_os_error().raw_os_error() == Some(libc::ENOENT) => {
+            Err(HdfsErr::FileNotFound(path.to_string()))
+        }
+        -1 => Err(path_error(path, message)),
+        ret => Ok(ret == 1),
     }
 }
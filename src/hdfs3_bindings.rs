@@ -22,6 +22,7 @@ pub type tPort = u16;
 pub enum tObjectKind {
     kObjectKindFile = 70,
     kObjectKindDirectory = 68,
+    kObjectKindSymlink = 76,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -167,6 +168,18 @@ extern "C" {
         kerbTicketCachePath: *const ::std::os::raw::c_char,
     );
 }
+extern "C" {
+    #[doc = " Set the user to impersonate, like Hadoop's UserGroupInformation.createProxyUser."]
+    #[doc = " The connection authenticates as the user name or Kerberos principal of the"]
+    #[doc = " builder, and requests are executed as the proxy user. With Kerberos, the"]
+    #[doc = " authenticated user must be allowed to impersonate by the hadoop.proxyuser"]
+    #[doc = " settings of the namenode. With simple authentication no real user is sent,"]
+    #[doc = " so the connection just acts as the proxy user and nothing is checked."]
+    #[doc = ""]
+    #[doc = " @param bld The HDFS builder"]
+    #[doc = " @param proxyUser The user to impersonate.  The string will be shallow-copied."]
+    pub fn hdfsBuilderSetProxyUser(bld: *mut hdfsBuilder, proxyUser: *const ::std::os::raw::c_char);
+}
 extern "C" {
     #[doc = " Set the token used to authenticate"]
     #[doc = ""]
@@ -319,6 +332,26 @@ extern "C" {
         length: tSize,
     ) -> tSize;
 }
+extern "C" {
+    #[doc = " hdfsPread - Positional read of data from an open file."]
+    #[doc = " The current offset in the file is left unchanged."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param file The file handle."]
+    #[doc = " @param position Position from which to read"]
+    #[doc = " @param buffer The buffer to copy read bytes into."]
+    #[doc = " @param length The length of the buffer."]
+    #[doc = " @return      On success, a positive number indicating how many bytes"]
+    #[doc = "              were read."]
+    #[doc = "              On end-of-file, 0."]
+    #[doc = "              On error, -1.  Errno will be set to the error code."]
+    pub fn hdfsPread(
+        fs: hdfsFS,
+        file: hdfsFile,
+        position: tOffset,
+        buffer: *mut ::std::os::raw::c_void,
+        length: tSize,
+    ) -> tSize;
+}
 extern "C" {
     #[doc = " hdfsWrite - Write data into an open file."]
     #[doc = " @param fs The configured filesystem handle."]
@@ -418,6 +451,60 @@ extern "C" {
         newPath: *const ::std::os::raw::c_char,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    #[doc = " hdfsRename2 - Rename file, atomically replacing the destination."]
+    #[doc = " Fails if the parent of newPath does not exist."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param oldPath The path of the source file."]
+    #[doc = " @param newPath The path of the destination file."]
+    #[doc = " @param overwrite Replace newPath if it is a file or an empty directory;"]
+    #[doc = " otherwise the rename fails with EEXIST if it exists."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsRename2(
+        fs: hdfsFS,
+        oldPath: *const ::std::os::raw::c_char,
+        newPath: *const ::std::os::raw::c_char,
+        overwrite: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsConcat - Move the blocks of the source files to the end of the"]
+    #[doc = " target file and delete the source files, without copying any data."]
+    #[doc = " The source files must have the same block size and replication as"]
+    #[doc = " the target file."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param trg The path of the target file."]
+    #[doc = " @param srcs A NULL terminated array of the paths of the source files."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsConcat(
+        fs: hdfsFS,
+        trg: *const ::std::os::raw::c_char,
+        srcs: *mut *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsRecoverLease - Start recovery of the lease on a file left open by"]
+    #[doc = " a writer which has died, so that the file can be closed."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the file."]
+    #[doc = " @return Returns 1 if the file is already closed, 0 if lease recovery"]
+    #[doc = " has started, -1 on error."]
+    pub fn hdfsRecoverLease(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsIsFileClosed - Check whether a file is closed."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the file."]
+    #[doc = " @return Returns 1 if the file is closed, 0 if it is open for writing,"]
+    #[doc = " -1 on error."]
+    pub fn hdfsIsFileClosed(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
 extern "C" {
     #[doc = " hdfsGetWorkingDirectory - Get the current working directory for"]
     #[doc = " the given filesystem."]
@@ -431,6 +518,19 @@ extern "C" {
         bufferSize: size_t,
     ) -> *mut ::std::os::raw::c_char;
 }
+extern "C" {
+    #[doc = " hdfsGetHomeDirectory - Get the home directory of the effective user,"]
+    #[doc = " which is the working directory of a new filesystem handle."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param buffer The user-buffer to copy path of the home directory into."]
+    #[doc = " @param bufferSize The length of user-buffer."]
+    #[doc = " @return Returns buffer, NULL on error."]
+    pub fn hdfsGetHomeDirectory(
+        fs: hdfsFS,
+        buffer: *mut ::std::os::raw::c_char,
+        bufferSize: size_t,
+    ) -> *mut ::std::os::raw::c_char;
+}
 extern "C" {
     #[doc = " hdfsSetWorkingDirectory - Set the working directory. All relative"]
     #[doc = " paths will be resolved relative to it."]
@@ -551,12 +651,14 @@ pub struct hdfsEncryptionFileInfo {
     pub mKeyName: *mut ::std::os::raw::c_char,
     pub mIv: *mut ::std::os::raw::c_char,
     pub mEzKeyVersionName: *mut ::std::os::raw::c_char,
+    pub mKeyLength: ::std::os::raw::c_int,
+    pub mIvLength: ::std::os::raw::c_int,
 }
 #[test]
 fn bindgen_test_layout_hdfsEncryptionFileInfo() {
     assert_eq!(
         ::std::mem::size_of::<hdfsEncryptionFileInfo>(),
-        40usize,
+        48usize,
         concat!("Size of: ", stringify!(hdfsEncryptionFileInfo))
     );
     assert_eq!(
@@ -630,6 +732,26 @@ fn bindgen_test_layout_hdfsEncryptionFileInfo() {
             stringify!(mEzKeyVersionName)
         )
     );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsEncryptionFileInfo, mKeyLength),
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsEncryptionFileInfo),
+            "::",
+            stringify!(mKeyLength)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsEncryptionFileInfo, mIvLength),
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsEncryptionFileInfo),
+            "::",
+            stringify!(mIvLength)
+        )
+    );
 }
 #[doc = " hdfsFileInfo - Information about a file/directory."]
 #[repr(C)]
@@ -646,12 +768,13 @@ pub struct hdfsFileInfo {
     pub mPermissions: ::std::os::raw::c_short,
     pub mLastAccess: tTime,
     pub mHdfsEncryptionFileInfo: *mut hdfsEncryptionFileInfo,
+    pub mSymlink: *mut ::std::os::raw::c_char,
 }
 #[test]
 fn bindgen_test_layout_hdfsFileInfo() {
     assert_eq!(
         ::std::mem::size_of::<hdfsFileInfo>(),
-        88usize,
+        96usize,
         concat!("Size of: ", stringify!(hdfsFileInfo))
     );
     assert_eq!(
@@ -771,6 +894,16 @@ fn bindgen_test_layout_hdfsFileInfo() {
             stringify!(mHdfsEncryptionFileInfo)
         )
     );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsFileInfo, mSymlink),
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsFileInfo),
+            "::",
+            stringify!(mSymlink)
+        )
+    );
 }
 extern "C" {
     #[doc = " hdfsListDirectory - Get list of files/directories for a given"]
@@ -796,6 +929,49 @@ extern "C" {
     #[doc = " NULL on error."]
     pub fn hdfsGetPathInfo(fs: hdfsFS, path: *const ::std::os::raw::c_char) -> *mut hdfsFileInfo;
 }
+extern "C" {
+    #[doc = " hdfsGetPathLinkInfo - Get information about a path like hdfsGetPathInfo,"]
+    #[doc = " but of the symlink itself if the path is one. hdfsFreeFileInfo should be"]
+    #[doc = " called when the pointer is no longer needed."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the file."]
+    #[doc = " @return Returns a dynamically-allocated hdfsFileInfo object;"]
+    #[doc = " NULL on error."]
+    pub fn hdfsGetPathLinkInfo(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+    ) -> *mut hdfsFileInfo;
+}
+extern "C" {
+    #[doc = " hdfsCreateSymlink - Create a symlink to a file or directory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param target The path the link points to, which is not resolved or checked."]
+    #[doc = " @param link The path of the link."]
+    #[doc = " @param createParent Whether to create missing parent directories of the link."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsCreateSymlink(
+        fs: hdfsFS,
+        target: *const ::std::os::raw::c_char,
+        link: *const ::std::os::raw::c_char,
+        createParent: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsGetLinkTarget - Get the target of a symlink. hdfsFreeLinkTarget"]
+    #[doc = " should be called when the pointer is no longer needed."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the symlink."]
+    #[doc = " @return Returns a dynamically-allocated target path; NULL on error."]
+    pub fn hdfsGetLinkTarget(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    #[doc = " hdfsFreeLinkTarget - Free up the target path returned by hdfsGetLinkTarget"]
+    #[doc = " @param target The target path to be freed."]
+    pub fn hdfsFreeLinkTarget(target: *mut ::std::os::raw::c_char);
+}
 extern "C" {
     #[doc = " hdfsFreeFileInfo - Free up the hdfsFileInfo array (including fields)"]
     #[doc = " @param infos The array of dynamically-allocated hdfsFileInfo"]
@@ -859,354 +1035,1404 @@ extern "C" {
     #[doc = " @return Returns the total-size; -1 on error."]
     pub fn hdfsGetUsed(fs: hdfsFS) -> tOffset;
 }
-extern "C" {
-    #[doc = " Change the user and/or group of a file or directory."]
-    #[doc = ""]
-    #[doc = " @param fs            The configured filesystem handle."]
-    #[doc = " @param path          the path to the file or directory"]
-    #[doc = " @param owner         User string.  Set to NULL for 'no change'"]
-    #[doc = " @param group         Group string.  Set to NULL for 'no change'"]
-    #[doc = " @return              0 on success else -1"]
-    pub fn hdfsChown(
-        fs: hdfsFS,
-        path: *const ::std::os::raw::c_char,
-        owner: *const ::std::os::raw::c_char,
-        group: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[doc = " hdfsChmod"]
-    #[doc = " @param fs The configured filesystem handle."]
-    #[doc = " @param path the path to the file or directory"]
-    #[doc = " @param mode the bitmask to set it to"]
-    #[doc = " @return 0 on success else -1"]
-    pub fn hdfsChmod(
-        fs: hdfsFS,
-        path: *const ::std::os::raw::c_char,
-        mode: ::std::os::raw::c_short,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[doc = " hdfsUtime"]
-    #[doc = " @param fs The configured filesystem handle."]
-    #[doc = " @param path the path to the file or directory"]
-    #[doc = " @param mtime new modification time or -1 for no change"]
-    #[doc = " @param atime new access time or -1 for no change"]
-    #[doc = " @return 0 on success else -1"]
-    pub fn hdfsUtime(
-        fs: hdfsFS,
-        path: *const ::std::os::raw::c_char,
-        mtime: tTime,
-        atime: tTime,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[doc = " hdfsTruncate - Truncate the file in the indicated path to the indicated size."]
-    #[doc = " @param fs The configured filesystem handle."]
-    #[doc = " @param path the path to the file."]
-    #[doc = " @param pos the position the file will be truncated to."]
-    #[doc = " @param shouldWait output value, true if and client does not need to wait for block recovery,"]
-    #[doc = " false if client needs to wait for block recovery."]
-    pub fn hdfsTruncate(
-        fs: hdfsFS,
-        path: *const ::std::os::raw::c_char,
-        pos: tOffset,
-        shouldWait: *mut ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
-}
-extern "C" {
-    #[doc = " Get a delegation token from namenode."]
-    #[doc = " The token should be freed using hdfsFreeDelegationToken after canceling the token or token expired."]
-    #[doc = ""]
-    #[doc = " @param fs The file system"]
-    #[doc = " @param renewer The user who will renew the token"]
-    #[doc = ""]
-    #[doc = " @return Return a delegation token, NULL on error."]
-    pub fn hdfsGetDelegationToken(
-        fs: hdfsFS,
-        renewer: *const ::std::os::raw::c_char,
-    ) -> *mut ::std::os::raw::c_char;
-}
-extern "C" {
-    #[doc = " Free a delegation token."]
-    #[doc = ""]
-    #[doc = " @param token The token to be freed."]
-    pub fn hdfsFreeDelegationToken(token: *mut ::std::os::raw::c_char);
-}
-extern "C" {
-    #[doc = " Renew a delegation token."]
-    #[doc = ""]
-    #[doc = " @param fs The file system."]
-    #[doc = " @param token The token to be renewed."]
-    #[doc = ""]
-    #[doc = " @return the new expiration time"]
-    pub fn hdfsRenewDelegationToken(fs: hdfsFS, token: *const ::std::os::raw::c_char) -> i64;
-}
-extern "C" {
-    #[doc = " Cancel a delegation token."]
-    #[doc = ""]
-    #[doc = " @param fs The file system."]
-    #[doc = " @param token The token to be canceled."]
-    #[doc = ""]
-    #[doc = " @return return 0 on success, -1 on error."]
-    pub fn hdfsCancelDelegationToken(
-        fs: hdfsFS,
-        token: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
-}
+#[doc = " hdfsFsStats - Space usage and block health of the filesystem."]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct Namenode {
-    pub rpc_addr: *mut ::std::os::raw::c_char,
-    pub http_addr: *mut ::std::os::raw::c_char,
+pub struct hdfsFsStats {
+    pub mCapacity: tOffset,
+    pub mUsed: tOffset,
+    pub mRemaining: tOffset,
+    pub mUnderReplicated: i64,
+    pub mCorruptBlocks: i64,
+    pub mMissingBlocks: i64,
 }
 #[test]
-fn bindgen_test_layout_Namenode() {
+fn bindgen_test_layout_hdfsFsStats() {
     assert_eq!(
-        ::std::mem::size_of::<Namenode>(),
-        16usize,
-        concat!("Size of: ", stringify!(Namenode))
+        ::std::mem::size_of::<hdfsFsStats>(),
+        48usize,
+        concat!("Size of: ", stringify!(hdfsFsStats))
     );
     assert_eq!(
-        ::std::mem::align_of::<Namenode>(),
+        ::std::mem::align_of::<hdfsFsStats>(),
         8usize,
-        concat!("Alignment of ", stringify!(Namenode))
+        concat!("Alignment of ", stringify!(hdfsFsStats))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Namenode>())).rpc_addr as *const _ as usize },
+        ::std::mem::offset_of!(hdfsFsStats, mCapacity),
         0usize,
         concat!(
             "Offset of field: ",
-            stringify!(Namenode),
+            stringify!(hdfsFsStats),
             "::",
-            stringify!(rpc_addr)
+            stringify!(mCapacity)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Namenode>())).http_addr as *const _ as usize },
+        ::std::mem::offset_of!(hdfsFsStats, mUsed),
         8usize,
         concat!(
             "Offset of field: ",
-            stringify!(Namenode),
+            stringify!(hdfsFsStats),
             "::",
-            stringify!(http_addr)
+            stringify!(mUsed)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsFsStats, mRemaining),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsFsStats),
+            "::",
+            stringify!(mRemaining)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsFsStats, mUnderReplicated),
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsFsStats),
+            "::",
+            stringify!(mUnderReplicated)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsFsStats, mCorruptBlocks),
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsFsStats),
+            "::",
+            stringify!(mCorruptBlocks)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsFsStats, mMissingBlocks),
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsFsStats),
+            "::",
+            stringify!(mMissingBlocks)
         )
     );
 }
 extern "C" {
-    #[doc = " If hdfs is configured with HA namenode, return all namenode informations as an array."]
-    #[doc = " Else return NULL."]
-    #[doc = ""]
-    #[doc = " Using configure file which is given by environment parameter LIBHDFS3_CONF"]
-    #[doc = " or \"hdfs-client.xml\" in working directory."]
-    #[doc = ""]
-    #[doc = " @param nameservice hdfs name service id."]
-    #[doc = " @param size output the size of returning array."]
-    #[doc = ""]
-    #[doc = " @return return an array of all namenode information."]
-    pub fn hdfsGetHANamenodes(
+    #[doc = " hdfsGetFsStats - Get the space usage and block health of the filesystem."]
+    #[doc = " hdfsFreeFsStats should be called when the pointer is no longer needed."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @return Returns a dynamically-allocated hdfsFsStats object; NULL on error."]
+    pub fn hdfsGetFsStats(fs: hdfsFS) -> *mut hdfsFsStats;
+}
+extern "C" {
+    #[doc = " hdfsFreeFsStats - Free up the hdfsFsStats"]
+    #[doc = " @param stats The dynamically-allocated hdfsFsStats object."]
+    pub fn hdfsFreeFsStats(stats: *mut hdfsFsStats);
+}
+#[doc = " hdfsServerDefaults - The defaults the namenode applies to new files."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hdfsServerDefaults {
+    pub mBlockSize: tOffset,
+    pub mTrashInterval: i64,
+    pub mBytesPerChecksum: ::std::os::raw::c_int,
+    pub mWritePacketSize: ::std::os::raw::c_int,
+    pub mFileBufferSize: ::std::os::raw::c_int,
+    pub mEncryptDataTransfer: ::std::os::raw::c_int,
+    pub mChecksumType: ::std::os::raw::c_int,
+    pub mReplication: ::std::os::raw::c_short,
+}
+#[test]
+fn bindgen_test_layout_hdfsServerDefaults() {
+    assert_eq!(
+        ::std::mem::size_of::<hdfsServerDefaults>(),
+        40usize,
+        concat!("Size of: ", stringify!(hdfsServerDefaults))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<hdfsServerDefaults>(),
+        8usize,
+        concat!("Alignment of ", stringify!(hdfsServerDefaults))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsServerDefaults, mBlockSize),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsServerDefaults),
+            "::",
+            stringify!(mBlockSize)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsServerDefaults, mTrashInterval),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsServerDefaults),
+            "::",
+            stringify!(mTrashInterval)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsServerDefaults, mBytesPerChecksum),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsServerDefaults),
+            "::",
+            stringify!(mBytesPerChecksum)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsServerDefaults, mWritePacketSize),
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsServerDefaults),
+            "::",
+            stringify!(mWritePacketSize)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsServerDefaults, mFileBufferSize),
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsServerDefaults),
+            "::",
+            stringify!(mFileBufferSize)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsServerDefaults, mEncryptDataTransfer),
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsServerDefaults),
+            "::",
+            stringify!(mEncryptDataTransfer)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsServerDefaults, mChecksumType),
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsServerDefaults),
+            "::",
+            stringify!(mChecksumType)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsServerDefaults, mReplication),
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsServerDefaults),
+            "::",
+            stringify!(mReplication)
+        )
+    );
+}
+extern "C" {
+    #[doc = " hdfsGetServerDefaults - Get the defaults the namenode applies to new files."]
+    #[doc = " hdfsFreeServerDefaults should be called when the pointer is no longer needed."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @return Returns a dynamically-allocated hdfsServerDefaults object;"]
+    #[doc = " NULL on error."]
+    pub fn hdfsGetServerDefaults(fs: hdfsFS) -> *mut hdfsServerDefaults;
+}
+extern "C" {
+    #[doc = " hdfsFreeServerDefaults - Free up the hdfsServerDefaults"]
+    #[doc = " @param defaults The dynamically-allocated hdfsServerDefaults object."]
+    pub fn hdfsFreeServerDefaults(defaults: *mut hdfsServerDefaults);
+}
+#[doc = " hdfsContentSummary - The disk usage, file and directory counts and"]
+#[doc = " quotas of a directory tree."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hdfsContentSummary {
+    pub mLength: tOffset,
+    pub mFileCount: i64,
+    pub mDirectoryCount: i64,
+    pub mQuota: i64,
+    pub mSpaceConsumed: tOffset,
+    pub mSpaceQuota: tOffset,
+}
+#[test]
+fn bindgen_test_layout_hdfsContentSummary() {
+    assert_eq!(
+        ::std::mem::size_of::<hdfsContentSummary>(),
+        48usize,
+        concat!("Size of: ", stringify!(hdfsContentSummary))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<hdfsContentSummary>(),
+        8usize,
+        concat!("Alignment of ", stringify!(hdfsContentSummary))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsContentSummary, mLength),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsContentSummary),
+            "::",
+            stringify!(mLength)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsContentSummary, mFileCount),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsContentSummary),
+            "::",
+            stringify!(mFileCount)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsContentSummary, mDirectoryCount),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsContentSummary),
+            "::",
+            stringify!(mDirectoryCount)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsContentSummary, mQuota),
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsContentSummary),
+            "::",
+            stringify!(mQuota)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsContentSummary, mSpaceConsumed),
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsContentSummary),
+            "::",
+            stringify!(mSpaceConsumed)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsContentSummary, mSpaceQuota),
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsContentSummary),
+            "::",
+            stringify!(mSpaceQuota)
+        )
+    );
+}
+extern "C" {
+    #[doc = " hdfsGetContentSummary - Get the disk usage, file and directory counts"]
+    #[doc = " and quotas of a directory tree. hdfsFreeContentSummary should be called"]
+    #[doc = " when the pointer is no longer needed."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The root of the tree."]
+    #[doc = " @return Returns a dynamically-allocated hdfsContentSummary object;"]
+    #[doc = " NULL on error. errno is set to ENOTSUP if the namenode does not"]
+    #[doc = " support the call."]
+    pub fn hdfsGetContentSummary(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+    ) -> *mut hdfsContentSummary;
+}
+extern "C" {
+    #[doc = " hdfsFreeContentSummary - Free up the hdfsContentSummary"]
+    #[doc = " @param summary The dynamically-allocated hdfsContentSummary object."]
+    pub fn hdfsFreeContentSummary(summary: *mut hdfsContentSummary);
+}
+extern "C" {
+    #[doc = " hdfsSetQuota - Set the namespace and disk space quotas of a directory."]
+    #[doc = " Requires superuser privilege."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the directory."]
+    #[doc = " @param namespaceQuota The maximum number of files and directories in the"]
+    #[doc = " tree, HDFS_QUOTA_DONT_SET to leave it unchanged or HDFS_QUOTA_RESET to clear it."]
+    #[doc = " @param diskspaceQuota The maximum disk space in bytes consumed by the"]
+    #[doc = " tree, HDFS_QUOTA_DONT_SET to leave it unchanged or HDFS_QUOTA_RESET to clear it."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsSetQuota(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        namespaceQuota: i64,
+        diskspaceQuota: i64,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Change the user and/or group of a file or directory."]
+    #[doc = ""]
+    #[doc = " @param fs            The configured filesystem handle."]
+    #[doc = " @param path          the path to the file or directory"]
+    #[doc = " @param owner         User string.  Set to NULL for 'no change'"]
+    #[doc = " @param group         Group string.  Set to NULL for 'no change'"]
+    #[doc = " @return              0 on success else -1"]
+    pub fn hdfsChown(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        owner: *const ::std::os::raw::c_char,
+        group: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsChmod"]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path the path to the file or directory"]
+    #[doc = " @param mode the bitmask to set it to"]
+    #[doc = " @return 0 on success else -1"]
+    pub fn hdfsChmod(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        mode: ::std::os::raw::c_short,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsUtime"]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path the path to the file or directory"]
+    #[doc = " @param mtime new modification time or -1 for no change"]
+    #[doc = " @param atime new access time or -1 for no change"]
+    #[doc = " @return 0 on success else -1"]
+    pub fn hdfsUtime(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        mtime: tTime,
+        atime: tTime,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsTruncate - Truncate the file in the indicated path to the indicated size."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path the path to the file."]
+    #[doc = " @param pos the position the file will be truncated to."]
+    #[doc = " @param shouldWait output value, 1 if the last block is being recovered and the"]
+    #[doc = " client must wait for the file to be closed before appending to it, 0 otherwise."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsTruncate(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        pos: tOffset,
+        shouldWait: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Get a delegation token from namenode."]
+    #[doc = " The token should be freed using hdfsFreeDelegationToken after canceling the token or token expired."]
+    #[doc = ""]
+    #[doc = " @param fs The file system"]
+    #[doc = " @param renewer The user who will renew the token"]
+    #[doc = ""]
+    #[doc = " @return Return a delegation token, NULL on error."]
+    pub fn hdfsGetDelegationToken(
+        fs: hdfsFS,
+        renewer: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    #[doc = " Free a delegation token."]
+    #[doc = ""]
+    #[doc = " @param token The token to be freed."]
+    pub fn hdfsFreeDelegationToken(token: *mut ::std::os::raw::c_char);
+}
+extern "C" {
+    #[doc = " Renew a delegation token."]
+    #[doc = ""]
+    #[doc = " @param fs The file system."]
+    #[doc = " @param token The token to be renewed."]
+    #[doc = ""]
+    #[doc = " @return the new expiration time"]
+    pub fn hdfsRenewDelegationToken(fs: hdfsFS, token: *const ::std::os::raw::c_char) -> i64;
+}
+extern "C" {
+    #[doc = " Cancel a delegation token."]
+    #[doc = ""]
+    #[doc = " @param fs The file system."]
+    #[doc = " @param token The token to be canceled."]
+    #[doc = ""]
+    #[doc = " @return return 0 on success, -1 on error."]
+    pub fn hdfsCancelDelegationToken(
+        fs: hdfsFS,
+        token: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Namenode {
+    pub rpc_addr: *mut ::std::os::raw::c_char,
+    pub http_addr: *mut ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_Namenode() {
+    assert_eq!(
+        ::std::mem::size_of::<Namenode>(),
+        16usize,
+        concat!("Size of: ", stringify!(Namenode))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Namenode>(),
+        8usize,
+        concat!("Alignment of ", stringify!(Namenode))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<Namenode>())).rpc_addr as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Namenode),
+            "::",
+            stringify!(rpc_addr)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<Namenode>())).http_addr as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Namenode),
+            "::",
+            stringify!(http_addr)
+        )
+    );
+}
+extern "C" {
+    #[doc = " If hdfs is configured with HA namenode, return all namenode informations as an array."]
+    #[doc = " Else return NULL."]
+    #[doc = ""]
+    #[doc = " Using configure file which is given by environment parameter LIBHDFS3_CONF"]
+    #[doc = " or \"hdfs-client.xml\" in working directory."]
+    #[doc = ""]
+    #[doc = " @param nameservice hdfs name service id."]
+    #[doc = " @param size output the size of returning array."]
+    #[doc = ""]
+    #[doc = " @return return an array of all namenode information."]
+    pub fn hdfsGetHANamenodes(
+        nameservice: *const ::std::os::raw::c_char,
+        size: *mut ::std::os::raw::c_int,
+    ) -> *mut Namenode;
+}
+extern "C" {
+    #[doc = " If hdfs is configured with HA namenode, return all namenode informations as an array."]
+    #[doc = " Else return NULL."]
+    #[doc = ""]
+    #[doc = " @param conf the path of configure file."]
+    #[doc = " @param nameservice hdfs name service id."]
+    #[doc = " @param size output the size of returning array."]
+    #[doc = ""]
+    #[doc = " @return return an array of all namenode information."]
+    pub fn hdfsGetHANamenodesWithConfig(
+        conf: *const ::std::os::raw::c_char,
         nameservice: *const ::std::os::raw::c_char,
         size: *mut ::std::os::raw::c_int,
     ) -> *mut Namenode;
 }
 extern "C" {
-    #[doc = " If hdfs is configured with HA namenode, return all namenode informations as an array."]
-    #[doc = " Else return NULL."]
-    #[doc = ""]
-    #[doc = " @param conf the path of configure file."]
-    #[doc = " @param nameservice hdfs name service id."]
-    #[doc = " @param size output the size of returning array."]
-    #[doc = ""]
-    #[doc = " @return return an array of all namenode information."]
-    pub fn hdfsGetHANamenodesWithConfig(
-        conf: *const ::std::os::raw::c_char,
-        nameservice: *const ::std::os::raw::c_char,
-        size: *mut ::std::os::raw::c_int,
-    ) -> *mut Namenode;
+    #[doc = " Free the array returned by hdfsGetConfiguredNamenodes()"]
+    #[doc = ""]
+    #[doc = " @param the array return by hdfsGetConfiguredNamenodes()"]
+    pub fn hdfsFreeNamenodeInformation(namenodes: *mut Namenode, size: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[doc = " hdfsGetActiveNamenode - Get the RPC address of the namenode which"]
+    #[doc = " currently serves the requests of the given filesystem."]
+    #[doc = " If namenode HA is enabled, the returned address changes after failover."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param buffer The user-buffer to copy the \"host:port\" address into."]
+    #[doc = " @param bufferSize The length of user-buffer."]
+    #[doc = " @return Returns buffer, NULL on error."]
+    pub fn hdfsGetActiveNamenode(
+        fs: hdfsFS,
+        buffer: *mut ::std::os::raw::c_char,
+        bufferSize: size_t,
+    ) -> *mut ::std::os::raw::c_char;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct BlockLocation {
+    pub rangeId: u32,
+    pub replicaGroupId: u32,
+    pub corrupt: ::std::os::raw::c_int,
+    pub numOfNodes: ::std::os::raw::c_int,
+    pub hosts: *mut *mut ::std::os::raw::c_char,
+    pub names: *mut *mut ::std::os::raw::c_char,
+    pub topologyPaths: *mut *mut ::std::os::raw::c_char,
+    pub length: tOffset,
+    pub offset: tOffset,
+}
+#[test]
+fn bindgen_test_layout_BlockLocation() {
+    assert_eq!(
+        ::std::mem::size_of::<BlockLocation>(),
+        56usize,
+        concat!("Size of: ", stringify!(BlockLocation))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<BlockLocation>(),
+        8usize,
+        concat!("Alignment of ", stringify!(BlockLocation))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<BlockLocation>())).rangeId as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(BlockLocation),
+            "::",
+            stringify!(rangeId)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<BlockLocation>())).replicaGroupId as *const _ as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(BlockLocation),
+            "::",
+            stringify!(replicaGroupId)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<BlockLocation>())).corrupt as *const _ as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(BlockLocation),
+            "::",
+            stringify!(corrupt)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<BlockLocation>())).numOfNodes as *const _ as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(BlockLocation),
+            "::",
+            stringify!(numOfNodes)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<BlockLocation>())).hosts as *const _ as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(BlockLocation),
+            "::",
+            stringify!(hosts)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<BlockLocation>())).names as *const _ as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(BlockLocation),
+            "::",
+            stringify!(names)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<BlockLocation>())).topologyPaths as *const _ as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(BlockLocation),
+            "::",
+            stringify!(topologyPaths)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<BlockLocation>())).length as *const _ as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(BlockLocation),
+            "::",
+            stringify!(length)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<BlockLocation>())).offset as *const _ as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(BlockLocation),
+            "::",
+            stringify!(offset)
+        )
+    );
+}
+extern "C" {
+    #[doc = " Get an array containing hostnames, offset and size of portions of the given file."]
+    #[doc = ""]
+    #[doc = " @param fs The file system"]
+    #[doc = " @param path The path to the file"]
+    #[doc = " @param start The start offset into the given file"]
+    #[doc = " @param length The length for which to get locations for"]
+    #[doc = " @param numOfBlock Output the number of elements in the returned array"]
+    #[doc = ""]
+    #[doc = " @return An array of BlockLocation struct."]
+    pub fn hdfsGetFileBlockLocations(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        start: tOffset,
+        length: tOffset,
+        numOfBlock: *mut ::std::os::raw::c_int,
+    ) -> *mut BlockLocation;
+}
+extern "C" {
+    #[doc = " Free the BlockLocation array returned by hdfsGetFileBlockLocations"]
+    #[doc = ""]
+    #[doc = " @param locations The array returned by hdfsGetFileBlockLocations"]
+    #[doc = " @param numOfBlock The number of elements in the locaitons"]
+    pub fn hdfsFreeFileBlockLocations(
+        locations: *mut BlockLocation,
+        numOfBlock: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    #[doc = " Create encryption zone for the directory with specific key name"]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the directory."]
+    #[doc = " @param keyname The key name of the encryption zone"]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsCreateEncryptionZone(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        keyName: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsEncryptionZoneInfo - Get information about a path as a (dynamically"]
+    #[doc = " allocated) single hdfsEncryptionZoneInfo struct. hdfsEncryptionZoneInfo should be"]
+    #[doc = " called when the pointer is no longer needed."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the encryption zone."]
+    #[doc = " @return Returns a dynamically-allocated hdfsEncryptionZoneInfo object;"]
+    #[doc = " NULL on error."]
+    pub fn hdfsGetEZForPath(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+    ) -> *mut hdfsEncryptionZoneInfo;
+}
+extern "C" {
+    #[doc = " hdfsEncryptionZoneInfo -  Get list of all the encryption zones."]
+    #[doc = " hdfsFreeEncryptionZoneInfo should be called to deallocate memory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @return Returns a dynamically-allocated array of hdfsEncryptionZoneInfo objects;"]
+    #[doc = " NULL on error."]
+    pub fn hdfsListEncryptionZones(
+        fs: hdfsFS,
+        numEntries: *mut ::std::os::raw::c_int,
+    ) -> *mut hdfsEncryptionZoneInfo;
+}
+extern "C" {
+    #[doc = " hdfsCreateSnapshot - Create a snapshot of a snapshottable directory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the directory."]
+    #[doc = " @param name The name of the snapshot, NULL to let the namenode generate one."]
+    #[doc = " @param buffer The user-buffer to copy the path of the snapshot into."]
+    #[doc = " @param bufferSize The length of user-buffer."]
+    #[doc = " @return Returns buffer, NULL on error."]
+    pub fn hdfsCreateSnapshot(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        name: *const ::std::os::raw::c_char,
+        buffer: *mut ::std::os::raw::c_char,
+        bufferSize: size_t,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    #[doc = " hdfsDeleteSnapshot - Delete a snapshot of a snapshottable directory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the directory."]
+    #[doc = " @param name The name of the snapshot."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsDeleteSnapshot(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        name: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsRenameSnapshot - Rename a snapshot of a snapshottable directory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the directory."]
+    #[doc = " @param oldName The current name of the snapshot."]
+    #[doc = " @param newName The new name of the snapshot."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsRenameSnapshot(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        oldName: *const ::std::os::raw::c_char,
+        newName: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsAllowSnapshot - Allow snapshots to be taken of a directory."]
+    #[doc = " Requires superuser privilege."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the directory."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsAllowSnapshot(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsDisallowSnapshot - Disallow snapshots of a directory. All of its"]
+    #[doc = " snapshots must have been deleted. Requires superuser privilege."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the directory."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsDisallowSnapshot(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+#[doc = " hdfsSnapshottableDirInfo - Information about a snapshottable directory."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hdfsSnapshottableDirInfo {
+    pub mPath: *mut ::std::os::raw::c_char,
+    pub mOwner: *mut ::std::os::raw::c_char,
+    pub mGroup: *mut ::std::os::raw::c_char,
+    pub mPermissions: ::std::os::raw::c_short,
+    pub mLastMod: tTime,
+    pub mSnapshotNumber: ::std::os::raw::c_int,
+    pub mSnapshotQuota: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_hdfsSnapshottableDirInfo() {
+    assert_eq!(
+        ::std::mem::size_of::<hdfsSnapshottableDirInfo>(),
+        48usize,
+        concat!("Size of: ", stringify!(hdfsSnapshottableDirInfo))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<hdfsSnapshottableDirInfo>(),
+        8usize,
+        concat!("Alignment of ", stringify!(hdfsSnapshottableDirInfo))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshottableDirInfo, mPath),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshottableDirInfo),
+            "::",
+            stringify!(mPath)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshottableDirInfo, mOwner),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshottableDirInfo),
+            "::",
+            stringify!(mOwner)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshottableDirInfo, mGroup),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshottableDirInfo),
+            "::",
+            stringify!(mGroup)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshottableDirInfo, mPermissions),
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshottableDirInfo),
+            "::",
+            stringify!(mPermissions)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshottableDirInfo, mLastMod),
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshottableDirInfo),
+            "::",
+            stringify!(mLastMod)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshottableDirInfo, mSnapshotNumber),
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshottableDirInfo),
+            "::",
+            stringify!(mSnapshotNumber)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshottableDirInfo, mSnapshotQuota),
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshottableDirInfo),
+            "::",
+            stringify!(mSnapshotQuota)
+        )
+    );
+}
+extern "C" {
+    #[doc = " hdfsGetSnapshottableDirListing - Get list of the snapshottable directories"]
+    #[doc = " which the current user owns, or all of them for the superuser."]
+    #[doc = " hdfsFreeSnapshottableDirInfo should be called to deallocate memory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param numEntries Set to the number of directories."]
+    #[doc = " @return Returns a dynamically-allocated array of hdfsSnapshottableDirInfo"]
+    #[doc = " objects; NULL on error or if there is none."]
+    pub fn hdfsGetSnapshottableDirListing(
+        fs: hdfsFS,
+        numEntries: *mut ::std::os::raw::c_int,
+    ) -> *mut hdfsSnapshottableDirInfo;
+}
+extern "C" {
+    #[doc = " hdfsFreeSnapshottableDirInfo - Free up the hdfsSnapshottableDirInfo array (including fields)"]
+    #[doc = " @param infos The array of dynamically-allocated hdfsSnapshottableDirInfo"]
+    #[doc = " objects."]
+    #[doc = " @param numEntries The size of the array."]
+    pub fn hdfsFreeSnapshottableDirInfo(
+        infos: *mut hdfsSnapshottableDirInfo,
+        numEntries: ::std::os::raw::c_int,
+    );
+}
+#[doc = " hdfsSnapshotDiffEntry - A difference between two snapshots."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hdfsSnapshotDiffEntry {
+    pub mType: ::std::os::raw::c_char,
+    pub mPath: *mut ::std::os::raw::c_char,
+    pub mTargetPath: *mut ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_hdfsSnapshotDiffEntry() {
+    assert_eq!(
+        ::std::mem::size_of::<hdfsSnapshotDiffEntry>(),
+        24usize,
+        concat!("Size of: ", stringify!(hdfsSnapshotDiffEntry))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<hdfsSnapshotDiffEntry>(),
+        8usize,
+        concat!("Alignment of ", stringify!(hdfsSnapshotDiffEntry))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshotDiffEntry, mType),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshotDiffEntry),
+            "::",
+            stringify!(mType)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshotDiffEntry, mPath),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshotDiffEntry),
+            "::",
+            stringify!(mPath)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshotDiffEntry, mTargetPath),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshotDiffEntry),
+            "::",
+            stringify!(mTargetPath)
+        )
+    );
+}
+#[doc = " hdfsSnapshotDiffReport - The differences between two snapshots of a directory."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hdfsSnapshotDiffReport {
+    pub mSnapshotRoot: *mut ::std::os::raw::c_char,
+    pub mFromSnapshot: *mut ::std::os::raw::c_char,
+    pub mToSnapshot: *mut ::std::os::raw::c_char,
+    pub mNumEntries: ::std::os::raw::c_int,
+    pub mEntries: *mut hdfsSnapshotDiffEntry,
+}
+#[test]
+fn bindgen_test_layout_hdfsSnapshotDiffReport() {
+    assert_eq!(
+        ::std::mem::size_of::<hdfsSnapshotDiffReport>(),
+        40usize,
+        concat!("Size of: ", stringify!(hdfsSnapshotDiffReport))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<hdfsSnapshotDiffReport>(),
+        8usize,
+        concat!("Alignment of ", stringify!(hdfsSnapshotDiffReport))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshotDiffReport, mSnapshotRoot),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshotDiffReport),
+            "::",
+            stringify!(mSnapshotRoot)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshotDiffReport, mFromSnapshot),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshotDiffReport),
+            "::",
+            stringify!(mFromSnapshot)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshotDiffReport, mToSnapshot),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshotDiffReport),
+            "::",
+            stringify!(mToSnapshot)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshotDiffReport, mNumEntries),
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshotDiffReport),
+            "::",
+            stringify!(mNumEntries)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsSnapshotDiffReport, mEntries),
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsSnapshotDiffReport),
+            "::",
+            stringify!(mEntries)
+        )
+    );
+}
+extern "C" {
+    #[doc = " hdfsGetSnapshotDiffReport - Get the differences between two snapshots"]
+    #[doc = " of a directory. hdfsFreeSnapshotDiffReport should be called to deallocate memory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the snapshottable directory."]
+    #[doc = " @param fromSnapshot The earlier snapshot, NULL or empty for the current state."]
+    #[doc = " @param toSnapshot The later snapshot, NULL or empty for the current state."]
+    #[doc = " @return Returns a dynamically-allocated hdfsSnapshotDiffReport object;"]
+    #[doc = " NULL on error."]
+    pub fn hdfsGetSnapshotDiffReport(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        fromSnapshot: *const ::std::os::raw::c_char,
+        toSnapshot: *const ::std::os::raw::c_char,
+    ) -> *mut hdfsSnapshotDiffReport;
 }
 extern "C" {
-    #[doc = " Free the array returned by hdfsGetConfiguredNamenodes()"]
-    #[doc = ""]
-    #[doc = " @param the array return by hdfsGetConfiguredNamenodes()"]
-    pub fn hdfsFreeNamenodeInformation(namenodes: *mut Namenode, size: ::std::os::raw::c_int);
+    #[doc = " hdfsFreeSnapshotDiffReport - Free up the hdfsSnapshotDiffReport (including fields)"]
+    #[doc = " @param report The dynamically-allocated hdfsSnapshotDiffReport object."]
+    pub fn hdfsFreeSnapshotDiffReport(report: *mut hdfsSnapshotDiffReport);
 }
+#[doc = " hdfsAclEntry - An entry of the ACL of a file or directory."]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct BlockLocation {
-    pub rangeId: u32,
-    pub replicaGroupId: u32,
-    pub corrupt: ::std::os::raw::c_int,
-    pub numOfNodes: ::std::os::raw::c_int,
-    pub hosts: *mut *mut ::std::os::raw::c_char,
-    pub names: *mut *mut ::std::os::raw::c_char,
-    pub topologyPaths: *mut *mut ::std::os::raw::c_char,
-    pub length: tOffset,
-    pub offset: tOffset,
+pub struct hdfsAclEntry {
+    pub mScope: ::std::os::raw::c_int,
+    pub mType: ::std::os::raw::c_int,
+    pub mPermissions: ::std::os::raw::c_int,
+    pub mName: *mut ::std::os::raw::c_char,
 }
 #[test]
-fn bindgen_test_layout_BlockLocation() {
+fn bindgen_test_layout_hdfsAclEntry() {
     assert_eq!(
-        ::std::mem::size_of::<BlockLocation>(),
-        56usize,
-        concat!("Size of: ", stringify!(BlockLocation))
+        ::std::mem::size_of::<hdfsAclEntry>(),
+        24usize,
+        concat!("Size of: ", stringify!(hdfsAclEntry))
     );
     assert_eq!(
-        ::std::mem::align_of::<BlockLocation>(),
+        ::std::mem::align_of::<hdfsAclEntry>(),
         8usize,
-        concat!("Alignment of ", stringify!(BlockLocation))
+        concat!("Alignment of ", stringify!(hdfsAclEntry))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<BlockLocation>())).rangeId as *const _ as usize },
+        ::std::mem::offset_of!(hdfsAclEntry, mScope),
         0usize,
         concat!(
             "Offset of field: ",
-            stringify!(BlockLocation),
+            stringify!(hdfsAclEntry),
             "::",
-            stringify!(rangeId)
+            stringify!(mScope)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<BlockLocation>())).replicaGroupId as *const _ as usize },
+        ::std::mem::offset_of!(hdfsAclEntry, mType),
         4usize,
         concat!(
             "Offset of field: ",
-            stringify!(BlockLocation),
+            stringify!(hdfsAclEntry),
             "::",
-            stringify!(replicaGroupId)
+            stringify!(mType)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<BlockLocation>())).corrupt as *const _ as usize },
+        ::std::mem::offset_of!(hdfsAclEntry, mPermissions),
         8usize,
         concat!(
             "Offset of field: ",
-            stringify!(BlockLocation),
+            stringify!(hdfsAclEntry),
             "::",
-            stringify!(corrupt)
+            stringify!(mPermissions)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<BlockLocation>())).numOfNodes as *const _ as usize },
-        12usize,
+        ::std::mem::offset_of!(hdfsAclEntry, mName),
+        16usize,
         concat!(
             "Offset of field: ",
-            stringify!(BlockLocation),
+            stringify!(hdfsAclEntry),
             "::",
-            stringify!(numOfNodes)
+            stringify!(mName)
         )
     );
+}
+#[doc = " hdfsAclStatus - The ACL of a file or directory."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hdfsAclStatus {
+    pub mOwner: *mut ::std::os::raw::c_char,
+    pub mGroup: *mut ::std::os::raw::c_char,
+    pub mPermissions: ::std::os::raw::c_short,
+    pub mSticky: ::std::os::raw::c_int,
+    pub mNumEntries: ::std::os::raw::c_int,
+    pub mEntries: *mut hdfsAclEntry,
+}
+#[test]
+fn bindgen_test_layout_hdfsAclStatus() {
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<BlockLocation>())).hosts as *const _ as usize },
-        16usize,
+        ::std::mem::size_of::<hdfsAclStatus>(),
+        40usize,
+        concat!("Size of: ", stringify!(hdfsAclStatus))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<hdfsAclStatus>(),
+        8usize,
+        concat!("Alignment of ", stringify!(hdfsAclStatus))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsAclStatus, mOwner),
+        0usize,
         concat!(
             "Offset of field: ",
-            stringify!(BlockLocation),
+            stringify!(hdfsAclStatus),
             "::",
-            stringify!(hosts)
+            stringify!(mOwner)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<BlockLocation>())).names as *const _ as usize },
-        24usize,
+        ::std::mem::offset_of!(hdfsAclStatus, mGroup),
+        8usize,
         concat!(
             "Offset of field: ",
-            stringify!(BlockLocation),
+            stringify!(hdfsAclStatus),
             "::",
-            stringify!(names)
+            stringify!(mGroup)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<BlockLocation>())).topologyPaths as *const _ as usize },
-        32usize,
+        ::std::mem::offset_of!(hdfsAclStatus, mPermissions),
+        16usize,
         concat!(
             "Offset of field: ",
-            stringify!(BlockLocation),
+            stringify!(hdfsAclStatus),
             "::",
-            stringify!(topologyPaths)
+            stringify!(mPermissions)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<BlockLocation>())).length as *const _ as usize },
-        40usize,
+        ::std::mem::offset_of!(hdfsAclStatus, mSticky),
+        20usize,
         concat!(
             "Offset of field: ",
-            stringify!(BlockLocation),
+            stringify!(hdfsAclStatus),
             "::",
-            stringify!(length)
+            stringify!(mSticky)
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<BlockLocation>())).offset as *const _ as usize },
-        48usize,
+        ::std::mem::offset_of!(hdfsAclStatus, mNumEntries),
+        24usize,
         concat!(
             "Offset of field: ",
-            stringify!(BlockLocation),
+            stringify!(hdfsAclStatus),
             "::",
-            stringify!(offset)
+            stringify!(mNumEntries)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsAclStatus, mEntries),
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsAclStatus),
+            "::",
+            stringify!(mEntries)
         )
     );
 }
 extern "C" {
-    #[doc = " Get an array containing hostnames, offset and size of portions of the given file."]
-    #[doc = ""]
-    #[doc = " @param fs The file system"]
-    #[doc = " @param path The path to the file"]
-    #[doc = " @param start The start offset into the given file"]
-    #[doc = " @param length The length for which to get locations for"]
-    #[doc = " @param numOfBlock Output the number of elements in the returned array"]
-    #[doc = ""]
-    #[doc = " @return An array of BlockLocation struct."]
-    pub fn hdfsGetFileBlockLocations(
+    #[doc = " hdfsGetAclStatus - Get the ACL of a file or directory."]
+    #[doc = " hdfsFreeAclStatus should be called to deallocate memory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the file or directory."]
+    #[doc = " @return Returns a dynamically-allocated hdfsAclStatus object;"]
+    #[doc = " NULL on error."]
+    pub fn hdfsGetAclStatus(
         fs: hdfsFS,
         path: *const ::std::os::raw::c_char,
-        start: tOffset,
-        length: tOffset,
-        numOfBlock: *mut ::std::os::raw::c_int,
-    ) -> *mut BlockLocation;
+    ) -> *mut hdfsAclStatus;
 }
 extern "C" {
-    #[doc = " Free the BlockLocation array returned by hdfsGetFileBlockLocations"]
-    #[doc = ""]
-    #[doc = " @param locations The array returned by hdfsGetFileBlockLocations"]
-    #[doc = " @param numOfBlock The number of elements in the locaitons"]
-    pub fn hdfsFreeFileBlockLocations(
-        locations: *mut BlockLocation,
-        numOfBlock: ::std::os::raw::c_int,
-    );
+    #[doc = " hdfsFreeAclStatus - Free up the hdfsAclStatus (including fields)"]
+    #[doc = " @param status The dynamically-allocated hdfsAclStatus object."]
+    pub fn hdfsFreeAclStatus(status: *mut hdfsAclStatus);
 }
 extern "C" {
-    #[doc = " Create encryption zone for the directory with specific key name"]
+    #[doc = " hdfsSetAcl - Replace the ACL of a file or directory. The entries must"]
+    #[doc = " include those of the owner, group and others."]
     #[doc = " @param fs The configured filesystem handle."]
-    #[doc = " @param path The path of the directory."]
-    #[doc = " @param keyname The key name of the encryption zone"]
+    #[doc = " @param path The path of the file or directory."]
+    #[doc = " @param entries The entries of the new ACL."]
+    #[doc = " @param numEntries The number of entries."]
     #[doc = " @return Returns 0 on success, -1 on error."]
-    pub fn hdfsCreateEncryptionZone(
+    pub fn hdfsSetAcl(
         fs: hdfsFS,
         path: *const ::std::os::raw::c_char,
-        keyName: *const ::std::os::raw::c_char,
+        entries: *const hdfsAclEntry,
+        numEntries: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[doc = " hdfsEncryptionZoneInfo - Get information about a path as a (dynamically"]
-    #[doc = " allocated) single hdfsEncryptionZoneInfo struct. hdfsEncryptionZoneInfo should be"]
-    #[doc = " called when the pointer is no longer needed."]
+    #[doc = " hdfsModifyAclEntries - Add or update entries of the ACL of a file or"]
+    #[doc = " directory, keeping the other entries."]
     #[doc = " @param fs The configured filesystem handle."]
-    #[doc = " @param path The path of the encryption zone."]
-    #[doc = " @return Returns a dynamically-allocated hdfsEncryptionZoneInfo object;"]
-    #[doc = " NULL on error."]
-    pub fn hdfsGetEZForPath(
+    #[doc = " @param path The path of the file or directory."]
+    #[doc = " @param entries The entries to add or update."]
+    #[doc = " @param numEntries The number of entries."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsModifyAclEntries(
         fs: hdfsFS,
         path: *const ::std::os::raw::c_char,
-    ) -> *mut hdfsEncryptionZoneInfo;
+        entries: *const hdfsAclEntry,
+        numEntries: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
 }
 extern "C" {
-    #[doc = " hdfsEncryptionZoneInfo -  Get list of all the encryption zones."]
-    #[doc = " hdfsFreeEncryptionZoneInfo should be called to deallocate memory."]
+    #[doc = " hdfsRemoveAclEntries - Remove entries of the ACL of a file or directory."]
     #[doc = " @param fs The configured filesystem handle."]
-    #[doc = " @return Returns a dynamically-allocated array of hdfsEncryptionZoneInfo objects;"]
-    #[doc = " NULL on error."]
-    pub fn hdfsListEncryptionZones(
+    #[doc = " @param path The path of the file or directory."]
+    #[doc = " @param entries The entries to remove, whose permissions are ignored."]
+    #[doc = " @param numEntries The number of entries."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsRemoveAclEntries(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        entries: *const hdfsAclEntry,
+        numEntries: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsRemoveDefaultAcl - Remove the default entries of the ACL of a directory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the directory."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsRemoveDefaultAcl(fs: hdfsFS, path: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsRemoveAcl - Remove all entries of the ACL of a file or directory,"]
+    #[doc = " except those of its permission."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the file or directory."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsRemoveAcl(fs: hdfsFS, path: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+#[doc = " hdfsXAttr - An extended attribute of a file or directory."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hdfsXAttr {
+    pub mNamespace: ::std::os::raw::c_int,
+    pub mName: *mut ::std::os::raw::c_char,
+    pub mValue: *mut ::std::os::raw::c_char,
+    pub mValueLength: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_hdfsXAttr() {
+    assert_eq!(
+        ::std::mem::size_of::<hdfsXAttr>(),
+        32usize,
+        concat!("Size of: ", stringify!(hdfsXAttr))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<hdfsXAttr>(),
+        8usize,
+        concat!("Alignment of ", stringify!(hdfsXAttr))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsXAttr, mNamespace),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsXAttr),
+            "::",
+            stringify!(mNamespace)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsXAttr, mName),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsXAttr),
+            "::",
+            stringify!(mName)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsXAttr, mValue),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsXAttr),
+            "::",
+            stringify!(mValue)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(hdfsXAttr, mValueLength),
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hdfsXAttr),
+            "::",
+            stringify!(mValueLength)
+        )
+    );
+}
+extern "C" {
+    #[doc = " hdfsGetXAttrs - Get extended attributes of a file or directory with"]
+    #[doc = " their values. hdfsFreeXAttrs should be called to deallocate memory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the file or directory."]
+    #[doc = " @param names The attributes to get, whose values are ignored; NULL for all of them."]
+    #[doc = " @param numNames The number of attributes to get."]
+    #[doc = " @param numEntries Set to the number of attributes returned."]
+    #[doc = " @return Returns a dynamically-allocated array of hdfsXAttr objects;"]
+    #[doc = " NULL on error or if there is none."]
+    pub fn hdfsGetXAttrs(
         fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        names: *const hdfsXAttr,
+        numNames: ::std::os::raw::c_int,
         numEntries: *mut ::std::os::raw::c_int,
-    ) -> *mut hdfsEncryptionZoneInfo;
+    ) -> *mut hdfsXAttr;
+}
+extern "C" {
+    #[doc = " hdfsListXAttrs - List the extended attributes of a file or directory"]
+    #[doc = " which the user may access, without their values."]
+    #[doc = " hdfsFreeXAttrs should be called to deallocate memory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the file or directory."]
+    #[doc = " @param numEntries Set to the number of attributes."]
+    #[doc = " @return Returns a dynamically-allocated array of hdfsXAttr objects;"]
+    #[doc = " NULL on error or if there is none."]
+    pub fn hdfsListXAttrs(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        numEntries: *mut ::std::os::raw::c_int,
+    ) -> *mut hdfsXAttr;
+}
+extern "C" {
+    #[doc = " hdfsFreeXAttrs - Free up the hdfsXAttr array (including fields)"]
+    #[doc = " @param xattrs The array of dynamically-allocated hdfsXAttr objects."]
+    #[doc = " @param numEntries The size of the array."]
+    pub fn hdfsFreeXAttrs(xattrs: *mut hdfsXAttr, numEntries: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[doc = " hdfsSetXAttr - Set an extended attribute of a file or directory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the file or directory."]
+    #[doc = " @param xattr The attribute with its value."]
+    #[doc = " @param flags 1 to create the attribute, 2 to replace it, or 3 for either."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsSetXAttr(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        xattr: *const hdfsXAttr,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsRemoveXAttr - Remove an extended attribute of a file or directory."]
+    #[doc = " @param fs The configured filesystem handle."]
+    #[doc = " @param path The path of the file or directory."]
+    #[doc = " @param xattr The attribute, whose value is ignored."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsRemoveXAttr(
+        fs: hdfsFS,
+        path: *const ::std::os::raw::c_char,
+        xattr: *const hdfsXAttr,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " hdfsGetSpnegoToken - Get a SPNEGO token to authenticate to an HTTP service,"]
+    #[doc = " e.g. the KMS, with the Kerberos credentials of a ticket cache."]
+    #[doc = " The token should be freed using hdfsFreeSpnegoToken."]
+    #[doc = " @param host The host name of the service, whose principal is `HTTP/<host>`."]
+    #[doc = " @param ticketCachePath The Kerberos ticket cache, NULL for the default one."]
+    #[doc = " @param token Output value, the token to send base64 encoded in a"]
+    #[doc = " \"Negotiate\" Authorization header."]
+    #[doc = " @param length Output value, the length of the token."]
+    #[doc = " @return Returns 0 on success, -1 on error."]
+    pub fn hdfsGetSpnegoToken(
+        host: *const ::std::os::raw::c_char,
+        ticketCachePath: *const ::std::os::raw::c_char,
+        token: *mut *mut ::std::os::raw::c_char,
+        length: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[doc = " Free a SPNEGO token."]
+    #[doc = ""]
+    #[doc = " @param token The token to be freed."]
+    pub fn hdfsFreeSpnegoToken(token: *mut ::std::os::raw::c_char);
 }
@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use log::*;
+use regex::Regex;
+
+use crate::config::{hadoop_conf_dir, read_site_xml};
+use crate::err::HdfsErr;
+
+/// Hadoop configuration key of the principal to short name mapping rules
+pub const AUTH_TO_LOCAL: &str = "hadoop.security.auth_to_local";
+
+lazy_static! {
+    static ref NAME_PATTERN: Regex = Regex::new(r"^([^/@]+)(/([^/@]+))?(@([^/@]+))?$").unwrap();
+    static ref RULE_PATTERN: Regex = Regex::new(
+        r"^\s*((DEFAULT)|(RULE:\[(\d*):([^\]]*)\](\(([^)]*)\))?(s/([^/]*)/([^/]*)/(g)?)?/?(L)?))"
+    )
+    .unwrap();
+    static ref PARAMETER_PATTERN: Regex = Regex::new(r"\$(\d+)").unwrap();
+    static ref NON_SIMPLE_PATTERN: Regex = Regex::new(r"[/@]").unwrap();
+}
+
+/// A Kerberos principal name, such as `alice/host.example.com@EXAMPLE.COM`, split into its
+/// service name, host and realm.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KerberosName {
+    service_name: String,
+    host: Option<String>,
+    realm: Option<String>,
+}
+
+impl KerberosName {
+    /// Parse a principal name. The host and realm are optional.
+    pub fn parse(principal: &str) -> Result<KerberosName, HdfsErr> {
+        let captures = NAME_PATTERN.captures(principal).ok_or_else(|| {
+            HdfsErr::Miscellaneous(format!("Malformed Kerberos name: {}", principal))
+        })?;
+        Ok(KerberosName {
+            service_name: captures[1].to_owned(),
+            host: captures.get(3).map(|m| m.as_str().to_owned()),
+            realm: captures.get(5).map(|m| m.as_str().to_owned()),
+        })
+    }
+
+    /// The first component of the principal, e.g. `alice` or `hdfs`
+    #[inline]
+    pub fn service_name(&self) -> &str {
+        &self.service_name
+    }
+
+    #[inline]
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+
+    #[inline]
+    pub fn realm(&self) -> Option<&str> {
+        self.realm.as_deref()
+    }
+
+    /// Map the principal to a local user name with the given rules.
+    pub fn short_name(&self, rules: &AuthToLocal) -> Result<String, HdfsErr> {
+        let realm = match &self.realm {
+            Some(realm) => realm.as_str(),
+            None if self.host.is_none() => return Ok(self.service_name.clone()),
+            None => "",
+        };
+        let mut params = vec![realm, self.service_name.as_str()];
+        if let Some(host) = &self.host {
+            params.push(host);
+        }
+        for rule in &rules.rules {
+            if let Some(short_name) = rule.apply(&params, rules.default_realm.as_deref())? {
+                return Ok(short_name);
+            }
+        }
+        Err(HdfsErr::Miscellaneous(format!(
+            "No auth_to_local rule applied to {}",
+            self
+        )))
+    }
+}
+
+impl FromStr for KerberosName {
+    type Err = HdfsErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KerberosName::parse(s)
+    }
+}
+
+impl Display for KerberosName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.service_name)?;
+        if let Some(host) = &self.host {
+            write!(f, "/{}", host)?;
+        }
+        if let Some(realm) = &self.realm {
+            write!(f, "@{}", realm)?;
+        }
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The `hadoop.security.auth_to_local` rules, which map Kerberos principals to local user
+/// names, e.g.
+///
+/// ```text
+/// RULE:[2:$1@$0](nn@EXAMPLE\.COM)s/.*/hdfs/
+/// RULE:[1:$1@$0](.*@EXAMPLE\.COM)s/@.*//L
+/// DEFAULT
+/// ```
+///
+/// `DEFAULT` maps principals of the default realm to their first component.
+#[derive(Clone, Debug)]
+pub struct AuthToLocal {
+    rules: Vec<Rule>,
+    default_realm: Option<String>,
+}
+
+impl AuthToLocal {
+    /// Parse rules, using the default realm of the Kerberos configuration.
+    pub fn parse(rules: &str) -> Result<AuthToLocal, HdfsErr> {
+        let mut remaining = rules.trim();
+        let mut parsed = Vec::new();
+        while !remaining.is_empty() {
+            let captures = RULE_PATTERN
+                .captures(remaining)
+                .ok_or_else(|| invalid(&format!("invalid rule: {}", remaining)))?;
+            let rule = if captures.get(2).is_some() {
+                Rule::Default
+            } else {
+                let components = captures[4]
+                    .parse()
+                    .map_err(|_| invalid(&format!("invalid rule: {}", &captures[0])))?;
+                let pattern = |group: usize| {
+                    captures
+                        .get(group)
+                        .map(|m| {
+                            Regex::new(&format!("^(?:{})$", m.as_str())).map_err(|e| {
+                                invalid(&format!("invalid regex {}: {}", m.as_str(), e))
+                            })
+                        })
+                        .transpose()
+                };
+                let substitution = match captures.get(9) {
+                    Some(from) => Some(Substitution {
+                        from: Regex::new(from.as_str()).map_err(|e| {
+                            invalid(&format!("invalid regex {}: {}", from.as_str(), e))
+                        })?,
+                        to: PARAMETER_PATTERN
+                            .replace_all(&captures[10], "$${$1}")
+                            .into_owned(),
+                        global: captures.get(11).is_some(),
+                    }),
+                    None => None,
+                };
+                Rule::Rule {
+                    components,
+                    format: captures[5].to_owned(),
+                    pattern: pattern(7)?,
+                    substitution,
+                    lower_case: captures.get(12).is_some(),
+                }
+            };
+            parsed.push(rule);
+            remaining = remaining[captures[0].len()..].trim_start();
+        }
+        Ok(AuthToLocal {
+            rules: parsed,
+            default_realm: default_realm(),
+        })
+    }
+
+    /// Read the rules from Hadoop configuration properties, defaulting to `DEFAULT`.
+    pub fn from_config(properties: &HashMap<String, String>) -> Result<AuthToLocal, HdfsErr> {
+        match properties.get(AUTH_TO_LOCAL) {
+            Some(rules) => AuthToLocal::parse(rules),
+            None => AuthToLocal::parse("DEFAULT"),
+        }
+    }
+
+    /// Read the rules from `core-site.xml` in the Hadoop configuration directory, see
+    /// [`hadoop_conf_dir`], defaulting to `DEFAULT` with a warning if it does not exist.
+    pub fn from_env() -> Result<AuthToLocal, HdfsErr> {
+        let core_site = match hadoop_conf_dir() {
+            Some(conf_dir) => conf_dir.join("core-site.xml"),
+            None => return AuthToLocal::parse("DEFAULT"),
+        };
+        if core_site.exists() {
+            AuthToLocal::from_config(&read_site_xml(&core_site)?)
+        } else {
+            warn!(
+                "{} does not exist, mapping Kerberos principals with the DEFAULT rule",
+                core_site.display()
+            );
+            AuthToLocal::parse("DEFAULT")
+        }
+    }
+
+    /// Use the given default realm instead of that of the Kerberos configuration.
+    pub fn with_default_realm(self, realm: &str) -> AuthToLocal {
+        AuthToLocal {
+            default_realm: Some(realm.to_owned()),
+            ..self
+        }
+    }
+
+    /// The default realm the `DEFAULT` rule applies to
+    #[inline]
+    pub fn default_realm(&self) -> Option<&str> {
+        self.default_realm.as_deref()
+    }
+
+    /// Map a principal to a local user name.
+    pub fn short_name(&self, principal: &str) -> Result<String, HdfsErr> {
+        KerberosName::parse(principal)?.short_name(self)
+    }
+}
+
+impl FromStr for AuthToLocal {
+    type Err = HdfsErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AuthToLocal::parse(s)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Rule {
+    Default,
+    Rule {
+        components: usize,
+        format: String,
+        pattern: Option<Regex>,
+        substitution: Option<Substitution>,
+        lower_case: bool,
+    },
+}
+
+#[derive(Clone, Debug)]
+struct Substitution {
+    from: Regex,
+    to: String,
+    global: bool,
+}
+
+impl Rule {
+    /// Apply the rule to the realm and the components of a principal, returning `None` if
+    /// it does not match.
+    fn apply(
+        &self,
+        params: &[&str],
+        default_realm: Option<&str>,
+    ) -> Result<Option<String>, HdfsErr> {
+        let (components, format, pattern, substitution, lower_case) = match self {
+            Rule::Default => {
+                return Ok(Some(params[1].to_owned()).filter(|_| Some(params[0]) == default_realm))
+            }
+            Rule::Rule {
+                components,
+                format,
+                pattern,
+                substitution,
+                lower_case,
+            } => (*components, format, pattern, substitution, *lower_case),
+        };
+        if components != params.len() - 1 {
+            return Ok(None);
+        }
+
+        let mut base = String::new();
+        let mut last = 0;
+        for captures in PARAMETER_PATTERN.captures_iter(format) {
+            let m = captures.get(0).unwrap();
+            let index: usize = captures[1].parse().unwrap_or(usize::MAX);
+            let param = params.get(index).ok_or_else(|| {
+                invalid(&format!(
+                    "index {} out of range in format {}",
+                    index, format
+                ))
+            })?;
+            base.push_str(&format[last..m.start()]);
+            base.push_str(param);
+            last = m.end();
+        }
+        base.push_str(&format[last..]);
+
+        if pattern
+            .as_ref()
+            .is_some_and(|pattern| !pattern.is_match(&base))
+        {
+            return Ok(None);
+        }
+        let result = match substitution {
+            Some(Substitution { from, to, global }) if *global => {
+                from.replace_all(&base, to.as_str()).into_owned()
+            }
+            Some(Substitution { from, to, .. }) => from.replace(&base, to.as_str()).into_owned(),
+            None => base,
+        };
+        if NON_SIMPLE_PATTERN.is_match(&result) {
+            return Err(HdfsErr::Miscellaneous(format!(
+                "Non-simple name {} after auth_to_local rule",
+                result
+            )));
+        }
+        Ok(Some(if lower_case {
+            result.to_lowercase()
+        } else {
+            result
+        }))
+    }
+}
+
+/// The `default_realm` of the `[libdefaults]` section of the Kerberos configuration given by
+/// `KRB5_CONFIG`, or `/etc/krb5.conf`.
+fn default_realm() -> Option<String> {
+    let path = env::var("KRB5_CONFIG").unwrap_or_else(|_| "/etc/krb5.conf".to_owned());
+    let content = std::fs::read_to_string(path).ok()?;
+    let mut libdefaults = false;
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            libdefaults = line == "[libdefaults]";
+        } else if libdefaults {
+            if let Some((key, value)) = line.split_once('=') {
+                if key.trim() == "default_realm" {
+                    return Some(value.trim().to_owned());
+                }
+            }
+        }
+    }
+    None
+}
+
+fn invalid(reason: &str) -> HdfsErr {
+    HdfsErr::InvalidConfig(format!("{}: {}", AUTH_TO_LOCAL, reason))
+}
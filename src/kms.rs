@@ -0,0 +1,278 @@
+use std::ffi::CString;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use libc::{c_char, c_int};
+use log::*;
+use serde::Deserialize;
+use url::Url;
+
+use crate::encryption::FileEncryptionInfo;
+use crate::err::HdfsErr;
+use crate::hdfs3::last_error;
+use crate::{hdfsFreeSpnegoToken, hdfsGetSpnegoToken};
+
+/// Configuration key of the key provider of the encryption zones in `hdfs-site.xml`
+pub const KEY_PROVIDER_URI: &str = "dfs.encryption.key.provider.uri";
+
+/// Configuration key of the key provider in `core-site.xml`, used if
+/// [`KEY_PROVIDER_URI`] is not set
+pub const KEY_PROVIDER_PATH: &str = "hadoop.security.key.provider.path";
+
+/// Timeout of each request to a KMS instance
+const KMS_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A client of the Hadoop KMS REST API, which decrypts the data encryption keys of files in
+/// encryption zones
+#[derive(Clone, Debug)]
+pub struct KmsClient {
+    /// Base URLs of the KMS instances, e.g. `http://kms:9600/kms`, which are tried in order
+    urls: Vec<Url>,
+    auth: KmsAuth,
+    /// The user to act on behalf of, i.e. the `doAs` parameter
+    proxy_user: Option<String>,
+    agent: ureq::Agent,
+}
+
+/// How requests to the KMS are authenticated
+#[derive(Clone, Debug)]
+enum KmsAuth {
+    /// Hadoop simple authentication as a user, i.e. the `user.name` parameter, `None` to
+    /// send no user
+    Simple(Option<String>),
+    /// SPNEGO with the Kerberos credentials of a ticket cache, the default one if `None`
+    Kerberos(Option<PathBuf>),
+}
+
+impl KmsClient {
+    /// Create a client from the URL of a KMS, e.g. `http://kms:9600/kms`, or a key provider
+    /// URI as configured in `dfs.encryption.key.provider.uri`, e.g.
+    /// `kms://http@kms1;kms2:9600/kms`, which names several instances.
+    pub fn new(uri: &str) -> Result<KmsClient, HdfsErr> {
+        let urls = kms_urls(uri)?;
+        Ok(KmsClient {
+            urls,
+            auth: KmsAuth::Simple(None),
+            proxy_user: None,
+            agent: ureq::AgentBuilder::new().timeout(KMS_TIMEOUT).build(),
+        })
+    }
+
+    /// Authenticate as a user with Hadoop simple authentication, i.e. the `user.name`
+    /// parameter.
+    pub fn with_user(mut self, user: impl Into<String>) -> KmsClient {
+        self.auth = KmsAuth::Simple(Some(user.into()));
+        self
+    }
+
+    /// Authenticate with SPNEGO, using the Kerberos credentials of a ticket cache, or of the
+    /// default one if `None`. The KMS principal is `HTTP/<host>` of its URL.
+    pub fn with_kerberos(mut self, ticket_cache: Option<PathBuf>) -> KmsClient {
+        self.auth = KmsAuth::Kerberos(ticket_cache);
+        self
+    }
+
+    /// Act on behalf of another user, i.e. the `doAs` parameter. The authenticated user must
+    /// be allowed to impersonate it by the KMS.
+    pub fn with_proxy_user(mut self, user: impl Into<String>) -> KmsClient {
+        self.proxy_user = Some(user.into());
+        self
+    }
+
+    /// Base URLs of the KMS instances
+    pub fn urls(&self) -> &[Url] {
+        &self.urls
+    }
+
+    /// Decrypt the encrypted data encryption key of a file with its zone key, returning the
+    /// key its data is encrypted with. The KMS instances are tried in order until one
+    /// succeeds.
+    pub fn decrypt_edek(&self, info: &FileEncryptionInfo) -> Result<Vec<u8>, HdfsErr> {
+        let body = serde_json::json!({
+            "name": info.key_name,
+            "iv": STANDARD.encode(&info.iv),
+            "material": STANDARD.encode(&info.edek),
+        });
+        let mut error = None;
+        for base in &self.urls {
+            let url = self.decrypt_url(base, &info.ez_key_version_name);
+            debug!(
+                "Decrypting the EDEK of key version {} at {}",
+                info.ez_key_version_name, base
+            );
+            let mut request = self
+                .agent
+                .post(url.as_str())
+                .set("Content-Type", "application/json");
+            if let KmsAuth::Kerberos(ticket_cache) = &self.auth {
+                let host = base.host_str().expect("KMS URLs have a host");
+                match spnego_token(host, ticket_cache.as_deref()) {
+                    Ok(token) => {
+                        request = request.set("Authorization", &format!("Negotiate {}", token))
+                    }
+                    Err(e) => {
+                        warn!("Could not authenticate to KMS {}: {}", base, e);
+                        error = Some(e.to_string());
+                        continue;
+                    }
+                }
+            }
+            match request.send_string(&body.to_string()) {
+                Ok(response) => {
+                    let key: KeyVersion = response
+                        .into_string()
+                        .map_err(|e| e.to_string())
+                        .and_then(|body| serde_json::from_str(&body).map_err(|e| e.to_string()))
+                        .map_err(|e| {
+                            HdfsErr::Miscellaneous(format!(
+                                "Invalid KMS response from {}: {}",
+                                base, e
+                            ))
+                        })?;
+                    return decode_material(&key.material);
+                }
+                Err(ureq::Error::Status(status, response)) => {
+                    let message = format!(
+                        "KMS {} could not decrypt the key of version {}: {} {}",
+                        base,
+                        info.ez_key_version_name,
+                        status,
+                        remote_message(response)
+                    );
+                    warn!("{}", message);
+                    error = Some(message);
+                }
+                Err(e) => {
+                    warn!("Could not reach KMS {}: {}", base, e);
+                    error = Some(format!("Could not reach KMS {}: {}", base, e));
+                }
+            }
+        }
+        Err(HdfsErr::Miscellaneous(format!(
+            "No KMS of {:?} could decrypt the key of version {}, the last error: {}",
+            self.urls.iter().map(Url::as_str).collect::<Vec<_>>(),
+            info.ez_key_version_name,
+            error.unwrap_or_default()
+        )))
+    }
+
+    /// `<base>/v1/keyversion/<version>/_eek?eek_op=decrypt`
+    fn decrypt_url(&self, base: &Url, version_name: &str) -> Url {
+        let mut url = base.clone();
+        url.path_segments_mut()
+            .expect("KMS URLs are http or https")
+            .pop_if_empty()
+            .extend(["v1", "keyversion", version_name, "_eek"]);
+        url.query_pairs_mut().append_pair("eek_op", "decrypt");
+        if let KmsAuth::Simple(Some(user)) = &self.auth {
+            url.query_pairs_mut().append_pair("user.name", user);
+        }
+        if let Some(proxy_user) = &self.proxy_user {
+            url.query_pairs_mut().append_pair("doAs", proxy_user);
+        }
+        url
+    }
+}
+
+/// A base64 encoded SPNEGO token for the `HTTP/<host>` principal, from the credentials of a
+/// Kerberos ticket cache
+fn spnego_token(host: &str, ticket_cache: Option<&Path>) -> Result<String, HdfsErr> {
+    let invalid = |what: &str| HdfsErr::Miscellaneous(format!("Invalid {}", what));
+    let host = CString::new(host).map_err(|_| invalid("KMS host"))?;
+    let ticket_cache = ticket_cache
+        .map(|path| CString::new(path.to_string_lossy().as_bytes()))
+        .transpose()
+        .map_err(|_| invalid("Kerberos ticket cache path"))?;
+    let mut token: *mut c_char = std::ptr::null_mut();
+    let mut length: c_int = 0;
+    let res = unsafe {
+        hdfsGetSpnegoToken(
+            host.as_ptr(),
+            ticket_cache
+                .as_ref()
+                .map_or(std::ptr::null(), |path| path.as_ptr()),
+            &mut token,
+            &mut length,
+        )
+    };
+    if res != 0 {
+        return Err(HdfsErr::Miscellaneous(last_error()));
+    }
+    let encoded =
+        STANDARD.encode(unsafe { std::slice::from_raw_parts(token as *const u8, length as usize) });
+    unsafe { hdfsFreeSpnegoToken(token) };
+    Ok(encoded)
+}
+
+/// A key version returned by the KMS
+#[derive(Deserialize)]
+struct KeyVersion {
+    material: String,
+}
+
+/// The error a KMS returns, e.g.
+/// `{"RemoteException":{"exception":"AuthorizationException","message":"..."}}`
+#[derive(Deserialize)]
+struct RemoteError {
+    #[serde(rename = "RemoteException")]
+    remote_exception: RemoteException,
+}
+
+#[derive(Deserialize)]
+struct RemoteException {
+    message: String,
+}
+
+fn remote_message(response: ureq::Response) -> String {
+    let body = response.into_string().unwrap_or_default();
+    match serde_json::from_str::<RemoteError>(&body) {
+        Ok(error) => error.remote_exception.message,
+        Err(_) => body,
+    }
+}
+
+/// Decode key material, which the KMS encodes as URL-safe base64, but older versions as
+/// standard base64
+fn decode_material(material: &str) -> Result<Vec<u8>, HdfsErr> {
+    let material: String = material
+        .trim_end_matches('=')
+        .chars()
+        .map(|c| match c {
+            '+' => '-',
+            '/' => '_',
+            c => c,
+        })
+        .collect();
+    URL_SAFE_NO_PAD
+        .decode(material)
+        .map_err(|e| HdfsErr::Miscellaneous(format!("Invalid key material from the KMS: {}", e)))
+}
+
+/// The base URLs of the KMS instances of a KMS URL or key provider URI
+fn kms_urls(uri: &str) -> Result<Vec<Url>, HdfsErr> {
+    let invalid = || HdfsErr::InvalidUrl(uri.to_owned());
+    let uris = match uri.strip_prefix("kms://") {
+        // kms://<scheme>@<host1>;<host2>:<port>/<path>
+        Some(rest) => {
+            let (scheme, rest) = rest.split_once('@').ok_or_else(invalid)?;
+            let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+            let (hosts, port) = match authority.rsplit_once(':') {
+                Some((hosts, port)) => (hosts, format!(":{}", port)),
+                None => (authority, String::new()),
+            };
+            hosts
+                .split(';')
+                .map(|host| format!("{}://{}{}{}", scheme, host, port, path))
+                .collect()
+        }
+        None => vec![uri.to_owned()],
+    };
+    uris.iter()
+        .map(|uri| match Url::parse(uri) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => Ok(url),
+            _ => Err(invalid()),
+        })
+        .collect()
+}
@@ -1,6 +1,9 @@
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
+// the layout tests bindgen generated for the bindings take field offsets through null
+// pointers
+#![cfg_attr(test, allow(deref_nullptr))]
 
 // Include the bindings directly instead of autogenerating them
 // at build time. This is because some systems may not have the
@@ -9,5 +12,20 @@
 // include!(concat!(env!("OUT_DIR"), "/hdfs3_bindings.rs"));
 include!("hdfs3_bindings.rs");
 
+pub mod acl;
+pub mod ccache;
+pub mod config;
+pub mod credentials;
+pub mod encryption;
 pub mod err;
 pub mod hdfs3;
+pub mod kerberos;
+pub mod kms;
+pub mod path;
+pub mod permission;
+pub mod profile;
+pub mod snapshot;
+pub mod stats;
+pub mod summary;
+pub mod token;
+pub mod xattr;
@@ -0,0 +1,418 @@
+use std::borrow::{Borrow, Cow};
+use std::ffi::CString;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::err::HdfsErr;
+
+/// URI scheme of HDFS paths
+pub const HDFS_SCHEME: &str = "hdfs";
+
+/// A borrowed HDFS path, either a plain path such as `/data/logs` or `logs/today`, or a URI
+/// such as `hdfs://namenode:8020/data/logs`. It is to [`HdfsPathBuf`] what
+/// [`std::path::Path`] is to [`std::path::PathBuf`].
+///
+/// Like file names on Unix, HDFS paths are bytes, which are usually but not necessarily
+/// UTF-8. [`HdfsPath::to_str`] checks that they are, while `Display` replaces invalid UTF-8.
+///
+/// Operations are lexical: they do not access the filesystem, and other than
+/// [`HdfsPath::normalize`] and [`HdfsPath::join`] they keep the path as it was written.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct HdfsPath([u8]);
+
+impl HdfsPath {
+    pub fn new<S: AsRef<[u8]> + ?Sized>(path: &S) -> &HdfsPath {
+        // safe because HdfsPath is a transparent wrapper of [u8]
+        unsafe { &*(path.as_ref() as *const [u8] as *const HdfsPath) }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// The path as a string, failing with [`HdfsErr::InvalidUtf8`] if it is not UTF-8
+    pub fn to_str(&self) -> Result<&str, HdfsErr> {
+        std::str::from_utf8(&self.0).map_err(|_| HdfsErr::InvalidUtf8(self.to_string()))
+    }
+
+    /// The path as a string, with invalid UTF-8 replaced by `U+FFFD`
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.0)
+    }
+
+    /// The path as a C string, failing with [`HdfsErr::InvalidUrl`] if it contains a NUL,
+    /// which [`HdfsFs::qualify`](crate::hdfs3::HdfsFs::qualify) already rejects
+    pub(crate) fn to_c_string(&self) -> Result<CString, HdfsErr> {
+        CString::new(&self.0).map_err(|_| HdfsErr::InvalidUrl(self.to_string()))
+    }
+
+    pub fn to_path_buf(&self) -> HdfsPathBuf {
+        HdfsPathBuf(self.0.to_owned())
+    }
+
+    /// The scheme of a URI, e.g. `hdfs`, `None` for a plain path
+    pub fn scheme(&self) -> Option<&str> {
+        self.split_uri().0.map(|(scheme, _)| scheme)
+    }
+
+    /// The authority of a URI, e.g. `namenode:8020`, `None` for a plain path. It is empty
+    /// for URIs such as `hdfs:///data`, which refer to the default filesystem.
+    pub fn authority(&self) -> Option<&str> {
+        self.split_uri().0.map(|(_, authority)| authority)
+    }
+
+    /// The path without the scheme and authority of a URI, e.g. `/data` for
+    /// `hdfs://namenode:8020/data`
+    pub fn without_authority(&self) -> &HdfsPath {
+        HdfsPath::new(self.split_uri().1)
+    }
+
+    /// Is the path absolute? URIs always are.
+    pub fn is_absolute(&self) -> bool {
+        self.scheme().is_some() || self.0.starts_with(b"/")
+    }
+
+    /// Is the path relative to the home directory, i.e. `~` or starting with `~/`?
+    pub fn is_home_relative(&self) -> bool {
+        self.0 == *b"~" || self.0.starts_with(b"~/")
+    }
+
+    /// The names in the path, without empty and `.` components
+    pub fn components(&self) -> impl Iterator<Item = &[u8]> {
+        self.without_authority()
+            .0
+            .split(|b| *b == b'/')
+            .filter(|c| !c.is_empty() && *c != b".")
+    }
+
+    /// Remove `.` components, repeated and trailing slashes and resolve `..` components,
+    /// the way the namenode does. `..` at the root is the root, while leading `..`
+    /// components of a relative path are kept.
+    pub fn normalize(&self) -> HdfsPathBuf {
+        let mut components: Vec<&[u8]> = Vec::new();
+        for component in self.components() {
+            match component {
+                b".." if components.last().is_some_and(|last| *last != b"..") => {
+                    components.pop();
+                }
+                b".." if self.is_absolute() => {}
+                component => components.push(component),
+            }
+        }
+        let path = components.join(&b'/');
+        HdfsPathBuf(match self.split_uri().0 {
+            Some((scheme, authority)) => {
+                [format!("{}://{}/", scheme, authority).as_bytes(), &path].concat()
+            }
+            None if self.is_absolute() => [b"/", &path[..]].concat(),
+            None if path.is_empty() => b".".to_vec(),
+            None => path,
+        })
+    }
+
+    /// Append a path to this one and normalize the result. If `path` is absolute, it
+    /// replaces this one.
+    pub fn join(&self, path: impl AsRef<HdfsPath>) -> HdfsPathBuf {
+        let path = path.as_ref();
+        if path.is_absolute() {
+            path.normalize()
+        } else {
+            HdfsPath::new(&[&self.0, b"/", &path.0].concat()).normalize()
+        }
+    }
+
+    /// The path without its last component, `None` for the root or a single relative
+    /// component
+    pub fn parent(&self) -> Option<&HdfsPath> {
+        let prefix = self.0.len() - self.without_authority().0.len();
+        let path = trim_end_dots(self.without_authority().as_bytes());
+        let parent = trim_end_dots(&path[..path.iter().rposition(|b| *b == b'/')?]);
+        let end = if parent.is_empty() && self.is_absolute() {
+            // the root
+            prefix + 1
+        } else {
+            prefix + parent.len()
+        };
+        Some(HdfsPath::new(&self.0[..end]))
+    }
+
+    /// The last component of the path, `None` for the root or if it is `..`
+    pub fn file_name(&self) -> Option<&[u8]> {
+        self.components().last().filter(|name| *name != b"..")
+    }
+
+    /// The extension of the file name, e.g. `gz` for `logs.tar.gz`, `None` if it has none.
+    /// A leading `.`, as in `.hidden`, does not start an extension.
+    pub fn extension(&self) -> Option<&[u8]> {
+        let name = self.file_name()?;
+        match name.iter().rposition(|b| *b == b'.') {
+            Some(dot) if dot > 0 => Some(&name[dot + 1..]),
+            _ => None,
+        }
+    }
+
+    /// The rest of the path after `base`, which must be a prefix made of whole components,
+    /// e.g. `b/c` for `/data/b/c` and `/data`. It is empty if the paths are the same, and
+    /// `None` if `base` is not a prefix.
+    pub fn strip_prefix(&self, base: impl AsRef<HdfsPath>) -> Option<&HdfsPath> {
+        let base = base.as_ref();
+        if self.split_uri().0 != base.split_uri().0 || self.is_absolute() != base.is_absolute() {
+            return None;
+        }
+        let path = self.without_authority().as_bytes();
+        let mut components = path
+            .split(|b| *b == b'/')
+            .scan(0, |offset, c| {
+                let start = *offset;
+                *offset += c.len() + 1;
+                Some((start, c))
+            })
+            .filter(|(_, c)| !c.is_empty() && *c != b".");
+        for name in base.components() {
+            match components.next() {
+                Some((_, c)) if c == name => {}
+                _ => return None,
+            }
+        }
+        Some(HdfsPath::new(match components.next() {
+            Some((start, _)) => &path[start..],
+            None => &[],
+        }))
+    }
+
+    /// The URI of the path on the namenode with the given authority, e.g.
+    /// `hdfs://namenode:8020/data` for `/data`. Fails for relative paths, which have no URI.
+    pub fn to_uri(&self, authority: &str) -> Result<HdfsPathBuf, HdfsErr> {
+        if !self.is_absolute() {
+            return Err(HdfsErr::InvalidUrl(format!(
+                "{}: relative paths have no URI",
+                self
+            )));
+        }
+        let path = HdfsPath::new("/").join(self.without_authority());
+        Ok(HdfsPathBuf(
+            [
+                format!("{}://{}", HDFS_SCHEME, authority).as_bytes(),
+                &path.0,
+            ]
+            .concat(),
+        ))
+    }
+
+    /// Split a URI into its scheme and authority and its path
+    fn split_uri(&self) -> (Option<(&str, &str)>, &[u8]) {
+        if let Some(colon) = self.0.windows(3).position(|w| w == b"://") {
+            let (scheme, rest) = (&self.0[..colon], &self.0[colon + 3..]);
+            let is_scheme = scheme.first().is_some_and(u8::is_ascii_alphabetic)
+                && scheme
+                    .iter()
+                    .all(|b| b.is_ascii_alphanumeric() || b"+-.".contains(b));
+            let (authority, path) =
+                rest.split_at(rest.iter().position(|b| *b == b'/').unwrap_or(rest.len()));
+            // host names are ASCII, so a URI with any other authority is not one
+            if let (true, Ok(scheme), Ok(authority)) = (
+                is_scheme,
+                std::str::from_utf8(scheme),
+                std::str::from_utf8(authority),
+            ) {
+                return (Some((scheme, authority)), path);
+            }
+        }
+        (None, &self.0)
+    }
+}
+
+impl Display for HdfsPath {
+    /// Format the path with invalid UTF-8 replaced by `U+FFFD`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_string_lossy())
+    }
+}
+
+impl Debug for HdfsPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.to_string_lossy(), f)
+    }
+}
+
+impl AsRef<HdfsPath> for HdfsPath {
+    fn as_ref(&self) -> &HdfsPath {
+        self
+    }
+}
+
+impl AsRef<HdfsPath> for str {
+    fn as_ref(&self) -> &HdfsPath {
+        HdfsPath::new(self)
+    }
+}
+
+impl AsRef<HdfsPath> for String {
+    fn as_ref(&self) -> &HdfsPath {
+        HdfsPath::new(self)
+    }
+}
+
+impl AsRef<HdfsPath> for [u8] {
+    fn as_ref(&self) -> &HdfsPath {
+        HdfsPath::new(self)
+    }
+}
+
+impl<const N: usize> AsRef<HdfsPath> for [u8; N] {
+    fn as_ref(&self) -> &HdfsPath {
+        HdfsPath::new(self)
+    }
+}
+
+impl AsRef<HdfsPath> for Vec<u8> {
+    fn as_ref(&self) -> &HdfsPath {
+        HdfsPath::new(self)
+    }
+}
+
+impl AsRef<[u8]> for HdfsPath {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<&HdfsPath> for Vec<u8> {
+    fn from(path: &HdfsPath) -> Self {
+        path.0.to_vec()
+    }
+}
+
+impl ToOwned for HdfsPath {
+    type Owned = HdfsPathBuf;
+
+    fn to_owned(&self) -> HdfsPathBuf {
+        self.to_path_buf()
+    }
+}
+
+/// An owned HDFS path, see [`HdfsPath`]
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HdfsPathBuf(Vec<u8>);
+
+impl HdfsPathBuf {
+    pub fn as_path(&self) -> &HdfsPath {
+        HdfsPath::new(&self.0)
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+
+    /// The path as a string, failing with [`HdfsErr::InvalidUtf8`] if it is not UTF-8
+    pub fn into_string(self) -> Result<String, HdfsErr> {
+        String::from_utf8(self.0)
+            .map_err(|e| HdfsErr::InvalidUtf8(String::from_utf8_lossy(e.as_bytes()).into_owned()))
+    }
+}
+
+impl Deref for HdfsPathBuf {
+    type Target = HdfsPath;
+
+    fn deref(&self) -> &HdfsPath {
+        self.as_path()
+    }
+}
+
+impl Borrow<HdfsPath> for HdfsPathBuf {
+    fn borrow(&self) -> &HdfsPath {
+        self.as_path()
+    }
+}
+
+impl AsRef<HdfsPath> for HdfsPathBuf {
+    fn as_ref(&self) -> &HdfsPath {
+        self.as_path()
+    }
+}
+
+impl AsRef<[u8]> for HdfsPathBuf {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Display for HdfsPathBuf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self.as_path(), f)
+    }
+}
+
+impl Debug for HdfsPathBuf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self.as_path(), f)
+    }
+}
+
+impl From<String> for HdfsPathBuf {
+    /// Wrap a path as it is, without normalizing or validating it
+    fn from(path: String) -> Self {
+        HdfsPathBuf(path.into_bytes())
+    }
+}
+
+impl From<&str> for HdfsPathBuf {
+    /// Wrap a path as it is, without normalizing or validating it
+    fn from(path: &str) -> Self {
+        HdfsPathBuf(path.as_bytes().to_vec())
+    }
+}
+
+impl From<Vec<u8>> for HdfsPathBuf {
+    /// Wrap a path as it is, without normalizing or validating it
+    fn from(path: Vec<u8>) -> Self {
+        HdfsPathBuf(path)
+    }
+}
+
+impl From<&[u8]> for HdfsPathBuf {
+    /// Wrap a path as it is, without normalizing or validating it
+    fn from(path: &[u8]) -> Self {
+        HdfsPathBuf(path.to_vec())
+    }
+}
+
+impl FromStr for HdfsPathBuf {
+    type Err = HdfsErr;
+
+    /// Parse and normalize a path or an `hdfs://authority/...` URI. A URI without an
+    /// authority, such as `hdfs:///data`, is the plain path `/data`, and one without a path,
+    /// such as `hdfs://namenode:8020` or `hdfs://`, names the root.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let path = HdfsPath::new(s);
+        if s.is_empty() {
+            return Err(HdfsErr::InvalidUrl("empty path".to_owned()));
+        }
+        match path.split_uri().0 {
+            Some((scheme, _)) if !scheme.eq_ignore_ascii_case(HDFS_SCHEME) => {
+                Err(HdfsErr::InvalidUrl(s.to_owned()))
+            }
+            Some((_, "")) => Ok(HdfsPath::new("/").join(path.without_authority())),
+            // the path of a URI with only an authority, e.g. `hdfs://namenode:8020`, is `/`
+            Some((_, authority)) => HdfsPath::new("/")
+                .join(path.without_authority())
+                .to_uri(authority),
+            None => Ok(path.normalize()),
+        }
+    }
+}
+
+/// Remove trailing slashes and `.` components
+fn trim_end_dots(mut path: &[u8]) -> &[u8] {
+    loop {
+        while let Some(rest) = path.strip_suffix(b"/") {
+            path = rest;
+        }
+        match path.strip_suffix(b"/.") {
+            Some(rest) => path = rest,
+            None => return path,
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use std::ffi::{CStr, CString};
+use std::fmt::{Display, Formatter};
+
+use libc::{c_char, c_int, gid_t};
+
+const SYMBOLS: [&str; 8] = ["---", "--x", "-w-", "-wx", "r--", "r-x", "rw-", "rwx"];
+
+/// An action on a file or directory, as given by one class of its permission bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FsAction {
+    None,
+    Execute,
+    Write,
+    WriteExecute,
+    Read,
+    ReadExecute,
+    ReadWrite,
+    All,
+}
+
+impl FsAction {
+    /// The action given by the lowest three bits, e.g. `0o5` for `ReadExecute`
+    pub fn from_bits(bits: u16) -> FsAction {
+        match bits & 0o7 {
+            0 => FsAction::None,
+            1 => FsAction::Execute,
+            2 => FsAction::Write,
+            3 => FsAction::WriteExecute,
+            4 => FsAction::Read,
+            5 => FsAction::ReadExecute,
+            6 => FsAction::ReadWrite,
+            _ => FsAction::All,
+        }
+    }
+
+    #[inline]
+    pub fn bits(self) -> u16 {
+        self as u16
+    }
+
+    /// Does this action include all of `other`?
+    #[inline]
+    pub fn implies(self, other: FsAction) -> bool {
+        self.bits() & other.bits() == other.bits()
+    }
+
+    #[inline]
+    pub fn and(self, other: FsAction) -> FsAction {
+        FsAction::from_bits(self.bits() & other.bits())
+    }
+
+    #[inline]
+    pub fn or(self, other: FsAction) -> FsAction {
+        FsAction::from_bits(self.bits() | other.bits())
+    }
+
+    /// The symbolic form of the action, e.g. `r-x`
+    pub fn symbol(self) -> &'static str {
+        SYMBOLS[self.bits() as usize]
+    }
+
+    /// The action of a symbolic form, e.g. `ReadExecute` for `r-x`, `None` if it is not one
+    pub fn from_symbol(symbol: &str) -> Option<FsAction> {
+        SYMBOLS
+            .iter()
+            .position(|s| *s == symbol)
+            .map(|bits| FsAction::from_bits(bits as u16))
+    }
+}
+
+impl Display for FsAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// The actions permitted to a user on a file or directory, from the owner bits if the user
+/// owns it, otherwise the group bits if the user is a member of its group, otherwise the
+/// other bits. This is how the namenode evaluates permissions; there is no fallthrough to
+/// a more permissive class.
+///
+/// * owner - owner of the file
+/// * group - group of the file
+/// * permission - permission bits of the file, e.g. `0o750`
+/// * user - the user accessing the file
+/// * groups - the groups of the user
+pub fn permitted_action(
+    owner: &str,
+    group: &str,
+    permission: u16,
+    user: &str,
+    groups: &[String],
+) -> FsAction {
+    if owner == user {
+        FsAction::from_bits(permission >> 6)
+    } else if groups.iter().any(|g| g == group) {
+        FsAction::from_bits(permission >> 3)
+    } else {
+        FsAction::from_bits(permission)
+    }
+}
+
+/// The path and the action of an access denied by the namenode, parsed from the message of
+/// its `AccessControlException`, e.g.
+/// `Permission denied: user=alice, access=WRITE, inode="/data/out":etl:etl:drwxr-xr-x`.
+/// `None` if the message does not name them.
+pub fn parse_access_denied(message: &str) -> Option<(String, FsAction)> {
+    let access = message.split("access=").nth(1)?;
+    let end = access
+        .find(|c: char| c != '_' && !c.is_ascii_alphabetic())
+        .unwrap_or(access.len());
+    let action = match &access[..end] {
+        "NONE" => FsAction::None,
+        "EXECUTE" => FsAction::Execute,
+        "WRITE" => FsAction::Write,
+        "WRITE_EXECUTE" => FsAction::WriteExecute,
+        "READ" => FsAction::Read,
+        "READ_EXECUTE" => FsAction::ReadExecute,
+        "READ_WRITE" => FsAction::ReadWrite,
+        "ALL" => FsAction::All,
+        _ => return None,
+    };
+    let inode = message.split("inode=\"").nth(1)?;
+    let path = &inode[..inode.find('"')?];
+    Some((path.to_owned(), action))
+}
+
+/// The path of a change denied by the namenode because only its owner or the superuser may
+/// make it, such as changing its ACL, parsed from the message of its
+/// `AccessControlException`, e.g.
+/// `Permission denied. user=bob is not the owner of inode=/data/out`. `None` if the message
+/// is not of this kind.
+pub fn parse_not_owner(message: &str) -> Option<String> {
+    let inode = message.split(" is not the owner of inode=").nth(1)?;
+    let path = inode.lines().next().unwrap_or_default().trim_end();
+    if path.is_empty() {
+        None
+    } else {
+        Some(path.to_owned())
+    }
+}
+
+/// Name of the effective user of the process, `None` if it cannot be resolved.
+pub(crate) fn local_user() -> Option<String> {
+    let mut buf = vec![0 as c_char; 16384];
+    let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut result = std::ptr::null_mut();
+    let rc = unsafe {
+        libc::getpwuid_r(
+            libc::geteuid(),
+            &mut pwd,
+            buf.as_mut_ptr(),
+            buf.len(),
+            &mut result,
+        )
+    };
+    if rc != 0 || result.is_null() {
+        return None;
+    }
+    Some(
+        unsafe { CStr::from_ptr(pwd.pw_name) }
+            .to_string_lossy()
+            .into_owned(),
+    )
+}
+
+/// Groups of a user according to the local system, which is how the namenode resolves them
+/// with the default `ShellBasedUnixGroupsMapping` when client and namenode share a user
+/// directory. Empty if the user is unknown.
+pub(crate) fn local_groups(user: &str) -> Vec<String> {
+    let cstr_user = match CString::new(user) {
+        Ok(cstr_user) => cstr_user,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut buf = vec![0 as c_char; 16384];
+    let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut result = std::ptr::null_mut();
+    let rc = unsafe {
+        libc::getpwnam_r(
+            cstr_user.as_ptr(),
+            &mut pwd,
+            buf.as_mut_ptr(),
+            buf.len(),
+            &mut result,
+        )
+    };
+    if rc != 0 || result.is_null() {
+        return Vec::new();
+    }
+
+    let mut gids: Vec<gid_t> = vec![0; 64];
+    loop {
+        let mut count = gids.len() as c_int;
+        let rc = unsafe {
+            libc::getgrouplist(
+                cstr_user.as_ptr(),
+                pwd.pw_gid,
+                gids.as_mut_ptr(),
+                &mut count,
+            )
+        };
+        if rc >= 0 {
+            gids.truncate(count as usize);
+            break;
+        }
+        gids.resize((count as usize).max(gids.len() * 2), 0);
+    }
+
+    gids.into_iter().filter_map(group_name).collect()
+}
+
+fn group_name(gid: gid_t) -> Option<String> {
+    let mut buf = vec![0 as c_char; 16384];
+    let mut grp: libc::group = unsafe { std::mem::zeroed() };
+    let mut result = std::ptr::null_mut();
+    let rc = unsafe { libc::getgrgid_r(gid, &mut grp, buf.as_mut_ptr(), buf.len(), &mut result) };
+    if rc != 0 || result.is_null() {
+        return None;
+    }
+    Some(
+        unsafe { CStr::from_ptr(grp.gr_name) }
+            .to_string_lossy()
+            .into_owned(),
+    )
+}
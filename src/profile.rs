@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::env;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::config::ClientConfig;
+use crate::err::HdfsErr;
+use crate::hdfs3::ConnectionProperties;
+
+/// Environment variable giving the path of the profile file
+pub const PROFILES_ENV: &str = "LIBHDFS3_PROFILES";
+
+/// Connection properties and client configuration of a named cluster.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Profile {
+    pub connection: ConnectionProperties,
+    pub config: ClientConfig,
+}
+
+/// Named cluster profiles, such as `prod`, `dr` and `dev`, loaded from a TOML or JSON file.
+///
+/// A TOML profile file looks like this:
+///
+/// ```toml
+/// [prod.connection]
+/// namenode_host = "prod"
+/// namenodes = ["nn1.prod.example.com:8020", "nn2.prod.example.com:8020"]
+/// namenode_user = "etl"
+///
+/// [prod.config]
+/// auth_method = "kerberos"
+/// rpc_timeout = 30000
+///
+/// [dev.connection]
+/// namenode_host = "localhost"
+/// namenode_port = 8020
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Profiles {
+    profiles: HashMap<String, Profile>,
+}
+
+impl Profiles {
+    /// Path of the default profile file: `LIBHDFS3_PROFILES` if set, otherwise
+    /// `~/.libhdfs3/profiles.toml`.
+    pub fn default_path() -> Option<PathBuf> {
+        env::var_os(PROFILES_ENV).map(PathBuf::from).or_else(|| {
+            env::var_os("HOME").map(|home| PathBuf::from(home).join(".libhdfs3/profiles.toml"))
+        })
+    }
+
+    /// Load the profiles from the default profile file.
+    pub fn load_default() -> Result<Profiles, HdfsErr> {
+        let path = Profiles::default_path().ok_or_else(|| {
+            HdfsErr::InvalidConfig(format!("{}: no profile file found", PROFILES_ENV))
+        })?;
+        Profiles::load(path)
+    }
+
+    /// Load the profiles from a file. Files with a `.json` extension are read as JSON, any
+    /// other file as TOML.
+    pub fn load(path: impl AsRef<Path>) -> Result<Profiles, HdfsErr> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            HdfsErr::InvalidConfig(format!("{}: could not be read: {}", path.display(), e))
+        })?;
+        let profiles = if path.extension().is_some_and(|ext| ext == "json") {
+            Profiles::from_json(&content)
+        } else {
+            Profiles::from_toml(&content)
+        };
+        profiles.map_err(|e| match e {
+            HdfsErr::InvalidConfig(reason) => {
+                HdfsErr::InvalidConfig(format!("{}: {}", path.display(), reason))
+            }
+            e => e,
+        })
+    }
+
+    /// Parse profiles from TOML.
+    pub fn from_toml(content: &str) -> Result<Profiles, HdfsErr> {
+        let profiles: Profiles =
+            toml::from_str(content).map_err(|e| HdfsErr::InvalidConfig(e.to_string()))?;
+        profiles.validate()?;
+        Ok(profiles)
+    }
+
+    /// Parse profiles from JSON.
+    pub fn from_json(content: &str) -> Result<Profiles, HdfsErr> {
+        let profiles: Profiles =
+            serde_json::from_str(content).map_err(|e| HdfsErr::InvalidConfig(e.to_string()))?;
+        profiles.validate()?;
+        Ok(profiles)
+    }
+
+    /// Get the profile with the given name.
+    pub fn get(&self, name: &str) -> Result<&Profile, HdfsErr> {
+        self.profiles
+            .get(name)
+            .ok_or_else(|| HdfsErr::InvalidConfig(format!("profile {}: not found", name)))
+    }
+
+    /// Names of the profiles, sorted.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = self
+            .profiles
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<&str>>();
+        names.sort_unstable();
+        names
+    }
+
+    fn validate(&self) -> Result<(), HdfsErr> {
+        for (name, profile) in &self.profiles {
+            let in_profile = |e: HdfsErr| match e {
+                HdfsErr::InvalidConfig(reason) => {
+                    HdfsErr::InvalidConfig(format!("profile {}: {}", name, reason))
+                }
+                e => e,
+            };
+            if profile.connection.namenode_host.is_empty() {
+                return Err(in_profile(HdfsErr::InvalidConfig(
+                    "namenode_host: not configured".to_owned(),
+                )));
+            }
+            profile.connection.validate().map_err(in_profile)?;
+            profile.config.validate().map_err(in_profile)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,294 @@
+use std::ffi::CStr;
+use std::fmt::{Display, Formatter};
+
+use libc::{c_char, time_t};
+
+use crate::path::{HdfsPath, HdfsPathBuf};
+use crate::{hdfsSnapshotDiffReport, hdfsSnapshottableDirInfo};
+
+/// Name of the hidden directory under a snapshottable directory through which its
+/// snapshots are read
+pub const SNAPSHOT_DIR: &str = ".snapshot";
+
+/// A directory which snapshots can be taken of
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshottableDirectory {
+    /// Full path of the directory
+    pub path: HdfsPathBuf,
+    pub owner: String,
+    pub group: String,
+    pub permission: i16,
+    /// Last modification time in seconds since the epoch
+    pub last_modified: time_t,
+    /// Number of snapshots of the directory
+    pub snapshot_number: i32,
+    /// Maximum number of snapshots of the directory
+    pub snapshot_quota: i32,
+}
+
+/// The kind of change a snapshot diff entry records
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DiffType {
+    Create,
+    Modify,
+    Delete,
+    /// Renamed to `target`, relative to the snapshot root
+    Rename {
+        target: HdfsPathBuf,
+    },
+    /// A change of a type this version does not know, with the raw type reported by libhdfs3
+    Unknown(i32),
+}
+
+impl DiffType {
+    /// The label `hdfs snapshotDiff` uses for the change, e.g. `+` for `Create`
+    pub fn label(&self) -> &'static str {
+        match self {
+            DiffType::Create => "+",
+            DiffType::Modify => "M",
+            DiffType::Delete => "-",
+            DiffType::Rename { .. } => "R",
+            DiffType::Unknown(_) => "?",
+        }
+    }
+}
+
+/// A file or directory which differs between two snapshots
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SnapshotDiffEntry {
+    pub diff_type: DiffType,
+    /// Path relative to the snapshot root, empty for the root itself
+    pub path: HdfsPathBuf,
+}
+
+impl SnapshotDiffEntry {
+    /// Relative path of the entry in the later snapshot, which is the target of a rename
+    pub fn target(&self) -> &HdfsPath {
+        match &self.diff_type {
+            DiffType::Rename { target } => target,
+            _ => &self.path,
+        }
+    }
+}
+
+impl Display for SnapshotDiffEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let display = |path: &HdfsPath| {
+            if path.as_bytes().is_empty() {
+                "./.".to_owned()
+            } else {
+                format!("./{}", path)
+            }
+        };
+        match &self.diff_type {
+            DiffType::Rename { target } => {
+                write!(f, "R\t{} -> {}", display(&self.path), display(target))
+            }
+            diff_type => write!(f, "{}\t{}", diff_type.label(), display(&self.path)),
+        }
+    }
+}
+
+/// The differences between two snapshots of a directory, as reported by
+/// `hdfs snapshotDiff`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotDiffReport {
+    /// Path of the snapshottable directory
+    pub snapshot_root: HdfsPathBuf,
+    /// The earlier snapshot, `None` for the current state of the directory
+    pub from_snapshot: Option<String>,
+    /// The later snapshot, `None` for the current state of the directory
+    pub to_snapshot: Option<String>,
+    pub entries: Vec<SnapshotDiffEntry>,
+}
+
+impl SnapshotDiffReport {
+    pub fn created(&self) -> impl Iterator<Item = &SnapshotDiffEntry> {
+        self.of_type(|t| matches!(t, DiffType::Create))
+    }
+
+    pub fn modified(&self) -> impl Iterator<Item = &SnapshotDiffEntry> {
+        self.of_type(|t| matches!(t, DiffType::Modify))
+    }
+
+    pub fn deleted(&self) -> impl Iterator<Item = &SnapshotDiffEntry> {
+        self.of_type(|t| matches!(t, DiffType::Delete))
+    }
+
+    pub fn renamed(&self) -> impl Iterator<Item = &SnapshotDiffEntry> {
+        self.of_type(|t| matches!(t, DiffType::Rename { .. }))
+    }
+
+    /// The path to read an entry from in the earlier snapshot
+    pub fn from_path(&self, entry: &SnapshotDiffEntry) -> HdfsPathBuf {
+        snapshot_entry_path(
+            &self.snapshot_root,
+            self.from_snapshot.as_deref(),
+            &entry.path,
+        )
+    }
+
+    /// The path to read an entry from in the later snapshot, following renames
+    pub fn to_path(&self, entry: &SnapshotDiffEntry) -> HdfsPathBuf {
+        snapshot_entry_path(
+            &self.snapshot_root,
+            self.to_snapshot.as_deref(),
+            entry.target(),
+        )
+    }
+
+    fn of_type<'a>(
+        &'a self,
+        predicate: impl Fn(&DiffType) -> bool + 'a,
+    ) -> impl Iterator<Item = &'a SnapshotDiffEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| predicate(&entry.diff_type))
+    }
+}
+
+impl Display for SnapshotDiffReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let describe = |snapshot: &Option<String>| match snapshot {
+            Some(name) => format!("snapshot {}", name),
+            None => "current directory".to_owned(),
+        };
+        writeln!(
+            f,
+            "Difference between {} and {} under directory {}:",
+            describe(&self.from_snapshot),
+            describe(&self.to_snapshot),
+            self.snapshot_root
+        )?;
+        for entry in &self.entries {
+            writeln!(f, "{}", entry)?;
+        }
+        Ok(())
+    }
+}
+
+/// A path inside a snapshot, e.g. `/data/.snapshot/s1/a/b`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SnapshotPath {
+    /// Path of the snapshottable directory, e.g. `/data`
+    pub root: HdfsPathBuf,
+    /// Name of the snapshot, e.g. `s1`
+    pub name: String,
+    /// Path relative to the snapshot root, e.g. `a/b`, empty for the root itself
+    pub path: HdfsPathBuf,
+}
+
+impl SnapshotPath {
+    /// Split a path inside a snapshot into its parts, `None` if it is not inside a snapshot
+    /// or the name of the snapshot is not UTF-8, as the names given to
+    /// [`HdfsFs::create_snapshot`](crate::hdfs3::HdfsFs::create_snapshot) are.
+    pub fn parse(path: impl AsRef<HdfsPath>) -> Option<SnapshotPath> {
+        let path = path.as_ref();
+        let components: Vec<&[u8]> = path.components().collect();
+        let index = components
+            .iter()
+            .position(|c| *c == SNAPSHOT_DIR.as_bytes())?;
+        let name = std::str::from_utf8(components.get(index + 1)?).ok()?;
+        let base = HdfsPath::new(if path.is_absolute() { "/" } else { "." });
+        Some(SnapshotPath {
+            root: base.join(HdfsPath::new(&components[..index].join(&b'/'))),
+            name: name.to_owned(),
+            path: HdfsPathBuf::from(components[index + 2..].join(&b'/')),
+        })
+    }
+
+    /// The path of the same file or directory in the current state of the directory
+    pub fn current_path(&self) -> HdfsPathBuf {
+        join(&self.root, &self.path)
+    }
+}
+
+impl Display for SnapshotPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(
+            &snapshot_entry_path(&self.root, Some(&self.name), &self.path),
+            f,
+        )
+    }
+}
+
+/// The path of a snapshot of a directory, e.g. `/data/.snapshot/s1`
+pub fn snapshot_path(dir: impl AsRef<HdfsPath>, name: &str) -> HdfsPathBuf {
+    dir.as_ref().join(SNAPSHOT_DIR).join(name)
+}
+
+/// Is the path inside a snapshot?
+pub fn is_snapshot_path(path: impl AsRef<HdfsPath>) -> bool {
+    let mut components = path.as_ref().components();
+    components.any(|c| c == SNAPSHOT_DIR.as_bytes()) && components.next().is_some()
+}
+
+/// The path of an entry relative to a snapshot root, in the given snapshot or in the current
+/// state of the directory if `None`.
+fn snapshot_entry_path(root: &HdfsPath, snapshot: Option<&str>, path: &HdfsPath) -> HdfsPathBuf {
+    match snapshot {
+        Some(name) => join(&snapshot_path(root, name), path),
+        None => join(root, path),
+    }
+}
+
+/// Append a path relative to a snapshot root, which is empty for the root itself
+fn join(dir: &HdfsPath, path: &HdfsPath) -> HdfsPathBuf {
+    if path.as_bytes().is_empty() {
+        dir.to_path_buf()
+    } else {
+        dir.join(path)
+    }
+}
+
+pub(crate) fn snapshottable_directory(info: &hdfsSnapshottableDirInfo) -> SnapshottableDirectory {
+    SnapshottableDirectory {
+        path: HdfsPathBuf::from(c_bytes(info.mPath)),
+        owner: from_c_str(info.mOwner),
+        group: from_c_str(info.mGroup),
+        permission: info.mPermissions,
+        last_modified: info.mLastMod,
+        snapshot_number: info.mSnapshotNumber,
+        snapshot_quota: info.mSnapshotQuota,
+    }
+}
+
+pub(crate) fn snapshot_diff_report(report: &hdfsSnapshotDiffReport) -> SnapshotDiffReport {
+    let snapshot = |ptr| Some(from_c_str(ptr)).filter(|s| !s.is_empty());
+    let entries = (0..report.mNumEntries.max(0) as usize)
+        .map(|i| {
+            let entry = unsafe { &*report.mEntries.add(i) };
+            let diff_type = match entry.mType as u8 {
+                b'+' => DiffType::Create,
+                b'M' => DiffType::Modify,
+                b'-' => DiffType::Delete,
+                b'R' => DiffType::Rename {
+                    target: HdfsPathBuf::from(c_bytes(entry.mTargetPath)),
+                },
+                _ => DiffType::Unknown(entry.mType as i32),
+            };
+            SnapshotDiffEntry {
+                diff_type,
+                path: HdfsPathBuf::from(c_bytes(entry.mPath)),
+            }
+        })
+        .collect();
+    SnapshotDiffReport {
+        snapshot_root: HdfsPathBuf::from(c_bytes(report.mSnapshotRoot)),
+        from_snapshot: snapshot(report.mFromSnapshot),
+        to_snapshot: snapshot(report.mToSnapshot),
+        entries,
+    }
+}
+
+fn from_c_str(ptr: *const c_char) -> String {
+    String::from_utf8_lossy(c_bytes(ptr)).into_owned()
+}
+
+fn c_bytes<'a>(ptr: *const c_char) -> &'a [u8] {
+    if ptr.is_null() {
+        &[]
+    } else {
+        unsafe { CStr::from_ptr(ptr) }.to_bytes()
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+
+use libc::c_int;
+
+use crate::err::HdfsErr;
+use crate::{hdfsFsStats, hdfsServerDefaults};
+
+/// Space usage and block health of the cluster, as reported by `hdfs dfsadmin -report`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FsStats {
+    /// Raw capacity in bytes, which replicas count against
+    pub capacity: u64,
+    /// Raw space in bytes used by files
+    pub used: u64,
+    /// Raw space in bytes available for files
+    pub remaining: u64,
+    /// Number of blocks with fewer replicas than wanted, `None` if the namenode did not
+    /// report it
+    pub under_replicated_blocks: Option<u64>,
+    /// Number of blocks with at least one corrupt replica, `None` if unknown
+    pub corrupt_blocks: Option<u64>,
+    /// Number of blocks without any good replica, `None` if unknown
+    pub missing_blocks: Option<u64>,
+}
+
+impl FsStats {
+    /// Percentage of the capacity used by files, `DFS Used%` in `hdfs dfsadmin -report`
+    pub fn used_percent(&self) -> f64 {
+        percent(self.used, self.capacity)
+    }
+
+    /// Percentage of the capacity available for files, `DFS Remaining%`
+    pub fn remaining_percent(&self) -> f64 {
+        percent(self.remaining, self.capacity)
+    }
+
+    /// Are all blocks fully replicated, with no corrupt or missing blocks? False if any of
+    /// the counts is unknown.
+    pub fn is_healthy(&self) -> bool {
+        self.under_replicated_blocks == Some(0)
+            && self.corrupt_blocks == Some(0)
+            && self.missing_blocks == Some(0)
+    }
+}
+
+fn percent(value: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        value as f64 * 100.0 / total as f64
+    }
+}
+
+/// The checksum algorithm datanodes use to verify blocks, `dfs.checksum.type`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChecksumType {
+    Null,
+    Crc32,
+    Crc32c,
+    /// A type this version does not know, with the raw type reported by libhdfs3
+    Unknown(i32),
+}
+
+impl ChecksumType {
+    /// Size in bytes of each checksum, `None` if the type is unknown
+    pub fn size(&self) -> Option<usize> {
+        match self {
+            ChecksumType::Null => Some(0),
+            ChecksumType::Crc32 | ChecksumType::Crc32c => Some(4),
+            ChecksumType::Unknown(_) => None,
+        }
+    }
+
+    fn from_raw(checksum_type: c_int) -> ChecksumType {
+        match checksum_type {
+            0 => ChecksumType::Null,
+            1 => ChecksumType::Crc32,
+            2 => ChecksumType::Crc32c,
+            other => ChecksumType::Unknown(other),
+        }
+    }
+}
+
+impl Display for ChecksumType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChecksumType::Null => f.write_str("NULL"),
+            ChecksumType::Crc32 => f.write_str("CRC32"),
+            ChecksumType::Crc32c => f.write_str("CRC32C"),
+            ChecksumType::Unknown(raw) => write!(f, "UNKNOWN({})", raw),
+        }
+    }
+}
+
+impl FromStr for ChecksumType {
+    type Err = HdfsErr;
+
+    /// Parse a checksum type the way `dfs.checksum.type` is configured, e.g. `CRC32C`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "NULL" => Ok(ChecksumType::Null),
+            "CRC32" => Ok(ChecksumType::Crc32),
+            "CRC32C" => Ok(ChecksumType::Crc32c),
+            _ => Err(HdfsErr::InvalidConfig(format!(
+                "dfs.checksum.type: unknown checksum type {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// The defaults the namenode applies to files written by clients which do not configure
+/// their own
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServerDefaults {
+    /// Block size in bytes
+    pub block_size: u64,
+    pub replication: u16,
+    /// Number of data bytes covered by each checksum
+    pub bytes_per_checksum: u32,
+    /// Size in bytes of the packets written to datanodes
+    pub write_packet_size: u32,
+    /// Size in bytes of the buffer used to read and write files
+    pub file_buffer_size: u32,
+    /// Must data transfer to datanodes be encrypted?
+    pub encrypt_data_transfer: bool,
+    /// Interval between trash checkpoints, `None` if trash is disabled
+    pub trash_interval: Option<Duration>,
+    pub checksum_type: ChecksumType,
+}
+
+pub(crate) fn fs_stats(stats: &hdfsFsStats) -> FsStats {
+    FsStats {
+        capacity: stats.mCapacity.max(0) as u64,
+        used: stats.mUsed.max(0) as u64,
+        remaining: stats.mRemaining.max(0) as u64,
+        under_replicated_blocks: count(stats.mUnderReplicated),
+        corrupt_blocks: count(stats.mCorruptBlocks),
+        missing_blocks: count(stats.mMissingBlocks),
+    }
+}
+
+/// A count reported by the namenode, which is negative if unknown
+fn count(value: i64) -> Option<u64> {
+    u64::try_from(value).ok()
+}
+
+pub(crate) fn server_defaults(defaults: &hdfsServerDefaults) -> ServerDefaults {
+    ServerDefaults {
+        block_size: defaults.mBlockSize.max(0) as u64,
+        replication: defaults.mReplication.max(0) as u16,
+        bytes_per_checksum: defaults.mBytesPerChecksum.max(0) as u32,
+        write_packet_size: defaults.mWritePacketSize.max(0) as u32,
+        file_buffer_size: defaults.mFileBufferSize.max(0) as u32,
+        encrypt_data_transfer: defaults.mEncryptDataTransfer != 0,
+        trash_interval: Some(defaults.mTrashInterval)
+            .filter(|minutes| *minutes > 0)
+            .map(|minutes| Duration::from_secs(minutes as u64 * 60)),
+        checksum_type: ChecksumType::from_raw(defaults.mChecksumType),
+    }
+}
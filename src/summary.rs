@@ -0,0 +1,108 @@
+use std::fmt::{Display, Formatter};
+
+use crate::hdfsContentSummary;
+
+/// Quota value which leaves a quota unchanged
+pub(crate) const QUOTA_DONT_SET: i64 = i64::MAX;
+
+/// Quota value which clears a quota
+pub(crate) const QUOTA_RESET: i64 = -1;
+
+/// The disk usage, file and directory counts and quotas of a directory tree, as reported by
+/// `hdfs dfs -count -q`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContentSummary {
+    /// Total length in bytes of the files in the tree
+    pub length: u64,
+    pub file_count: u64,
+    /// Number of directories in the tree, including its root
+    pub directory_count: u64,
+    /// Disk space in bytes consumed by the tree, including replicas
+    pub space_consumed: u64,
+    /// Maximum number of files and directories in the tree, `None` if there is no quota
+    pub quota: Option<u64>,
+    /// Maximum disk space in bytes consumed by the tree, `None` if there is no quota
+    pub space_quota: Option<u64>,
+}
+
+impl ContentSummary {
+    /// Count a file of the given length and replication.
+    pub fn add_file(&mut self, length: u64, replication: u16) {
+        self.file_count += 1;
+        self.length += length;
+        self.space_consumed += length * replication as u64;
+    }
+
+    /// Count a directory.
+    pub fn add_directory(&mut self) {
+        self.directory_count += 1;
+    }
+
+    /// How many more files and directories the namespace quota allows, negative if the quota
+    /// has been exceeded, e.g. because it was set below the current usage.
+    pub fn remaining_quota(&self) -> Option<i64> {
+        self.quota
+            .map(|quota| quota as i64 - (self.file_count + self.directory_count) as i64)
+    }
+
+    /// How many more bytes the disk space quota allows, negative if it has been exceeded.
+    pub fn remaining_space_quota(&self) -> Option<i64> {
+        self.space_quota
+            .map(|quota| quota as i64 - self.space_consumed as i64)
+    }
+}
+
+impl Display for ContentSummary {
+    /// Format as `hdfs dfs -count -q` does: quota, remaining quota, space quota, remaining
+    /// space quota, directory count, file count and length.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (quota, remaining_quota) = quota_pair(self.quota, self.remaining_quota());
+        let (space_quota, remaining_space_quota) =
+            quota_pair(self.space_quota, self.remaining_space_quota());
+        write!(
+            f,
+            "{:>12} {:>15} {:>15} {:>15} {:>12} {:>12} {:>18}",
+            quota,
+            remaining_quota,
+            space_quota,
+            remaining_space_quota,
+            self.directory_count,
+            self.file_count,
+            self.length
+        )
+    }
+}
+
+fn quota_pair(quota: Option<u64>, remaining: Option<i64>) -> (String, String) {
+    match (quota, remaining) {
+        (Some(quota), Some(remaining)) => (quota.to_string(), remaining.to_string()),
+        _ => ("none".to_owned(), "inf".to_owned()),
+    }
+}
+
+/// The quota reported by the namenode, which is `-1` if there is none
+fn quota(value: i64) -> Option<u64> {
+    if value < 0 {
+        None
+    } else {
+        Some(value as u64)
+    }
+}
+
+pub(crate) fn content_summary(summary: &hdfsContentSummary) -> ContentSummary {
+    ContentSummary {
+        length: summary.mLength.max(0) as u64,
+        file_count: summary.mFileCount.max(0) as u64,
+        directory_count: summary.mDirectoryCount.max(0) as u64,
+        space_consumed: summary.mSpaceConsumed.max(0) as u64,
+        quota: quota(summary.mQuota),
+        space_quota: quota(summary.mSpaceQuota),
+    }
+}
+
+/// The value to pass to the namenode for a quota, `None` to leave it unchanged
+pub(crate) fn quota_value(quota: Option<u64>) -> i64 {
+    quota.map_or(QUOTA_DONT_SET, |quota| {
+        quota.min(i64::MAX as u64 - 1) as i64
+    })
+}
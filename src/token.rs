@@ -0,0 +1,214 @@
+use std::fmt::Formatter;
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+use log::*;
+use serde::{Deserialize, Serialize};
+
+use crate::credentials::Token;
+use crate::err::HdfsErr;
+use crate::hdfs3::HdfsFs;
+
+/// Delay before retrying a failed renewal
+const RENEW_RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// An HDFS delegation token.
+///
+/// The token is held in the URL-safe encoding used by Hadoop, which is what
+/// [`DelegationToken::as_str`], `Display` and serde produce, so it can be shipped to workers
+/// and used there with [`HdfsFs::new_with_token`].
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct DelegationToken {
+    encoded: String,
+}
+
+impl DelegationToken {
+    /// Create a token from its URL-safe encoding.
+    pub fn from_encoded(encoded: impl Into<String>) -> Result<DelegationToken, HdfsErr> {
+        let encoded = encoded.into();
+        let valid = !encoded.is_empty()
+            && encoded
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+        if valid {
+            Ok(DelegationToken { encoded })
+        } else {
+            Err(HdfsErr::Miscellaneous(
+                "Invalid delegation token encoding".to_owned(),
+            ))
+        }
+    }
+
+    /// The URL-safe encoding of the token
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.encoded
+    }
+
+    /// The user the token was issued to, as a short name or a Kerberos principal
+    pub fn owner(&self) -> Result<String, HdfsErr> {
+        Token::from_delegation_token(self)?.owner()
+    }
+}
+
+impl FromStr for DelegationToken {
+    type Err = HdfsErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DelegationToken::from_encoded(s)
+    }
+}
+
+impl TryFrom<String> for DelegationToken {
+    type Error = HdfsErr;
+
+    fn try_from(encoded: String) -> Result<Self, Self::Error> {
+        DelegationToken::from_encoded(encoded)
+    }
+}
+
+impl From<DelegationToken> for String {
+    fn from(token: DelegationToken) -> Self {
+        token.encoded
+    }
+}
+
+impl std::fmt::Display for DelegationToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.encoded)
+    }
+}
+
+/// The token is a credential, so it is never written to logs.
+impl std::fmt::Debug for DelegationToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DelegationToken(..)")
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Renews a delegation token in a background thread until it is dropped.
+///
+/// The token is renewed when three quarters of its remaining lifetime have passed. Failed
+/// renewals are retried every minute until the token expires, at which point the renewer
+/// gives up.
+pub struct DelegationTokenRenewer {
+    fs: HdfsFs,
+    token: DelegationToken,
+    expiration: Arc<Mutex<Option<SystemTime>>>,
+    cancel_on_drop: bool,
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl DelegationTokenRenewer {
+    /// Start renewing a token.
+    ///
+    /// * fs - a Kerberos authenticated filesystem of the token's renewer
+    /// * token - the token to renew
+    /// * cancel_on_drop - if true, cancel the token when the renewer is dropped
+    pub fn start(
+        fs: HdfsFs,
+        token: DelegationToken,
+        cancel_on_drop: bool,
+    ) -> Result<DelegationTokenRenewer, HdfsErr> {
+        let expires_at = fs.renew_delegation_token(&token)?;
+        let expiration = Arc::new(Mutex::new(Some(expires_at)));
+        let (stop, stopped) = mpsc::channel::<()>();
+
+        let handle = {
+            let fs = fs.clone();
+            let token = token.clone();
+            let expiration = expiration.clone();
+            thread::Builder::new()
+                .name("hdfs-token-renewer".to_owned())
+                .spawn(move || {
+                    let mut expires_at = expires_at;
+                    let mut delay = renew_delay(expires_at);
+                    while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(delay) {
+                        match fs.renew_delegation_token(&token) {
+                            Ok(renewed_until) => {
+                                debug!("Renewed delegation token until {:?}", renewed_until);
+                                expires_at = renewed_until;
+                                *expiration.lock().unwrap() = Some(expires_at);
+                                delay = renew_delay(expires_at);
+                            }
+                            Err(e) => {
+                                warn!("Could not renew delegation token: {}", e);
+                                match expires_at.duration_since(SystemTime::now()) {
+                                    Ok(remaining) => delay = remaining.min(RENEW_RETRY_INTERVAL),
+                                    Err(_) => {
+                                        error!("Delegation token expired");
+                                        *expiration.lock().unwrap() = None;
+                                        return;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                })
+                .map_err(|e| {
+                    HdfsErr::Miscellaneous(format!("Could not start token renewer: {}", e))
+                })?
+        };
+
+        Ok(DelegationTokenRenewer {
+            fs,
+            token,
+            expiration,
+            cancel_on_drop,
+            stop: Some(stop),
+            handle: Some(handle),
+        })
+    }
+
+    /// The token being renewed
+    #[inline]
+    pub fn token(&self) -> &DelegationToken {
+        &self.token
+    }
+
+    /// Expiration time of the token after the last successful renewal, `None` if the token
+    /// expired without being renewed.
+    pub fn expiration(&self) -> Option<SystemTime> {
+        *self.expiration.lock().unwrap()
+    }
+}
+
+impl Drop for DelegationTokenRenewer {
+    fn drop(&mut self) {
+        // Dropping the sender wakes up the renewer thread.
+        self.stop.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        if self.cancel_on_drop {
+            if let Err(e) = self.fs.cancel_delegation_token(&self.token) {
+                warn!("Could not cancel delegation token: {}", e);
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for DelegationTokenRenewer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DelegationTokenRenewer")
+            .field("fs", &self.fs)
+            .field("expiration", &self.expiration())
+            .field("cancel_on_drop", &self.cancel_on_drop)
+            .finish()
+    }
+}
+
+/// Time to wait before renewing a token expiring at the given time.
+fn renew_delay(expires_at: SystemTime) -> Duration {
+    let remaining = expires_at
+        .duration_since(SystemTime::now())
+        .unwrap_or_default();
+    (remaining * 3 / 4).max(Duration::from_secs(1))
+}
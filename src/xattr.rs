@@ -0,0 +1,357 @@
+use std::ffi::{CStr, CString};
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::ops::BitOr;
+use std::str::FromStr;
+
+use libc::{c_char, c_int};
+
+use crate::err::HdfsErr;
+use crate::hdfsXAttr;
+use crate::path::HdfsPath;
+
+/// The namespace of an extended attribute, the prefix of its name, which decides who may
+/// access it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum XAttrNamespace {
+    /// Attributes of users, subject to the permission of the file
+    User,
+    /// Attributes only the superuser may access
+    Trusted,
+    /// Attributes of the file system itself, which users cannot read
+    Security,
+    /// Attributes of the file system itself, e.g. those of HDFS encryption
+    System,
+    /// Attributes of the file system which the superuser may read through `/.reserved/raw`
+    Raw,
+    /// A namespace this version does not know, with the raw namespace reported by libhdfs3
+    Unknown(i32),
+}
+
+impl XAttrNamespace {
+    /// The prefix of names in the namespace, e.g. `user`, and `unknown` for unknown
+    /// namespaces, which cannot be parsed
+    pub fn prefix(self) -> &'static str {
+        match self {
+            XAttrNamespace::User => "user",
+            XAttrNamespace::Trusted => "trusted",
+            XAttrNamespace::Security => "security",
+            XAttrNamespace::System => "system",
+            XAttrNamespace::Raw => "raw",
+            XAttrNamespace::Unknown(_) => "unknown",
+        }
+    }
+
+    fn from_raw(namespace: c_int) -> XAttrNamespace {
+        match namespace {
+            0 => XAttrNamespace::User,
+            1 => XAttrNamespace::Trusted,
+            2 => XAttrNamespace::Security,
+            3 => XAttrNamespace::System,
+            4 => XAttrNamespace::Raw,
+            other => XAttrNamespace::Unknown(other),
+        }
+    }
+
+    fn to_raw(self) -> c_int {
+        match self {
+            XAttrNamespace::User => 0,
+            XAttrNamespace::Trusted => 1,
+            XAttrNamespace::Security => 2,
+            XAttrNamespace::System => 3,
+            XAttrNamespace::Raw => 4,
+            XAttrNamespace::Unknown(raw) => raw,
+        }
+    }
+}
+
+impl FromStr for XAttrNamespace {
+    type Err = HdfsErr;
+
+    /// Parse a prefix, ignoring its case as Hadoop does
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "user" => Ok(XAttrNamespace::User),
+            "trusted" => Ok(XAttrNamespace::Trusted),
+            "security" => Ok(XAttrNamespace::Security),
+            "system" => Ok(XAttrNamespace::System),
+            "raw" => Ok(XAttrNamespace::Raw),
+            _ => Err(HdfsErr::InvalidXAttrName(s.to_owned())),
+        }
+    }
+}
+
+impl Display for XAttrNamespace {
+    /// Format as the prefix, with the raw namespace of unknown ones, e.g. `unknown(5)`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XAttrNamespace::Unknown(raw) => write!(f, "{}({})", self.prefix(), raw),
+            _ => f.write_str(self.prefix()),
+        }
+    }
+}
+
+/// Split the full name of an extended attribute, e.g. `user.checksum`, into its namespace
+/// and its name within the namespace, which may itself contain dots.
+pub fn split_name(name: &str) -> Result<(XAttrNamespace, &str), HdfsErr> {
+    let invalid = || HdfsErr::InvalidXAttrName(name.to_owned());
+    let (prefix, rest) = name.split_once('.').ok_or_else(invalid)?;
+    let namespace = prefix.parse().map_err(|_| invalid())?;
+    if rest.is_empty() || rest.contains('\0') {
+        return Err(invalid());
+    }
+    Ok((namespace, rest))
+}
+
+/// The full name of an extended attribute with its prefix in lower case, as the namenode
+/// lists it
+pub(crate) fn full_name(name: &str) -> Result<String, HdfsErr> {
+    let (namespace, name) = split_name(name)?;
+    Ok(format!("{}.{}", namespace, name))
+}
+
+/// The error of a failed [`HdfsFs::set_xattr`](crate::hdfs3::HdfsFs::set_xattr) or
+/// [`HdfsFs::remove_xattr`](crate::hdfs3::HdfsFs::remove_xattr), given the message of the
+/// namenode. It fails both with a plain `IOException`, so a `Miscellaneous` error becomes
+/// `XAttrAlreadyExists` or `XAttrNotFound` by its message, e.g.
+/// `XAttr: checksum already exists. The REPLACE flag must be specified.` Other errors are
+/// returned as they are.
+pub(crate) fn xattr_error(path: &HdfsPath, name: &str, message: &str, error: HdfsErr) -> HdfsErr {
+    if !matches!(error, HdfsErr::Miscellaneous(_)) {
+        return error;
+    }
+    if message.contains("already exists. The REPLACE flag must be specified") {
+        HdfsErr::XAttrAlreadyExists(path.to_string(), name.to_owned())
+    } else if message.contains("does not exist. The CREATE flag must be specified")
+        || message.contains("No matching attributes found for remove operation")
+    {
+        HdfsErr::XAttrNotFound(path.to_string(), name.to_owned())
+    } else {
+        error
+    }
+}
+
+/// The error of a failed call on extended attributes by name, given the names of the
+/// attributes the file has. A `Miscellaneous` error becomes `XAttrNotFound` for the first
+/// name the file does not have, since the namenode does not tell it apart. Other errors are
+/// returned as they are.
+pub(crate) fn xattr_not_found_error(
+    path: &HdfsPath,
+    names: &[&str],
+    existing: &[String],
+    error: HdfsErr,
+) -> HdfsErr {
+    if !matches!(error, HdfsErr::Miscellaneous(_)) {
+        return error;
+    }
+    names
+        .iter()
+        .find(|name| full_name(name).is_ok_and(|name| !existing.contains(&name)))
+        .map_or(error, |name| {
+            HdfsErr::XAttrNotFound(path.to_string(), (*name).to_owned())
+        })
+}
+
+/// Whether [`HdfsFs::set_xattr`](crate::hdfs3::HdfsFs::set_xattr) may create an attribute,
+/// replace an existing one, or both
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct XAttrSetFlags(u8);
+
+impl XAttrSetFlags {
+    /// Create the attribute, failing if the file already has it
+    pub const CREATE: XAttrSetFlags = XAttrSetFlags(1);
+    /// Replace the attribute, failing if the file does not have it
+    pub const REPLACE: XAttrSetFlags = XAttrSetFlags(2);
+
+    pub fn contains(self, other: XAttrSetFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+}
+
+impl Default for XAttrSetFlags {
+    /// Create the attribute or replace it, as `hdfs dfs -setfattr` does
+    fn default() -> Self {
+        XAttrSetFlags::CREATE | XAttrSetFlags::REPLACE
+    }
+}
+
+impl BitOr for XAttrSetFlags {
+    type Output = XAttrSetFlags;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        XAttrSetFlags(self.0 | rhs.0)
+    }
+}
+
+/// Extended attributes as passed to libhdfs3, which borrow the names they own and the
+/// values of the caller
+pub(crate) struct RawXAttrs<'a> {
+    _names: Vec<CString>,
+    xattrs: Vec<hdfsXAttr>,
+    _values: PhantomData<&'a [u8]>,
+}
+
+impl<'a> RawXAttrs<'a> {
+    /// Attributes without values, to get or remove them
+    pub(crate) fn names(names: &[&str]) -> Result<RawXAttrs<'static>, HdfsErr> {
+        let names = names.iter().map(|name| (*name, None)).collect::<Vec<_>>();
+        RawXAttrs::new(&names)
+    }
+
+    /// An attribute with its value, to set it
+    pub(crate) fn with_value(name: &str, value: &'a [u8]) -> Result<RawXAttrs<'a>, HdfsErr> {
+        RawXAttrs::new(&[(name, Some(value))])
+    }
+
+    fn new(attrs: &[(&str, Option<&'a [u8]>)]) -> Result<RawXAttrs<'a>, HdfsErr> {
+        let mut names = Vec::with_capacity(attrs.len());
+        let mut xattrs = Vec::with_capacity(attrs.len());
+        for (name, value) in attrs {
+            let (namespace, short_name) = split_name(name)?;
+            let short_name = CString::new(short_name)
+                .map_err(|_| HdfsErr::InvalidXAttrName((*name).to_owned()))?;
+            xattrs.push(hdfsXAttr {
+                mNamespace: namespace.to_raw(),
+                mName: short_name.as_ptr() as *mut c_char,
+                mValue: value.map_or(std::ptr::null_mut(), |value| value.as_ptr() as *mut c_char),
+                mValueLength: value.map_or(0, |value| value.len() as c_int),
+            });
+            names.push(short_name);
+        }
+        Ok(RawXAttrs {
+            _names: names,
+            xattrs,
+            _values: PhantomData,
+        })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const hdfsXAttr {
+        self.xattrs.as_ptr()
+    }
+
+    pub(crate) fn len(&self) -> c_int {
+        self.xattrs.len() as c_int
+    }
+}
+
+/// The full name and the value of an attribute returned by libhdfs3, whose value is empty
+/// if it has none. The name of one in an unknown namespace starts with e.g. `unknown(5).`
+pub(crate) fn xattr(xattr: &hdfsXAttr) -> (String, Vec<u8>) {
+    let name = if xattr.mName.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(xattr.mName) }
+            .to_string_lossy()
+            .into_owned()
+    };
+    let value = if xattr.mValue.is_null() {
+        Vec::new()
+    } else {
+        unsafe {
+            std::slice::from_raw_parts(
+                xattr.mValue as *const u8,
+                xattr.mValueLength.max(0) as usize,
+            )
+        }
+        .to_vec()
+    };
+    (
+        format!("{}.{}", XAttrNamespace::from_raw(xattr.mNamespace), name),
+        value,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::permission::FsAction;
+
+    #[test]
+    fn test_namespace_from_raw() {
+        for namespace in [
+            XAttrNamespace::User,
+            XAttrNamespace::Raw,
+            XAttrNamespace::Unknown(9),
+        ] {
+            assert_eq!(XAttrNamespace::from_raw(namespace.to_raw()), namespace);
+        }
+    }
+
+    fn failed() -> HdfsErr {
+        HdfsErr::Miscellaneous("Could not set extended attribute".to_owned())
+    }
+
+    #[test]
+    fn test_xattr_error() {
+        let path = HdfsPath::new("/data/in");
+        let message = "java.io.IOException: XAttr: checksum already exists. \
+            The REPLACE flag must be specified.";
+        assert!(matches!(
+            xattr_error(path, "user.checksum", message, failed()),
+            HdfsErr::XAttrAlreadyExists(p, n) if p == "/data/in" && n == "user.checksum"
+        ));
+        let message = "java.io.IOException: XAttr: owner does not exist. \
+            The CREATE flag must be specified.";
+        assert!(matches!(
+            xattr_error(path, "user.owner", message, failed()),
+            HdfsErr::XAttrNotFound(p, n) if p == "/data/in" && n == "user.owner"
+        ));
+        let message = "java.io.IOException: No matching attributes found for remove operation";
+        assert!(matches!(
+            xattr_error(path, "user.owner", message, failed()),
+            HdfsErr::XAttrNotFound(p, n) if p == "/data/in" && n == "user.owner"
+        ));
+        // any other failure has another cause
+        let message = "java.io.IOException: The XAttr is too big.";
+        assert!(matches!(
+            xattr_error(path, "user.checksum", message, failed()),
+            HdfsErr::Miscellaneous(_)
+        ));
+        // errors of another kind are kept
+        assert!(matches!(
+            xattr_error(
+                path,
+                "user.owner",
+                "No matching attributes found for remove operation",
+                HdfsErr::FileNotFound("/data/in".to_owned())
+            ),
+            HdfsErr::FileNotFound(_)
+        ));
+    }
+
+    #[test]
+    fn test_xattr_not_found_error() {
+        let path = HdfsPath::new("/data/in");
+        let existing = vec!["user.checksum".to_owned(), "trusted.source".to_owned()];
+        assert!(matches!(
+            xattr_not_found_error(path, &["user.checksum", "user.owner"], &existing, failed()),
+            HdfsErr::XAttrNotFound(p, n) if p == "/data/in" && n == "user.owner"
+        ));
+        assert!(matches!(
+            xattr_not_found_error(
+                path,
+                &["TRUSTED.source", "user.checksum"],
+                &existing,
+                failed()
+            ),
+            HdfsErr::Miscellaneous(_)
+        ));
+        assert!(matches!(
+            xattr_not_found_error(path, &[], &existing, failed()),
+            HdfsErr::Miscellaneous(_)
+        ));
+        assert!(matches!(
+            xattr_not_found_error(
+                path,
+                &["user.owner"],
+                &existing,
+                HdfsErr::AccessDenied("/data".to_owned(), FsAction::Execute)
+            ),
+            HdfsErr::AccessDenied(..)
+        ));
+    }
+}
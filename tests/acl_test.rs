@@ -0,0 +1,155 @@
+use libhdfs3_sys::acl::{AclEntry, AclEntryScope, AclEntryType, AclStatus};
+use libhdfs3_sys::err::HdfsErr;
+use libhdfs3_sys::permission::FsAction;
+
+#[test]
+fn test_parse_acl_entry() {
+    let entry: AclEntry = "user:alice:rwx".parse().unwrap();
+    assert_eq!(
+        entry,
+        AclEntry::new(
+            AclEntryScope::Access,
+            AclEntryType::User,
+            Some("alice"),
+            FsAction::All
+        )
+    );
+    let entry: AclEntry = "default:group::r-x".parse().unwrap();
+    assert_eq!(
+        entry,
+        AclEntry::new(
+            AclEntryScope::Default,
+            AclEntryType::Group,
+            None,
+            FsAction::ReadExecute
+        )
+    );
+    assert_eq!(entry.to_string(), "default:group::r-x");
+    assert_eq!(
+        "mask::r--".parse::<AclEntry>().unwrap().to_string(),
+        "mask::r--"
+    );
+
+    let entry = AclEntry::parse("default:user:alice", false).unwrap();
+    assert_eq!(entry.scope, AclEntryScope::Default);
+    assert_eq!(entry.name.as_deref(), Some("alice"));
+    assert_eq!(entry.permission, FsAction::None);
+    assert_eq!(
+        AclEntry::parse("mask", false).unwrap().entry_type,
+        AclEntryType::Mask
+    );
+
+    for invalid in [
+        "",
+        "alice:rwx",
+        "user:alice",
+        "user:alice:rwz",
+        "user:alice:rwx:x",
+        "other:alice:r--",
+        "default",
+    ] {
+        assert!(
+            matches!(
+                invalid.parse::<AclEntry>(),
+                Err(HdfsErr::InvalidAclSpec(..))
+            ),
+            "{}",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn test_parse_acl_spec() {
+    let entries =
+        AclEntry::parse_spec("user::rwx, group::r-x,other::---,user:alice:rw-", true).unwrap();
+    let formatted: Vec<String> = entries.iter().map(AclEntry::to_string).collect();
+    assert_eq!(
+        formatted,
+        ["user::rwx", "group::r-x", "other::---", "user:alice:rw-"]
+    );
+    assert_eq!(
+        AclEntry::parse_spec("user:alice,default:group:etl", false)
+            .unwrap()
+            .len(),
+        2
+    );
+    assert!(AclEntry::parse_spec("user::rwx,group:r-x", true).is_err());
+}
+
+#[test]
+fn test_acl_status() {
+    let entry = |s: &str| s.parse::<AclEntry>().unwrap();
+    // `hdfs dfs -setfacl -m user:alice:rwx,default:user:bob:rwx,default:mask::r-x` on a
+    // directory with permission 750, after which the group bits hold the mask
+    let status = AclStatus {
+        owner: "hdfs".to_owned(),
+        group: "analysts".to_owned(),
+        permission: 0o770,
+        sticky: false,
+        entries: vec![
+            entry("user:alice:rwx"),
+            entry("group::r-x"),
+            entry("default:user::rwx"),
+            entry("default:user:bob:rwx"),
+            entry("default:group::r-x"),
+            entry("default:mask::r-x"),
+            entry("default:other::---"),
+        ],
+    };
+    let full: Vec<String> = status
+        .full_entries()
+        .iter()
+        .map(AclEntry::to_string)
+        .collect();
+    assert_eq!(
+        full,
+        [
+            "user::rwx",
+            "user:alice:rwx",
+            "group::r-x",
+            "mask::rwx",
+            "other::---",
+            "default:user::rwx",
+            "default:user:bob:rwx",
+            "default:group::r-x",
+            "default:mask::r-x",
+            "default:other::---",
+        ]
+    );
+    assert_eq!(
+        status.effective_permission(&entry("default:user:bob:rwx")),
+        FsAction::ReadExecute
+    );
+    assert_eq!(
+        status.effective_permission(&entry("user:alice:rwx")),
+        FsAction::All
+    );
+    assert_eq!(
+        status.effective_permission(&entry("default:user::rwx")),
+        FsAction::All
+    );
+
+    // entries of types this version does not know are kept as they are
+    let unknown = AclEntry::new(
+        AclEntryScope::Access,
+        AclEntryType::Unknown(7),
+        None,
+        FsAction::All,
+    );
+    assert_eq!(status.effective_permission(&unknown), FsAction::All);
+    assert_eq!(unknown.to_string(), "unknown::rwx");
+
+    // without an ACL the entries are those of the permission bits
+    let status = AclStatus {
+        permission: 0o750,
+        entries: Vec::new(),
+        ..status
+    };
+    let full: Vec<String> = status
+        .full_entries()
+        .iter()
+        .map(AclEntry::to_string)
+        .collect();
+    assert_eq!(full, ["user::rwx", "group::r-x", "other::---"]);
+}
@@ -0,0 +1,133 @@
+use std::path::PathBuf;
+use std::time::{Duration, UNIX_EPOCH};
+
+use libhdfs3_sys::ccache::{ticket_cache_path, CredentialCache};
+
+fn u16(buf: &mut Vec<u8>, value: u16) {
+    buf.extend_from_slice(&value.to_be_bytes());
+}
+
+fn u32(buf: &mut Vec<u8>, value: u32) {
+    buf.extend_from_slice(&value.to_be_bytes());
+}
+
+fn data(buf: &mut Vec<u8>, value: &[u8]) {
+    u32(buf, value.len() as u32);
+    buf.extend_from_slice(value);
+}
+
+fn principal(buf: &mut Vec<u8>, realm: &str, components: &[&str]) {
+    u32(buf, 1);
+    u32(buf, components.len() as u32);
+    data(buf, realm.as_bytes());
+    for component in components {
+        data(buf, component.as_bytes());
+    }
+}
+
+fn credential(buf: &mut Vec<u8>, version: u16, server: (&str, &[&str]), end_time: u32) {
+    principal(buf, "EXAMPLE.COM", &["etl"]);
+    principal(buf, server.0, server.1);
+    u16(buf, 18);
+    if version == 3 {
+        u16(buf, 18);
+    }
+    data(buf, b"key");
+    u32(buf, 1_000);
+    u32(buf, 0);
+    u32(buf, end_time);
+    u32(buf, end_time + 1_000);
+    buf.push(0);
+    u32(buf, 0x40e1_0000);
+    // One address, no authorization data
+    u32(buf, 1);
+    u16(buf, 2);
+    data(buf, &[10, 0, 0, 1]);
+    u32(buf, 0);
+    data(buf, b"ticket");
+    data(buf, b"");
+}
+
+fn ccache(version: u16) -> Vec<u8> {
+    let mut buf = Vec::new();
+    u16(&mut buf, 0x0500 | version);
+    if version == 4 {
+        // KDC time offset header tag
+        u16(&mut buf, 12);
+        u16(&mut buf, 1);
+        u16(&mut buf, 8);
+        u32(&mut buf, 0);
+        u32(&mut buf, 0);
+    }
+    principal(&mut buf, "EXAMPLE.COM", &["etl"]);
+    credential(
+        &mut buf,
+        version,
+        ("X-CACHECONF:", &["krb5_ccache_conf_data", "fast_avail"]),
+        0,
+    );
+    credential(
+        &mut buf,
+        version,
+        ("EXAMPLE.COM", &["krbtgt", "EXAMPLE.COM"]),
+        5_000,
+    );
+    credential(
+        &mut buf,
+        version,
+        ("EXAMPLE.COM", &["hdfs", "nn1.example.com"]),
+        4_000,
+    );
+    buf
+}
+
+#[test]
+fn test_parse() -> anyhow::Result<()> {
+    for version in [3, 4] {
+        let cache = CredentialCache::parse(&ccache(version))?;
+        assert_eq!(cache.version, version);
+        assert_eq!(cache.principal.to_string(), "etl@EXAMPLE.COM");
+        assert_eq!(cache.tickets.len(), 2);
+
+        let tgt = cache.tgt().unwrap();
+        assert!(tgt.is_expired());
+        assert_eq!(tgt.start_time, None);
+        assert_eq!(
+            tgt.renew_till,
+            Some(UNIX_EPOCH + Duration::from_secs(6_000))
+        );
+        assert_eq!(
+            cache.tickets[1].server.to_string(),
+            "hdfs/nn1.example.com@EXAMPLE.COM"
+        );
+        assert_eq!(
+            cache.expires_at(),
+            Some(UNIX_EPOCH + Duration::from_secs(5_000))
+        );
+
+        let content = ccache(version);
+        assert!(CredentialCache::parse(&content[..content.len() - 1]).is_err());
+    }
+    assert!(CredentialCache::parse(&[0x05, 0x02]).is_err());
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("krb5cc_etl");
+    std::fs::write(&path, ccache(4))?;
+    let cache = CredentialCache::read(format!("FILE:{}", path.display()))?;
+    assert_eq!(cache.version, 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_ticket_cache_path() {
+    assert_eq!(
+        ticket_cache_path(Some("FILE:/tmp/krb5cc_etl")),
+        Some(PathBuf::from("/tmp/krb5cc_etl"))
+    );
+    assert_eq!(
+        ticket_cache_path(Some("/tmp/krb5cc_etl")),
+        Some(PathBuf::from("/tmp/krb5cc_etl"))
+    );
+    assert_eq!(ticket_cache_path(Some("KEYRING:persistent:1000")), None);
+}
@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use libhdfs3_sys::config::{
+    AuthMethod, ByteSize, ClientConfig, EffectiveSetting, SESSION_CONFIG_DEFAULTS,
+};
+use libhdfs3_sys::err::HdfsErr;
+
+#[test]
+fn test_default_leaves_site_config() {
+    // nothing is passed unless set, so e.g. Kerberos in core-site.xml is not overridden
+    let params = ClientConfig::default().to_hdfs_params();
+    assert!(params.is_empty(), "{:?}", params);
+}
+
+#[test]
+fn test_effective() -> anyhow::Result<()> {
+    let effective = ClientConfig::default().effective();
+    assert_eq!(effective.len(), SESSION_CONFIG_DEFAULTS.len());
+    assert!(effective.iter().all(|setting| !setting.is_set));
+    assert!(effective.windows(2).all(|pair| pair[0].key < pair[1].key));
+    let value = |settings: &[EffectiveSetting], key: &str| {
+        settings
+            .iter()
+            .find(|setting| setting.key == key)
+            .map(|setting| (setting.value.clone(), setting.is_set))
+    };
+    assert_eq!(
+        value(&effective, "output.default.packetsize"),
+        Some(("65536".to_owned(), false))
+    );
+    assert_eq!(value(&effective, "dfs.encryption.key.provider.uri"), None);
+
+    let effective = ClientConfig::builder()
+        .default_replica(2)
+        .key_provider_uri("http://kms:9600/kms")
+        .build()?
+        .effective();
+    assert_eq!(effective.len(), SESSION_CONFIG_DEFAULTS.len() + 1);
+    assert_eq!(
+        value(&effective, "dfs.default.replica"),
+        Some(("2".to_owned(), true))
+    );
+    assert_eq!(
+        value(&effective, "dfs.encryption.key.provider.uri"),
+        Some(("http://kms:9600/kms".to_owned(), true))
+    );
+    assert_eq!(
+        value(&effective, "dfs.prefetchsize"),
+        Some(("10".to_owned(), false))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_builder() -> anyhow::Result<()> {
+    let config = ClientConfig::builder()
+        .rpc_timeout(Duration::from_secs(30))
+        .read_short_circuit(false)
+        .default_block_size(ByteSize::mib(128))
+        .domain_socket_path("/var/lib/hadoop-hdfs/dn_socket")
+        .build()?;
+    let params = config.to_hdfs_params();
+
+    assert_eq!(params["rpc.client.timeout"], "30000");
+    assert_eq!(params["dfs.client.read.shortcircuit"], "false");
+    assert_eq!(params["dfs.default.blocksize"], "134217728");
+    assert_eq!(
+        params["dfs.domain.socket.path"],
+        "/var/lib/hadoop-hdfs/dn_socket"
+    );
+    assert_eq!(params.len(), 4);
+    assert!(!params.contains_key("hadoop.security.authentication"));
+    let debug = format!("{:?}", config);
+    assert!(
+        debug.contains("\"rpc.client.timeout\": \"30000 (set)\""),
+        "{}",
+        debug
+    );
+    assert!(
+        debug.contains("\"dfs.default.replica\": \"3 (default)\""),
+        "{}",
+        debug
+    );
+
+    let params = ClientConfig::builder()
+        .auth_method(AuthMethod::Kerberos)
+        .rpc_socket_linger_timeout(Duration::from_secs(5))
+        .build()?
+        .to_hdfs_params();
+    assert_eq!(params["hadoop.security.authentication"], "kerberos");
+    assert_eq!(params["rpc.client.socekt.linger.timeout"], "5000");
+
+    Ok(())
+}
+
+#[test]
+fn test_validation() {
+    let result = ClientConfig::builder()
+        .chunk_size(ByteSize::bytes(1000))
+        .build();
+    assert!(matches!(result, Err(HdfsErr::InvalidConfig(_))));
+
+    let result = ClientConfig::builder()
+        .rpc_timeout(Duration::from_secs(30 * 24 * 3600))
+        .build();
+    assert!(matches!(result, Err(HdfsErr::InvalidConfig(_))));
+
+    let result = ClientConfig::builder().default_replica(0).build();
+    assert!(matches!(result, Err(HdfsErr::InvalidConfig(_))));
+
+    let result = ClientConfig::builder()
+        .chunk_size(ByteSize::kib(128))
+        .packet_size(ByteSize::kib(64))
+        .build();
+    assert!(matches!(result, Err(HdfsErr::InvalidConfig(_))));
+
+    // the packet size may come from the site configuration
+    assert!(ClientConfig::builder()
+        .chunk_size(ByteSize::kib(128))
+        .build()
+        .is_ok());
+}
@@ -0,0 +1,113 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+use libhdfs3_sys::credentials::Credentials;
+use libhdfs3_sys::hdfs3::ConnectionProperties;
+
+/// Hadoop `Text`, or a VInt length prefixed byte array, for lengths below 128
+fn text(buf: &mut Vec<u8>, value: &[u8]) {
+    buf.push(value.len() as u8);
+    buf.extend_from_slice(value);
+}
+
+/// Protobuf length delimited field, for lengths below 128
+fn field(buf: &mut Vec<u8>, number: u8, value: &[u8]) {
+    buf.push(number << 3 | 2);
+    buf.push(value.len() as u8);
+    buf.extend_from_slice(value);
+}
+
+fn writable_token(buf: &mut Vec<u8>, alias: &str, kind: &str, service: &str) {
+    text(buf, alias.as_bytes());
+    text(buf, b"identifier");
+    text(buf, b"password");
+    text(buf, kind.as_bytes());
+    text(buf, service.as_bytes());
+}
+
+fn proto_token(kind: &str, service: &str) -> Vec<u8> {
+    let mut token = Vec::new();
+    field(&mut token, 1, b"identifier");
+    field(&mut token, 2, b"password");
+    field(&mut token, 3, kind.as_bytes());
+    field(&mut token, 4, service.as_bytes());
+    token
+}
+
+#[test]
+fn test_writable_token_storage() -> anyhow::Result<()> {
+    let mut content = b"HDTS\x00".to_vec();
+    content.push(2);
+    writable_token(&mut content, "rm", "RM_DELEGATION_TOKEN", "10.0.0.1:8032");
+    writable_token(
+        &mut content,
+        "ha-hdfs:mycluster",
+        "HDFS_DELEGATION_TOKEN",
+        "ha-hdfs:mycluster",
+    );
+    content.push(1);
+    text(&mut content, b"key");
+    text(&mut content, b"secret");
+
+    let credentials = Credentials::parse(&content)?;
+    assert_eq!(credentials.tokens().count(), 2);
+    assert_eq!(credentials.secret("key"), Some(&b"secret"[..]));
+
+    let connection_properties = ConnectionProperties::with_namenodes(
+        "mycluster",
+        &["nn1.example.com:8020", "nn2.example.com:8020"],
+    );
+    let token = credentials.hdfs_token(&connection_properties).unwrap();
+    assert_eq!(token.service, "ha-hdfs:mycluster");
+    assert_eq!(token.password, b"password");
+
+    let mut expected = Vec::new();
+    text(&mut expected, b"identifier");
+    text(&mut expected, b"password");
+    text(&mut expected, b"HDFS_DELEGATION_TOKEN");
+    text(&mut expected, b"ha-hdfs:mycluster");
+    assert_eq!(
+        token.to_delegation_token().as_str(),
+        URL_SAFE_NO_PAD.encode(expected)
+    );
+
+    let other_cluster = ConnectionProperties::with_namenodes("other", &["nn.example.com:8020"]);
+    assert!(credentials.hdfs_token(&other_cluster).is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_protobuf_token_storage() -> anyhow::Result<()> {
+    let mut message = Vec::new();
+    let mut kv = Vec::new();
+    field(&mut kv, 1, b"127.0.0.1:8020");
+    field(
+        &mut kv,
+        2,
+        &proto_token("HDFS_DELEGATION_TOKEN", "127.0.0.1:8020"),
+    );
+    field(&mut message, 1, &kv);
+    let mut kv = Vec::new();
+    field(&mut kv, 1, b"key");
+    field(&mut kv, 3, b"secret");
+    field(&mut message, 2, &kv);
+
+    let mut content = b"HDTS\x01".to_vec();
+    content.push(message.len() as u8);
+    content.extend_from_slice(&message);
+
+    let credentials = Credentials::parse(&content)?;
+    assert_eq!(credentials.secret("key"), Some(&b"secret"[..]));
+
+    let connection_properties = ConnectionProperties::new("localhost", 8020);
+    let token = credentials.hdfs_token(&connection_properties).unwrap();
+    assert_eq!(token.identifier, b"identifier");
+    assert_eq!(token.kind, "HDFS_DELEGATION_TOKEN");
+
+    assert!(Credentials::parse(b"HDTS\x02").is_err());
+    assert!(Credentials::parse(b"NOPE\x00").is_err());
+    assert!(Credentials::parse(&content[..content.len() - 1]).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use libhdfs3_sys::encryption::{CipherSuite, CryptoProtocolVersion};
+
+#[test]
+fn test_cipher_suite() {
+    assert_eq!(
+        CipherSuite::AesCtrNoPadding.to_string(),
+        "AES/CTR/NoPadding"
+    );
+    assert_eq!(CipherSuite::AesCtrNoPadding.block_size(), 16);
+    assert_eq!(CipherSuite::Unknown.block_size(), 0);
+    assert_eq!(
+        CryptoProtocolVersion::EncryptionZones.to_string(),
+        "Encryption zones"
+    );
+}
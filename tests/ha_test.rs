@@ -0,0 +1,88 @@
+use std::io::Write;
+
+use libhdfs3_sys::err::HdfsErr;
+use libhdfs3_sys::hdfs3::{ha_namenodes_with_config, ConnectionProperties, NamenodeAddress};
+
+const HA_CONFIG: &str = r#"<?xml version="1.0"?>
+<configuration>
+    <property>
+        <name>dfs.nameservices</name>
+        <value>mycluster</value>
+    </property>
+    <property>
+        <name>dfs.ha.namenodes.mycluster</name>
+        <value>nn1,nn2</value>
+    </property>
+    <property>
+        <name>dfs.namenode.rpc-address.mycluster.nn1</name>
+        <value>nn1.example.com:8020</value>
+    </property>
+    <property>
+        <name>dfs.namenode.rpc-address.mycluster.nn2</name>
+        <value>nn2.example.com:8020</value>
+    </property>
+    <property>
+        <name>dfs.namenode.http-address.mycluster.nn1</name>
+        <value>nn1.example.com:50070</value>
+    </property>
+</configuration>
+"#;
+
+/// Needs the libhdfs3 shared library, but not a running HDFS.
+#[test]
+fn test_ha_namenodes_with_config() -> anyhow::Result<()> {
+    let mut config = tempfile::NamedTempFile::new()?;
+    config.write_all(HA_CONFIG.as_bytes())?;
+    let config_path = config.path().to_str().unwrap();
+
+    let namenodes = ha_namenodes_with_config(config_path, "mycluster")?;
+    assert_eq!(
+        namenodes,
+        vec![
+            NamenodeAddress {
+                rpc_addr: "nn1.example.com:8020".to_string(),
+                http_addr: Some("nn1.example.com:50070".to_string()),
+            },
+            NamenodeAddress {
+                rpc_addr: "nn2.example.com:8020".to_string(),
+                http_addr: None,
+            },
+        ]
+    );
+
+    let result = ha_namenodes_with_config(config_path, "othercluster");
+    assert!(matches!(result, Err(HdfsErr::InvalidConfig(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_namenodes_to_hdfs_params() -> anyhow::Result<()> {
+    let connection_properties = ConnectionProperties::with_namenodes(
+        "mycluster",
+        &["nn1.example.com:8020", "nn2.example.com:8020"],
+    );
+    connection_properties.validate()?;
+    let params = connection_properties.to_hdfs_params();
+
+    assert_eq!(params.len(), 4);
+    assert_eq!(params["dfs.nameservices"], "mycluster");
+    assert_eq!(params["dfs.ha.namenodes.mycluster"], "nn1,nn2");
+    assert_eq!(
+        params["dfs.namenode.rpc-address.mycluster.nn1"],
+        "nn1.example.com:8020"
+    );
+    assert_eq!(
+        params["dfs.namenode.rpc-address.mycluster.nn2"],
+        "nn2.example.com:8020"
+    );
+
+    let connection_properties =
+        ConnectionProperties::with_namenodes("mycluster", &["nn1.example.com"]);
+    assert!(matches!(
+        connection_properties.validate(),
+        Err(HdfsErr::InvalidConfig(_))
+    ));
+
+    Ok(())
+}
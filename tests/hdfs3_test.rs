@@ -1,4 +1,9 @@
-use libhdfs3_sys::hdfs3::{HdfsFs, ConnectionProperties};
+use std::time::{Duration, Instant};
+
+use libhdfs3_sys::err::HdfsErr;
+use libhdfs3_sys::hdfs3::{HdfsFs, ConnectionProperties, RenameOptions};
+use libhdfs3_sys::path::{HdfsPath, HdfsPathBuf};
+use libhdfs3_sys::permission::FsAction;
 
 /// An integration test of the API.
 ///
@@ -10,14 +15,10 @@ use libhdfs3_sys::hdfs3::{HdfsFs, ConnectionProperties};
 ///
 #[test]
 fn test_all() -> anyhow::Result<()> {
-    let connection_properties = ConnectionProperties {
-        namenode_host: "localhost".to_string(),
-        namenode_port: 8020,
-        namenode_user: None,
-        kerberos_ticket_cache_path: None,
-    };
+    let connection_properties = ConnectionProperties::new("localhost", 8020);
     
     let fs = HdfsFs::new(connection_properties)?;
+    assert_eq!(fs.active_namenode()?, "localhost:8020");
 
     let parent_path = "/test";
     let path = format!("{}/Cargo.toml", parent_path);
@@ -42,7 +43,7 @@ fn test_all() -> anyhow::Result<()> {
     if let Ok(statuses) = fs.list_status(parent_path) {
         assert_eq!(statuses.len(), 1);
         let status = &statuses[0];
-        assert_eq!(status.name(), path);
+        assert_eq!(status.name()?, path);
     }
 
     // (5) Delete the file
@@ -53,3 +54,287 @@ fn test_all() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Symlinks are not followed, and are reported by `symlink_metadata`.
+///
+/// Needs a local HDFS with symlinks enabled (`test.SymlinkEnabledForTesting`).
+#[test]
+fn test_symlinks() -> anyhow::Result<()> {
+    let fs = HdfsFs::new(ConnectionProperties::new("localhost", 8020))?;
+    let dir = "/test_symlinks";
+    fs.mkdir(format!("{}/target", dir))?;
+    fs.create(format!("{}/target/file", dir))?.close()?;
+
+    fs.create_symlink(format!("{}/target", dir), format!("{}/link", dir), false)?;
+    assert!(matches!(
+        fs.create_symlink(format!("{}/target", dir), format!("{}/link", dir), false),
+        Err(HdfsErr::FileAlreadyExists(_))
+    ));
+
+    let status = fs.symlink_metadata(format!("{}/link", dir))?;
+    assert!(status.is_symlink());
+    assert_eq!(
+        status.symlink_target(),
+        Some(HdfsPath::new(&format!("{}/target", dir)))
+    );
+    assert_eq!(
+        fs.read_link(format!("{}/link", dir))?,
+        HdfsPathBuf::from(format!("{}/target", dir))
+    );
+    let status = fs.symlink_metadata(format!("{}/target", dir))?;
+    assert!(!status.is_symlink());
+    assert_eq!(status.symlink_target(), None);
+
+    // a path through the link is not resolved
+    assert!(matches!(
+        fs.get_file_status(format!("{}/link/file", dir)),
+        Err(HdfsErr::UnresolvedLink(_))
+    ));
+    // the path with the link is the one at fault
+    assert!(matches!(
+        fs.rename_with_options(
+            format!("{}/target/file", dir),
+            format!("{}/link/moved", dir),
+            RenameOptions::default()
+        ),
+        Err(HdfsErr::UnresolvedLink(path)) if path == format!("{}/link/moved", dir)
+    ));
+    assert!(matches!(
+        fs.rename_with_options(
+            format!("{}/link/file", dir),
+            format!("{}/moved", dir),
+            RenameOptions::default()
+        ),
+        Err(HdfsErr::UnresolvedLink(path)) if path == format!("{}/link/file", dir)
+    ));
+
+    fs.delete(dir, true)?;
+    Ok(())
+}
+
+/// The errors of a failed rename name the path at fault.
+///
+/// Needs a local HDFS to be up and running.
+#[test]
+fn test_rename() -> anyhow::Result<()> {
+    let fs = HdfsFs::new(ConnectionProperties::new("localhost", 8020))?;
+    let dir = "/test_rename";
+    fs.mkdir(dir)?;
+    fs.create(format!("{}/a", dir))?.close()?;
+    fs.create(format!("{}/b", dir))?.close()?;
+    let options = RenameOptions::default();
+
+    assert!(matches!(
+        fs.rename_with_options(format!("{}/missing", dir), format!("{}/c", dir), options),
+        Err(HdfsErr::FileNotFound(path)) if path == format!("{}/missing", dir)
+    ));
+    assert!(matches!(
+        fs.rename_with_options(format!("{}/a", dir), format!("{}/missing/c", dir), options),
+        Err(HdfsErr::FileNotFound(path)) if path == format!("{}/missing", dir)
+    ));
+    assert!(matches!(
+        fs.rename_with_options(format!("{}/a", dir), format!("{}/b", dir), options),
+        Err(HdfsErr::FileAlreadyExists(path)) if path == format!("{}/b", dir)
+    ));
+    assert!(matches!(
+        fs.rename_with_options(format!("{}/a", dir), format!("{}/b/c", dir), options),
+        Err(HdfsErr::NotADirectory(path)) if path == format!("{}/b", dir)
+    ));
+
+    let options = RenameOptions {
+        overwrite: true,
+        create_parent: true,
+    };
+    fs.rename_with_options(format!("{}/a", dir), format!("{}/b", dir), options)?;
+    fs.rename_with_options(format!("{}/b", dir), format!("{}/new/c", dir), options)?;
+    assert!(fs.exist(format!("{}/new/c", dir)));
+    assert!(matches!(
+        fs.rename_with_options(format!("{}/new", dir), format!("{}/new/c/d", dir), options),
+        Err(HdfsErr::NotADirectory(path)) if path == format!("{}/new/c", dir)
+    ));
+
+    fs.delete(dir, true)?;
+    Ok(())
+}
+
+/// Concat accepts what the namenode accepts, and names the file at fault otherwise.
+///
+/// Needs a local HDFS to be up and running.
+#[test]
+fn test_concat() -> anyhow::Result<()> {
+    let fs = HdfsFs::new(ConnectionProperties::new("localhost", 8020))?;
+    let dir = "/test_concat";
+    fs.mkdir(format!("{}/other", dir))?;
+    let write = |name: &str, content: &[u8]| -> anyhow::Result<String> {
+        let path = format!("{}/{}", dir, name);
+        let file = fs.create(&path)?;
+        file.write(content)?;
+        file.close()?;
+        Ok(path)
+    };
+    // an empty target and sources ending in partial blocks are accepted
+    let target = write("target", b"")?;
+    let a = write("a", b"aaa")?;
+    let b = write("b", b"bb")?;
+    fs.concat(&target, &[&a, &b])?;
+    assert_eq!(fs.get_file_status(&target)?.len(), 5);
+    assert!(!fs.exist(&a));
+
+    let missing = format!("{}/missing", dir);
+    assert!(matches!(
+        fs.concat(&target, &[&missing]),
+        Err(HdfsErr::FileNotFound(path)) if path == missing
+    ));
+    let c = write("c", b"c")?;
+    assert!(matches!(
+        fs.concat(&target, &[&c, &c]),
+        Err(HdfsErr::CannotConcat(path, _)) if path == c
+    ));
+    let empty = write("empty", b"")?;
+    assert!(matches!(
+        fs.concat(&target, &[&c, &empty]),
+        Err(HdfsErr::CannotConcat(path, _)) if path == empty
+    ));
+    let other = write("other/d", b"d")?;
+    assert!(matches!(
+        fs.concat(&target, &[&other]),
+        Err(HdfsErr::CannotConcat(path, _)) if path == other
+    ));
+    assert!(matches!(
+        fs.concat(&target, &[&target]),
+        Err(HdfsErr::CannotConcat(path, _)) if path == target
+    ));
+
+    fs.delete(dir, true)?;
+    Ok(())
+}
+
+/// Waiting for a file which stays open gives up after the timeout.
+///
+/// Needs a local HDFS to be up and running.
+#[test]
+fn test_wait_for_close() -> anyhow::Result<()> {
+    let fs = HdfsFs::new(ConnectionProperties::new("localhost", 8020))?;
+    let path = "/test_wait_for_close";
+    let file = fs.create(path)?;
+    file.write(b"open")?;
+    assert!(!fs.is_file_closed(path)?);
+
+    let timeout = Duration::from_millis(500);
+    let start = Instant::now();
+    assert!(!fs.wait_for_close(path, timeout)?);
+    let elapsed = start.elapsed();
+    assert!(elapsed >= timeout, "{:?}", elapsed);
+    assert!(elapsed < timeout + Duration::from_secs(2), "{:?}", elapsed);
+
+    file.close()?;
+    assert!(fs.wait_for_close(path, timeout)?);
+
+    fs.delete(path, false)?;
+    Ok(())
+}
+
+/// Truncating within a block needs block recovery, truncating beyond the end fails.
+///
+/// Needs a local HDFS to be up and running.
+#[test]
+fn test_truncate() -> anyhow::Result<()> {
+    let fs = HdfsFs::new(ConnectionProperties::new("localhost", 8020))?;
+    let path = "/test_truncate";
+    let file = fs.create(path)?;
+    file.write(b"0123456789")?;
+    file.close()?;
+
+    assert!(fs.truncate(path, 20).is_err());
+    assert!(fs.truncate(path, u64::MAX).is_err());
+    assert!(matches!(
+        fs.truncate("/test_truncate_missing", 0),
+        Err(HdfsErr::FileNotFound(_))
+    ));
+    assert_eq!(fs.get_file_status(path)?.len(), 10);
+
+    fs.truncate_and_wait(path, 4, Duration::from_secs(30))?;
+    assert_eq!(fs.get_file_status(path)?.len(), 4);
+    assert!(fs.is_file_closed(path)?);
+
+    // truncating to a block boundary needs no recovery
+    assert!(!fs.truncate(path, 0)?);
+    assert_eq!(fs.get_file_status(path)?.len(), 0);
+
+    fs.delete(path, false)?;
+    Ok(())
+}
+
+/// Access is checked per component against the permission bits, except for the superuser.
+///
+/// Needs a local HDFS to be up and running, whose `/` is not writable by others.
+#[test]
+fn test_check_access() -> anyhow::Result<()> {
+    let fs = HdfsFs::new(ConnectionProperties::new("localhost", 8020))?;
+    let path = "/test_check_access";
+    fs.create(path)?.close()?;
+    let no_groups: Vec<String> = vec![];
+
+    fs.check_access_as(path, FsAction::Read, "nobody", &no_groups)?;
+    match fs.check_access_as(
+        "/test_check_access_new",
+        FsAction::Write,
+        "nobody",
+        &no_groups,
+    ) {
+        Err(HdfsErr::AccessDenied(denied, FsAction::Write)) => assert_eq!(denied, "/"),
+        other => panic!("Unexpected result {:?}", other),
+    }
+    match fs.check_access_as(
+        "/test_check_access/child",
+        FsAction::Read,
+        "nobody",
+        &no_groups,
+    ) {
+        Err(HdfsErr::NotADirectory(dir)) => assert_eq!(dir, path),
+        other => panic!("Unexpected result {:?}", other),
+    }
+    match fs.check_access_as(
+        "/test_check_access_none",
+        FsAction::Read,
+        "nobody",
+        &no_groups,
+    ) {
+        Err(HdfsErr::FileNotFound(missing)) => assert_eq!(missing, "/test_check_access_none"),
+        other => panic!("Unexpected result {:?}", other),
+    }
+
+    // members of the superuser group are not checked
+    let supergroup = vec!["supergroup".to_owned()];
+    fs.check_access_as(
+        "/test_check_access_new",
+        FsAction::Write,
+        "nobody",
+        &supergroup,
+    )?;
+
+    fs.delete(path, false)?;
+    Ok(())
+}
+
+/// Paths are bytes, but libhdfs3 cannot be given one containing a NUL, nor a name.
+///
+/// Needs a local HDFS to be up and running.
+#[test]
+fn test_nul_in_path() -> anyhow::Result<()> {
+    let fs = HdfsFs::new(ConnectionProperties::new("localhost", 8020))?;
+    let path = HdfsPath::new(b"/test_nul\0in_path");
+    assert!(matches!(fs.qualify(path), Err(HdfsErr::InvalidUrl(_))));
+    assert!(matches!(fs.create(path), Err(HdfsErr::InvalidUrl(_))));
+    assert!(matches!(
+        fs.get_xattrs(path, &[]),
+        Err(HdfsErr::InvalidUrl(_))
+    ));
+    assert!(!fs.exist(path));
+    // nor names containing one
+    assert!(matches!(
+        fs.create_encryption_zone("/test_nul_in_key_name", "key\0name"),
+        Err(HdfsErr::InvalidConfig(_))
+    ));
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use libhdfs3_sys::config::hadoop_conf_dir;
+use libhdfs3_sys::err::HdfsErr;
+use libhdfs3_sys::kerberos::{AuthToLocal, KerberosName, AUTH_TO_LOCAL};
+
+const RULES: &str = r"
+    RULE:[2:$1@$0](nn@EXAMPLE\.COM)s/.*/hdfs/
+    RULE:[2:$1;$2](^.*;admin\.example\.com$)s/;admin\.example\.com$//
+    RULE:[1:$1@$0](.*@CORP\.EXAMPLE\.COM)s/@.*//L
+    RULE:[2:$1@$0](.*@EXAMPLE\.COM)s/(.*)@EXAMPLE\.COM/$1-svc/
+    DEFAULT
+";
+
+#[test]
+fn test_kerberos_name() -> anyhow::Result<()> {
+    let name = KerberosName::parse("alice/host.example.com@EXAMPLE.COM")?;
+    assert_eq!(name.service_name(), "alice");
+    assert_eq!(name.host(), Some("host.example.com"));
+    assert_eq!(name.realm(), Some("EXAMPLE.COM"));
+    assert_eq!(name.to_string(), "alice/host.example.com@EXAMPLE.COM");
+
+    let name: KerberosName = "alice".parse()?;
+    assert_eq!(name.host(), None);
+    assert_eq!(name.realm(), None);
+
+    assert!(KerberosName::parse("alice@").is_err());
+    assert!(KerberosName::parse("a/b/c@EXAMPLE.COM").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_auth_to_local() -> anyhow::Result<()> {
+    let rules = AuthToLocal::parse(RULES)?.with_default_realm("EXAMPLE.COM");
+    assert_eq!(rules.short_name("nn/nn1.example.com@EXAMPLE.COM")?, "hdfs");
+    assert_eq!(
+        rules.short_name("bob/admin.example.com@EXAMPLE.COM")?,
+        "bob"
+    );
+    assert_eq!(rules.short_name("Carol@CORP.EXAMPLE.COM")?, "carol");
+    assert_eq!(
+        rules.short_name("yarn/rm.example.com@EXAMPLE.COM")?,
+        "yarn-svc"
+    );
+    assert_eq!(rules.short_name("alice@EXAMPLE.COM")?, "alice");
+    assert_eq!(rules.short_name("alice")?, "alice");
+    assert!(matches!(
+        rules.short_name("alice@OTHER.COM"),
+        Err(HdfsErr::Miscellaneous(_))
+    ));
+
+    // Rules must not produce names with a host or realm
+    let rules = AuthToLocal::parse("RULE:[1:$1@$0]")?;
+    assert!(rules.short_name("alice@EXAMPLE.COM").is_err());
+
+    let properties = HashMap::from([(AUTH_TO_LOCAL.to_owned(), "RULE:[1:$1]".to_owned())]);
+    let rules = AuthToLocal::from_config(&properties)?;
+    assert_eq!(rules.short_name("alice@OTHER.COM")?, "alice");
+
+    assert!(matches!(
+        AuthToLocal::parse("RULE:[x:$1]"),
+        Err(HdfsErr::InvalidConfig(_))
+    ));
+    assert!(matches!(
+        AuthToLocal::parse("DEFAULT NOPE"),
+        Err(HdfsErr::InvalidConfig(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_auth_to_local_from_env() -> anyhow::Result<()> {
+    let conf_dir = tempfile::tempdir()?;
+    std::fs::write(
+        conf_dir.path().join("core-site.xml"),
+        r#"<configuration>
+            <property><name>hadoop.security.auth_to_local</name><value>RULE:[1:$1]</value></property>
+        </configuration>"#,
+    )?;
+    std::env::set_var("HADOOP_CONF_DIR", conf_dir.path());
+    assert_eq!(hadoop_conf_dir(), Some(conf_dir.path().to_owned()));
+    let rules = AuthToLocal::from_env()?;
+    assert_eq!(rules.short_name("alice@OTHER.COM")?, "alice");
+
+    // without core-site.xml the DEFAULT rule applies
+    std::fs::remove_file(conf_dir.path().join("core-site.xml"))?;
+    let rules = AuthToLocal::from_env()?.with_default_realm("EXAMPLE.COM");
+    assert!(rules.short_name("alice@OTHER.COM").is_err());
+
+    Ok(())
+}
@@ -0,0 +1,226 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::JoinHandle;
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use libhdfs3_sys::encryption::{CipherSuite, CryptoProtocolVersion, CtrCipher, FileEncryptionInfo};
+use libhdfs3_sys::err::HdfsErr;
+use libhdfs3_sys::kms::KmsClient;
+
+/// A request received by the mock KMS
+struct Request {
+    method: String,
+    target: String,
+    body: serde_json::Value,
+}
+
+/// Serve one request on a local port for each of the given statuses and bodies, returning the
+/// port of the KMS and the handle of the thread, which returns the requests.
+fn mock_kms(responses: &[(&str, &str)]) -> (u16, JoinHandle<Vec<Request>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let responses: Vec<String> = responses
+        .iter()
+        .map(|(status, body)| {
+            format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+        })
+        .collect();
+    let handle = std::thread::spawn(move || {
+        responses
+            .iter()
+            .map(|response| serve(&listener, response))
+            .collect()
+    });
+    (port, handle)
+}
+
+fn serve(listener: &TcpListener, response: &str) -> Request {
+    let (stream, _) = listener.accept().unwrap();
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap().to_owned();
+    let target = parts.next().unwrap().to_owned();
+    let mut length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        if header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse().unwrap();
+            }
+        }
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+    reader.get_mut().write_all(response.as_bytes()).unwrap();
+    Request {
+        method,
+        target,
+        body: serde_json::from_slice(&body).unwrap(),
+    }
+}
+
+fn key_version(dek: &[u8]) -> String {
+    format!(
+        r#"{{"name":"key1","versionName":"EK","material":"{}"}}"#,
+        URL_SAFE_NO_PAD.encode(dek)
+    )
+}
+
+fn encryption_info() -> FileEncryptionInfo {
+    FileEncryptionInfo {
+        suite: CipherSuite::AesCtrNoPadding,
+        protocol_version: CryptoProtocolVersion::EncryptionZones,
+        edek: vec![0xfe; 16],
+        iv: (0..16).collect(),
+        key_name: "key1".to_owned(),
+        ez_key_version_name: "key1@0".to_owned(),
+    }
+}
+
+#[test]
+fn test_decrypt_edek() {
+    let dek: Vec<u8> = (100..116).collect();
+    let (port, handle) = mock_kms(&[("200 OK", &key_version(&dek))]);
+    let kms = KmsClient::new(&format!("http://127.0.0.1:{}/kms", port))
+        .unwrap()
+        .with_user("alice");
+    let info = encryption_info();
+    assert_eq!(kms.decrypt_edek(&info).unwrap(), dek);
+
+    let request = handle.join().unwrap().remove(0);
+    assert_eq!(request.method, "POST");
+    assert_eq!(
+        request.target,
+        "/kms/v1/keyversion/key1@0/_eek?eek_op=decrypt&user.name=alice"
+    );
+    assert_eq!(request.body["name"], "key1");
+    assert_eq!(request.body["iv"], STANDARD.encode(&info.iv));
+    assert_eq!(request.body["material"], STANDARD.encode(&info.edek));
+}
+
+#[test]
+fn test_decrypt_edek_error() {
+    let (port, handle) = mock_kms(&[(
+        "403 Forbidden",
+        r#"{"RemoteException":{"exception":"AuthorizationException","message":"User:bob not allowed to do 'DECRYPT_EEK' on 'key1'"}}"#,
+    )]);
+    let kms = KmsClient::new(&format!("http://127.0.0.1:{}/kms", port))
+        .unwrap()
+        .with_user("bob");
+    match kms.decrypt_edek(&encryption_info()) {
+        Err(HdfsErr::Miscellaneous(message)) => {
+            assert!(message.contains("403"), "{}", message);
+            assert!(
+                message.contains("User:bob not allowed to do 'DECRYPT_EEK' on 'key1'"),
+                "{}",
+                message
+            );
+        }
+        other => panic!("Unexpected result {:?}", other),
+    }
+    handle.join().unwrap();
+}
+
+#[test]
+fn test_decrypt_edek_failover() {
+    // both instances are served by the same mock, the first one failing
+    let dek: Vec<u8> = (100..116).collect();
+    let (port, handle) = mock_kms(&[
+        ("500 Internal Server Error", "{}"),
+        ("200 OK", &key_version(&dek)),
+    ]);
+    let kms = KmsClient::new(&format!("kms://http@127.0.0.1;127.0.0.1:{}/kms", port))
+        .unwrap()
+        .with_user("alice");
+    assert_eq!(kms.decrypt_edek(&encryption_info()).unwrap(), dek);
+    assert_eq!(handle.join().unwrap().len(), 2);
+
+    // the error of the last instance is reported when all fail
+    let (port, handle) = mock_kms(&[
+        ("500 Internal Server Error", "{}"),
+        ("503 Service Unavailable", "{}"),
+    ]);
+    let kms = KmsClient::new(&format!("kms://http@127.0.0.1;127.0.0.1:{}/kms", port)).unwrap();
+    match kms.decrypt_edek(&encryption_info()) {
+        Err(HdfsErr::Miscellaneous(message)) => assert!(message.contains("503"), "{}", message),
+        other => panic!("Unexpected result {:?}", other),
+    }
+    handle.join().unwrap();
+}
+
+#[test]
+fn test_decrypt_edek_proxy_user() {
+    let dek: Vec<u8> = (100..116).collect();
+    let (port, handle) = mock_kms(&[("200 OK", &key_version(&dek))]);
+    let kms = KmsClient::new(&format!("http://127.0.0.1:{}/kms", port))
+        .unwrap()
+        .with_user("etl")
+        .with_proxy_user("alice");
+    assert_eq!(kms.decrypt_edek(&encryption_info()).unwrap(), dek);
+    assert_eq!(
+        handle.join().unwrap()[0].target,
+        "/kms/v1/keyversion/key1@0/_eek?eek_op=decrypt&user.name=etl&doAs=alice"
+    );
+}
+
+#[test]
+fn test_kms_urls() {
+    let kms = KmsClient::new("kms://http@kms1;kms2:9600/kms").unwrap();
+    let urls: Vec<&str> = kms.urls().iter().map(|url| url.as_str()).collect();
+    assert_eq!(urls, ["http://kms1:9600/kms", "http://kms2:9600/kms"]);
+
+    let kms = KmsClient::new("https://kms:9600/kms").unwrap();
+    assert_eq!(kms.urls()[0].as_str(), "https://kms:9600/kms");
+
+    assert!(matches!(
+        KmsClient::new("kms://kms1:9600/kms"),
+        Err(HdfsErr::InvalidUrl(_))
+    ));
+    assert!(matches!(
+        KmsClient::new("hdfs://kms:9600/kms"),
+        Err(HdfsErr::InvalidUrl(_))
+    ));
+}
+
+#[test]
+fn test_ctr_cipher() {
+    // NIST SP 800-38A, F.5.1 CTR-AES128.Encrypt
+    let key = hex("2b7e151628aed2a6abf7158809cf4f3c");
+    let iv = hex("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff");
+    let plain = hex("6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51");
+    let encrypted = hex("874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff");
+    let cipher = CtrCipher::new(CipherSuite::AesCtrNoPadding, &key, &iv).unwrap();
+
+    let mut data = plain.clone();
+    cipher.apply_keystream(0, &mut data);
+    assert_eq!(data, encrypted);
+
+    // decrypting from any offset, e.g. after a seek, matches decrypting the whole file
+    for pos in [1, 15, 16, 17, 31] {
+        let mut data = encrypted[pos..].to_vec();
+        cipher.apply_keystream(pos as u64, &mut data);
+        assert_eq!(data, &plain[pos..]);
+    }
+
+    assert!(CtrCipher::new(CipherSuite::Unknown, &key, &iv).is_err());
+    assert!(CtrCipher::new(CipherSuite::AesCtrNoPadding, &key[..10], &iv).is_err());
+}
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
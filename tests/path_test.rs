@@ -0,0 +1,109 @@
+use libhdfs3_sys::path::{HdfsPath, HdfsPathBuf};
+
+#[test]
+fn test_normalize_path() {
+    let normalize = |path| HdfsPath::new(path).normalize().into_string().unwrap();
+    assert_eq!(normalize("/"), "/");
+    assert_eq!(normalize("//data///logs/"), "/data/logs");
+    assert_eq!(normalize("/data/./logs/../tmp"), "/data/tmp");
+    assert_eq!(normalize("/../.."), "/");
+}
+
+#[test]
+fn test_relative_path() {
+    // `HdfsFs::qualify` joins relative paths to the working directory, and those starting
+    // with a ~ component to the home directory
+    let join = |dir, path| HdfsPath::new(dir).join(path).into_string().unwrap();
+    assert_eq!(join("/user/alice/work", "/tmp/x"), "/tmp/x");
+    assert_eq!(join("/user/alice/work", "x/y"), "/user/alice/work/x/y");
+    assert_eq!(join("/user/alice/work", "../x"), "/user/alice/x");
+    assert_eq!(join("/user/alice/work", "."), "/user/alice/work");
+    assert!(HdfsPath::new("~").is_home_relative());
+    assert!(HdfsPath::new("~/x").is_home_relative());
+    assert!(!HdfsPath::new("~x").is_home_relative());
+    assert_eq!(
+        HdfsPath::new("~/x").strip_prefix("~").map(as_str),
+        Some("x")
+    );
+}
+
+#[test]
+fn test_hdfs_path() {
+    let path = HdfsPath::new("/data//logs/./app.log.gz");
+    assert_eq!(path.normalize().to_str().unwrap(), "/data/logs/app.log.gz");
+    assert_eq!(path.file_name(), Some(&b"app.log.gz"[..]));
+    assert_eq!(path.extension(), Some(&b"gz"[..]));
+    assert_eq!(path.parent().map(as_str), Some("/data//logs"));
+    assert_eq!(HdfsPath::new("/data").parent().map(as_str), Some("/"));
+    assert_eq!(HdfsPath::new("/").parent(), None);
+    assert_eq!(HdfsPath::new("/").file_name(), None);
+    assert_eq!(HdfsPath::new("/data/.hidden").extension(), None);
+    assert_eq!(HdfsPath::new("../x/..").normalize().to_str().unwrap(), "..");
+
+    let dir = HdfsPath::new("/data/logs");
+    assert_eq!(dir.join("../tmp").to_str().unwrap(), "/data/tmp");
+    assert_eq!(dir.join("/tmp").to_str().unwrap(), "/tmp");
+    assert_eq!(
+        path.strip_prefix("/data/logs").map(as_str),
+        Some("app.log.gz")
+    );
+    assert_eq!(dir.strip_prefix("/data/logs").map(as_str), Some(""));
+    assert_eq!(dir.strip_prefix("/data/lo"), None);
+    assert_eq!(dir.strip_prefix("data"), None);
+}
+
+#[test]
+fn test_hdfs_path_uri() {
+    let uri = HdfsPath::new("hdfs://namenode:8020/data/../logs/");
+    assert_eq!(uri.scheme(), Some("hdfs"));
+    assert_eq!(uri.authority(), Some("namenode:8020"));
+    assert_eq!(uri.without_authority().to_str().unwrap(), "/data/../logs/");
+    assert!(uri.is_absolute());
+    assert_eq!(
+        uri.normalize().to_str().unwrap(),
+        "hdfs://namenode:8020/logs"
+    );
+    assert_eq!(uri.file_name(), Some(&b"logs"[..]));
+    assert_eq!(
+        HdfsPath::new("/data")
+            .to_uri("namenode:8020")
+            .unwrap()
+            .to_str()
+            .unwrap(),
+        "hdfs://namenode:8020/data"
+    );
+    assert!(HdfsPath::new("data").to_uri("namenode:8020").is_err());
+
+    let parse = |s: &str| s.parse::<HdfsPathBuf>().and_then(HdfsPathBuf::into_string);
+    assert_eq!(parse("hdfs://nn/a/./b").unwrap(), "hdfs://nn/a/b");
+    assert_eq!(parse("hdfs:///a/b/").unwrap(), "/a/b");
+    // a URI with only an authority names the root
+    assert_eq!(
+        parse("hdfs://namenode:8020").unwrap(),
+        "hdfs://namenode:8020/"
+    );
+    assert_eq!(parse("hdfs://").unwrap(), "/");
+    assert_eq!(parse("a//b/..").unwrap(), "a");
+    assert!(parse("file:///a").is_err());
+    assert!(parse("").is_err());
+    assert_eq!(HdfsPathBuf::from("/a").to_string(), "/a");
+}
+
+#[test]
+fn test_non_utf8_path() {
+    let path = HdfsPath::new(b"/data/caf\xe9.txt");
+    assert!(path.to_str().is_err());
+    assert_eq!(path.to_string(), "/data/caf\u{fffd}.txt");
+    assert_eq!(path.file_name(), Some(&b"caf\xe9.txt"[..]));
+    assert_eq!(path.extension(), Some(&b"txt"[..]));
+    assert_eq!(path.parent().map(as_str), Some("/data"));
+    assert_eq!(
+        HdfsPath::new("/data").join(&b"caf\xe9.txt"[..]).as_bytes(),
+        path.as_bytes()
+    );
+    assert!(HdfsPathBuf::from(path.as_bytes()).into_string().is_err());
+}
+
+fn as_str(path: &HdfsPath) -> &str {
+    path.to_str().unwrap()
+}
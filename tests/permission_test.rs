@@ -0,0 +1,95 @@
+use libhdfs3_sys::permission::{parse_access_denied, parse_not_owner, permitted_action, FsAction};
+
+#[test]
+fn test_fs_action() {
+    assert_eq!(FsAction::from_bits(0o5), FsAction::ReadExecute);
+    assert_eq!(FsAction::from_bits(0o757), FsAction::All);
+    assert_eq!(FsAction::ReadWrite.bits(), 0o6);
+    assert!(FsAction::All.implies(FsAction::WriteExecute));
+    assert!(!FsAction::ReadExecute.implies(FsAction::Write));
+    assert!(FsAction::ReadExecute.implies(FsAction::None));
+    assert_eq!(FsAction::Read.or(FsAction::Write), FsAction::ReadWrite);
+    assert_eq!(
+        FsAction::ReadWrite.and(FsAction::WriteExecute),
+        FsAction::Write
+    );
+    assert_eq!(FsAction::ReadExecute.to_string(), "r-x");
+}
+
+#[test]
+fn test_fs_action_from_symbol() {
+    assert_eq!(FsAction::from_symbol("rw-"), Some(FsAction::ReadWrite));
+    assert_eq!(FsAction::from_symbol("---"), Some(FsAction::None));
+    assert_eq!(FsAction::from_symbol("rwx"), Some(FsAction::All));
+    assert_eq!(FsAction::from_symbol("rwz"), None);
+    assert_eq!(FsAction::from_symbol("rw"), None);
+    for bits in 0..8 {
+        let action = FsAction::from_bits(bits);
+        assert_eq!(FsAction::from_symbol(action.symbol()), Some(action));
+    }
+}
+
+#[test]
+fn test_permitted_action() {
+    let groups = vec!["analysts".to_string(), "users".to_string()];
+    assert_eq!(
+        permitted_action("alice", "hdfs", 0o750, "alice", &groups),
+        FsAction::All
+    );
+    assert_eq!(
+        permitted_action("etl", "analysts", 0o750, "alice", &groups),
+        FsAction::ReadExecute
+    );
+    assert_eq!(
+        permitted_action("etl", "etl", 0o754, "alice", &groups),
+        FsAction::Read
+    );
+    // The owner class applies to the owner even if the group or other class permit more
+    assert_eq!(
+        permitted_action("alice", "analysts", 0o077, "alice", &groups),
+        FsAction::None
+    );
+}
+
+#[test]
+fn test_parse_access_denied() {
+    assert_eq!(
+        parse_access_denied(
+            "Permission denied: user=alice, access=WRITE, inode=\"/data/out\":etl:etl:drwxr-xr-x"
+        ),
+        Some(("/data/out".to_owned(), FsAction::Write))
+    );
+    assert_eq!(
+        parse_access_denied(
+            "org.apache.hadoop.security.AccessControlException: Permission denied: \
+             user=alice, access=READ_EXECUTE, inode=\"/data\":etl:etl:drwx------\n\tat ..."
+        ),
+        Some(("/data".to_owned(), FsAction::ReadExecute))
+    );
+    assert_eq!(
+        parse_access_denied("Permission denied: user=alice, access=MODIFY, inode=\"/data\""),
+        None
+    );
+    assert_eq!(parse_access_denied("Path /data does not exist"), None);
+}
+
+#[test]
+fn test_parse_not_owner() {
+    assert_eq!(
+        parse_not_owner("Permission denied. user=bob is not the owner of inode=/data/out"),
+        Some("/data/out".to_owned())
+    );
+    assert_eq!(
+        parse_not_owner(
+            "org.apache.hadoop.security.AccessControlException: Permission denied. \
+             user=bob is not the owner of inode=/data\n\tat ..."
+        ),
+        Some("/data".to_owned())
+    );
+    assert_eq!(
+        parse_not_owner(
+            "Permission denied: user=alice, access=WRITE, inode=\"/data/out\":etl:etl:drwxr-xr-x"
+        ),
+        None
+    );
+}
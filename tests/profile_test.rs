@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use libhdfs3_sys::config::AuthMethod;
+use libhdfs3_sys::err::HdfsErr;
+use libhdfs3_sys::hdfs3::ConnectionProperties;
+use libhdfs3_sys::profile::Profiles;
+
+const PROFILES_TOML: &str = r#"
+[prod.connection]
+namenode_host = "prod"
+namenodes = ["nn1.prod.example.com:8020", "nn2.prod.example.com:8020"]
+namenode_user = "etl"
+
+[prod.config]
+auth_method = "kerberos"
+rpc_timeout = 30000
+
+[dev.connection]
+namenode_host = "localhost"
+namenode_port = 8020
+transparent_encryption = false
+"#;
+
+#[test]
+fn test_profiles_toml() -> anyhow::Result<()> {
+    let profiles = Profiles::from_toml(PROFILES_TOML)?;
+    assert_eq!(profiles.names(), vec!["dev", "prod"]);
+
+    let prod = profiles.get("prod")?;
+    assert!(prod.connection.is_nameservice());
+    assert_eq!(prod.connection.namenode_user.as_deref(), Some("etl"));
+    assert_eq!(prod.config.auth_method, Some(AuthMethod::Kerberos));
+    assert_eq!(prod.config.rpc_timeout, Some(Duration::from_secs(30)));
+    assert_eq!(prod.config.default_replica, None);
+
+    let dev = profiles.get("dev")?;
+    assert_eq!(dev.connection.namenode_port, 8020);
+    assert!(!dev.connection.is_nameservice());
+    assert_eq!(dev.connection.transparent_encryption, Some(false));
+    assert_eq!(prod.connection.transparent_encryption, None);
+
+    assert!(matches!(profiles.get("dr"), Err(HdfsErr::InvalidConfig(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_profiles_json() -> anyhow::Result<()> {
+    let profiles = Profiles::from_json(
+        r#"{"dr": {"connection": {"namenode_host": "dr-nn.example.com", "namenode_port": 9000},
+                   "config": {"read_short_circuit": false}}}"#,
+    )?;
+    let dr = profiles.get("dr")?;
+    assert_eq!(dr.connection.namenode_host, "dr-nn.example.com");
+    assert_eq!(dr.config.read_short_circuit, Some(false));
+
+    let result = Profiles::from_json(r#"{"dr": {"connection": {"namenode_hots": "dr"}}}"#);
+    assert!(matches!(result, Err(HdfsErr::InvalidConfig(_))));
+
+    let result = Profiles::from_json(
+        r#"{"dr": {"connection": {"namenode_host": "dr"}, "config": {"chunk_size": 1000}}}"#,
+    );
+    assert!(matches!(result, Err(HdfsErr::InvalidConfig(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_from_env() -> anyhow::Result<()> {
+    let conf_dir = tempfile::tempdir()?;
+    std::fs::write(
+        conf_dir.path().join("core-site.xml"),
+        r#"<configuration>
+            <property><name>fs.defaultFS</name><value>hdfs://mycluster</value></property>
+        </configuration>"#,
+    )?;
+    std::fs::write(
+        conf_dir.path().join("hdfs-site.xml"),
+        r#"<configuration>
+            <property><name>dfs.ha.namenodes.mycluster</name><value>nn1, nn2</value></property>
+            <property>
+                <name>dfs.namenode.rpc-address.mycluster.nn1</name>
+                <value>nn1.example.com:8020</value>
+            </property>
+            <property>
+                <name>dfs.namenode.rpc-address.mycluster.nn2</name>
+                <value>nn2.example.com:8020</value>
+            </property>
+        </configuration>"#,
+    )?;
+    std::env::set_var("HADOOP_CONF_DIR", conf_dir.path());
+    std::env::set_var("HADOOP_USER_NAME", "etl");
+    std::env::set_var("KRB5CCNAME", "/tmp/krb5cc_etl");
+
+    let connection_properties = ConnectionProperties::from_env()?;
+    assert_eq!(
+        connection_properties,
+        ConnectionProperties::with_namenodes(
+            "mycluster",
+            &["nn1.example.com:8020", "nn2.example.com:8020"]
+        )
+        .namenode_user("etl")
+        .kerberos_ticket_cache_path("/tmp/krb5cc_etl")
+    );
+
+    std::fs::write(
+        conf_dir.path().join("core-site.xml"),
+        r#"<configuration>
+            <property><name>fs.defaultFS</name><value>hdfs://nn.example.com:9000</value></property>
+        </configuration>"#,
+    )?;
+    let connection_properties = ConnectionProperties::from_env()?;
+    assert_eq!(connection_properties.namenode_host, "nn.example.com");
+    assert_eq!(connection_properties.namenode_port, 9000);
+
+    Ok(())
+}
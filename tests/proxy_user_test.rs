@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+
+use libhdfs3_sys::err::HdfsErr;
+use libhdfs3_sys::hdfs3::ConnectionProperties;
+use libhdfs3_sys::profile::Profiles;
+
+#[test]
+fn test_proxy_user() -> anyhow::Result<()> {
+    let gateway = ConnectionProperties::new("localhost", 8020)
+        .kerberos_ticket_cache_path("/tmp/krb5cc_gateway");
+    let alice = gateway.clone().proxy_user("alice");
+    let bob = gateway.clone().proxy_user("bob");
+    assert_eq!(alice.proxy_user.as_deref(), Some("alice"));
+    alice.validate()?;
+
+    // Connections are cached per effective user
+    let keys: HashSet<ConnectionProperties> = [gateway.clone(), alice.clone(), bob, alice]
+        .into_iter()
+        .collect();
+    assert_eq!(keys.len(), 3);
+
+    let result = gateway.clone().proxy_user("").validate();
+    assert!(matches!(result, Err(HdfsErr::InvalidConfig(_))));
+
+    let result = gateway
+        .proxy_user("alice")
+        .delegation_token("token".parse()?)
+        .validate();
+    assert!(matches!(result, Err(HdfsErr::InvalidConfig(_))));
+
+    let profiles = Profiles::from_toml(
+        r#"
+        [gateway.connection]
+        namenode_host = "localhost"
+        namenode_port = 8020
+        proxy_user = "alice"
+        "#,
+    )?;
+    let profile = profiles.get("gateway")?;
+    assert_eq!(profile.connection.proxy_user.as_deref(), Some("alice"));
+
+    Ok(())
+}
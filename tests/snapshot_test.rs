@@ -0,0 +1,95 @@
+use libhdfs3_sys::path::{HdfsPath, HdfsPathBuf};
+use libhdfs3_sys::snapshot::{
+    is_snapshot_path, snapshot_path, DiffType, SnapshotDiffEntry, SnapshotDiffReport, SnapshotPath,
+};
+
+#[test]
+fn test_snapshot_path() {
+    assert_eq!(
+        snapshot_path("/data", "s1").as_bytes(),
+        b"/data/.snapshot/s1"
+    );
+    assert_eq!(
+        snapshot_path("/data/", "s1").as_bytes(),
+        b"/data/.snapshot/s1"
+    );
+
+    let path = SnapshotPath::parse("/data/.snapshot/s1/a/b").unwrap();
+    assert_eq!(path.root.as_bytes(), b"/data");
+    assert_eq!(path.name, "s1");
+    assert_eq!(path.path.as_bytes(), b"a/b");
+    assert_eq!(path.current_path().as_bytes(), b"/data/a/b");
+    assert_eq!(path.to_string(), "/data/.snapshot/s1/a/b");
+
+    let path = SnapshotPath::parse("/.snapshot/s1").unwrap();
+    assert_eq!(path.root.as_bytes(), b"/");
+    assert_eq!(path.path.as_bytes(), b"");
+    assert_eq!(path.current_path().as_bytes(), b"/");
+
+    // names which are not UTF-8 are kept as they are
+    let path = SnapshotPath::parse(HdfsPath::new(b"/caf\xe9/.snapshot/s1/\xff")).unwrap();
+    assert_eq!(path.root.as_bytes(), b"/caf\xe9");
+    assert_eq!(path.current_path().as_bytes(), b"/caf\xe9/\xff");
+
+    assert!(is_snapshot_path("/data/.snapshot/s1"));
+    assert!(!is_snapshot_path("/data/.snapshot"));
+    assert!(!is_snapshot_path("/data/a/b"));
+}
+
+#[test]
+fn test_snapshot_diff_report() {
+    let entry = |diff_type, path: &[u8]| SnapshotDiffEntry {
+        diff_type,
+        path: HdfsPathBuf::from(path),
+    };
+    let report = SnapshotDiffReport {
+        snapshot_root: HdfsPathBuf::from("/data"),
+        from_snapshot: Some("s1".to_owned()),
+        to_snapshot: None,
+        entries: vec![
+            entry(DiffType::Modify, b""),
+            entry(DiffType::Create, b"new"),
+            entry(DiffType::Delete, b"old"),
+            entry(
+                DiffType::Rename {
+                    target: HdfsPathBuf::from("b/moved"),
+                },
+                b"a/file",
+            ),
+            entry(DiffType::Unknown(b'X' as i32), b"other"),
+            entry(DiffType::Create, b"caf\xe9"),
+        ],
+    };
+
+    assert_eq!(report.created().count(), 2);
+    assert_eq!(report.deleted().next().unwrap().path.as_bytes(), b"old");
+    // changes of unknown types are not reported as modifications
+    assert_eq!(report.modified().count(), 1);
+    let renamed = report.renamed().next().unwrap();
+    assert_eq!(renamed.target().as_bytes(), b"b/moved");
+    assert_eq!(
+        report.from_path(renamed).as_bytes(),
+        b"/data/.snapshot/s1/a/file"
+    );
+    assert_eq!(report.to_path(renamed).as_bytes(), b"/data/b/moved");
+    assert_eq!(
+        report.from_path(&report.entries[0]).as_bytes(),
+        b"/data/.snapshot/s1"
+    );
+    // paths which are not UTF-8 can be used to read the entry
+    assert_eq!(
+        report.to_path(&report.entries[5]).as_bytes(),
+        b"/data/caf\xe9"
+    );
+
+    assert_eq!(
+        report.to_string(),
+        "Difference between snapshot s1 and current directory under directory /data:\n\
+         M\t./.\n\
+         +\t./new\n\
+         -\t./old\n\
+         R\t./a/file -> ./b/moved\n\
+         ?\t./other\n\
+         +\t./caf\u{fffd}\n"
+    );
+}
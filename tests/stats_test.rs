@@ -0,0 +1,56 @@
+use libhdfs3_sys::stats::{ChecksumType, FsStats};
+
+#[test]
+fn test_fs_stats() {
+    let stats = FsStats {
+        capacity: 1000,
+        used: 250,
+        remaining: 700,
+        under_replicated_blocks: Some(0),
+        corrupt_blocks: Some(0),
+        missing_blocks: Some(0),
+    };
+    assert_eq!(stats.used_percent(), 25.0);
+    assert_eq!(stats.remaining_percent(), 70.0);
+    assert!(stats.is_healthy());
+
+    let unhealthy = FsStats {
+        missing_blocks: Some(1),
+        ..stats.clone()
+    };
+    assert!(!unhealthy.is_healthy());
+
+    // unknown counts are not taken as healthy
+    let unknown = FsStats {
+        corrupt_blocks: None,
+        ..stats
+    };
+    assert!(!unknown.is_healthy());
+    assert!(!FsStats::default().is_healthy());
+    assert_eq!(FsStats::default().used_percent(), 0.0);
+}
+
+#[test]
+fn test_checksum_type() {
+    for checksum_type in [
+        ChecksumType::Null,
+        ChecksumType::Crc32,
+        ChecksumType::Crc32c,
+    ] {
+        assert_eq!(
+            checksum_type.to_string().parse::<ChecksumType>().unwrap(),
+            checksum_type
+        );
+    }
+    assert_eq!(
+        "crc32c".parse::<ChecksumType>().unwrap(),
+        ChecksumType::Crc32c
+    );
+    assert_eq!(ChecksumType::Crc32c.size(), Some(4));
+    assert_eq!(ChecksumType::Null.size(), Some(0));
+    assert!("MD5".parse::<ChecksumType>().is_err());
+
+    // types this version does not know are not reported as CRC32
+    assert_eq!(ChecksumType::Unknown(7).size(), None);
+    assert_eq!(ChecksumType::Unknown(7).to_string(), "UNKNOWN(7)");
+}
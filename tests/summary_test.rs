@@ -0,0 +1,31 @@
+use libhdfs3_sys::summary::ContentSummary;
+
+#[test]
+fn test_content_summary() {
+    let mut summary = ContentSummary::default();
+    summary.add_directory();
+    summary.add_directory();
+    summary.add_file(100, 3);
+    summary.add_file(20, 1);
+    assert_eq!(summary.length, 120);
+    assert_eq!(summary.file_count, 2);
+    assert_eq!(summary.directory_count, 2);
+    assert_eq!(summary.space_consumed, 320);
+    assert_eq!(summary.remaining_quota(), None);
+    assert_eq!(
+        summary.to_string(),
+        "        none             inf            none             inf            2            2                120"
+    );
+
+    let summary = ContentSummary {
+        quota: Some(3),
+        space_quota: Some(1024),
+        ..summary
+    };
+    assert_eq!(summary.remaining_quota(), Some(-1));
+    assert_eq!(summary.remaining_space_quota(), Some(704));
+    assert_eq!(
+        summary.to_string(),
+        "           3              -1            1024             704            2            2                120"
+    );
+}
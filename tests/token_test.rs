@@ -0,0 +1,46 @@
+use libhdfs3_sys::credentials::Token;
+use libhdfs3_sys::token::DelegationToken;
+
+const ENCODED: &str = "HAAEaGRmcwNldGwAigGHqmP8AIoBh85wgAABAhRfY3Zx0Lb0ZL6xmcvSOw0Ai6Nh5BNIREZTX0RFTEVHQVRJT05fVE9LRU4AAA";
+
+#[test]
+fn test_delegation_token_encoding() -> anyhow::Result<()> {
+    let token: DelegationToken = ENCODED.parse()?;
+    assert_eq!(token.as_str(), ENCODED);
+    assert_eq!(token.to_string(), ENCODED);
+    assert_eq!(format!("{:?}", token), "DelegationToken(..)");
+
+    let json = serde_json::to_string(&token)?;
+    assert_eq!(json, format!("\"{}\"", ENCODED));
+    assert_eq!(serde_json::from_str::<DelegationToken>(&json)?, token);
+
+    assert!(DelegationToken::from_encoded("").is_err());
+    assert!(DelegationToken::from_encoded("not a token").is_err());
+    assert!(serde_json::from_str::<DelegationToken>("\"not a token\"").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_delegation_token_owner() -> anyhow::Result<()> {
+    // version, owner, renewer, real user, issue and max date, sequence number, master key
+    let identifier = b"\x00\x0fetl@EXAMPLE.COM\x04yarn\x00\x8a\x01\x87\xaa\x63\xfc\x00\x8a\x01\x87\xce\x70\x80\x00\x01\x02";
+    let token = Token {
+        identifier: identifier.to_vec(),
+        password: b"password".to_vec(),
+        kind: "HDFS_DELEGATION_TOKEN".to_owned(),
+        service: "ha-hdfs:mycluster".to_owned(),
+    };
+    let delegation_token = token.to_delegation_token();
+    assert!(Token::from_delegation_token(&delegation_token)? == token);
+    assert_eq!(delegation_token.owner()?, "etl@EXAMPLE.COM");
+
+    let other_version = Token {
+        identifier: b"\x01\x03etl".to_vec(),
+        ..token
+    };
+    assert!(other_version.owner().is_err());
+    assert!(Token::from_delegation_token(&DelegationToken::from_encoded("AAAA")?).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use libhdfs3_sys::err::HdfsErr;
+use libhdfs3_sys::xattr::{split_name, XAttrNamespace, XAttrSetFlags};
+
+#[test]
+fn test_split_name() {
+    assert_eq!(
+        split_name("user.checksum").unwrap(),
+        (XAttrNamespace::User, "checksum")
+    );
+    assert_eq!(
+        split_name("trusted.lineage.source").unwrap(),
+        (XAttrNamespace::Trusted, "lineage.source")
+    );
+    // the prefix is case-insensitive, the name is not
+    assert_eq!(
+        split_name("SECURITY.hdfs.unreadable.by.superuser").unwrap(),
+        (XAttrNamespace::Security, "hdfs.unreadable.by.superuser")
+    );
+    assert_eq!(
+        split_name("System.Crypto").unwrap(),
+        (XAttrNamespace::System, "Crypto")
+    );
+    assert_eq!(split_name("raw.a").unwrap().0, XAttrNamespace::Raw);
+
+    for invalid in [
+        "",
+        "checksum",
+        "user.",
+        "users.checksum",
+        ".checksum",
+        "user.a\0b",
+    ] {
+        assert!(
+            matches!(split_name(invalid), Err(HdfsErr::InvalidXAttrName(_))),
+            "{:?}",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn test_namespace() {
+    for namespace in [
+        XAttrNamespace::User,
+        XAttrNamespace::Trusted,
+        XAttrNamespace::Security,
+        XAttrNamespace::System,
+        XAttrNamespace::Raw,
+    ] {
+        assert_eq!(namespace.to_string(), namespace.prefix());
+        assert_eq!(
+            namespace.prefix().parse::<XAttrNamespace>().unwrap(),
+            namespace
+        );
+    }
+    assert!("group".parse::<XAttrNamespace>().is_err());
+
+    // namespaces this version does not know are not reported as user attributes
+    assert_eq!(XAttrNamespace::Unknown(5).to_string(), "unknown(5)");
+    assert!("unknown".parse::<XAttrNamespace>().is_err());
+}
+
+#[test]
+fn test_set_flags() {
+    let flags = XAttrSetFlags::default();
+    assert!(flags.contains(XAttrSetFlags::CREATE));
+    assert!(flags.contains(XAttrSetFlags::REPLACE));
+    assert_eq!(flags, XAttrSetFlags::CREATE | XAttrSetFlags::REPLACE);
+    assert_eq!(flags.bits(), 3);
+
+    assert!(!XAttrSetFlags::CREATE.contains(XAttrSetFlags::REPLACE));
+    assert!(!XAttrSetFlags::REPLACE.contains(XAttrSetFlags::CREATE));
+    assert!(!XAttrSetFlags::CREATE.contains(flags));
+}